@@ -1,9 +1,10 @@
 use crate::systems::ServerEventsReceiver;
 use bevy::prelude::{Res, ResMut};
 use renderer_api::{ClientGameState, ClientHistoryState};
-use shared::{EntityType, HelloState, ServerEvent};
+use shared::{EntityType, GameOutcome, HelloState, ServerEvent};
 use std::cmp::Reverse;
 use std::collections::HashMap;
+use std::time::Instant;
 use tracing::{debug, error, info};
 use uuid::Uuid;
 
@@ -29,12 +30,22 @@ pub fn process_server_events(
         ServerEvent::Hello(HelloState::Rejected { reason }) => {
             error!("Client connection rejected: {}", reason)
         }
-        ServerEvent::FullState(rx_game_state) => {
+        ServerEvent::FullState {
+            game_state: rx_game_state,
+            clock,
+        } => {
+            game_state_snapshot.clock = clock;
+            game_state_snapshot.clock_received_at = Instant::now();
             game_state_snapshot.week = rx_game_state.week;
             game_state_snapshot.players = rx_game_state.players;
             game_state_snapshot.companies = rx_game_state.companies;
             game_state_snapshot.organizations = rx_game_state.organizations;
             game_state_snapshot.entities = rx_game_state.entities;
+            game_state_snapshot.former_employees = rx_game_state.former_employees;
+            game_state_snapshot.candidates = rx_game_state.candidates;
+            game_state_snapshot.corporate_events = rx_game_state.corporate_events;
+            game_state_snapshot.board = rx_game_state.board;
+            game_state_snapshot.last_rejection = None;
 
             let mut ordered_organizations_of_company: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
             let mut ordered_employees_of_organization: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
@@ -141,11 +152,21 @@ pub fn process_server_events(
             ordered_unemployed_entities
                 .sort_by_key(|e| game_state_snapshot.entities.get(e).unwrap().name.clone());
 
+            // Most senior candidates first
+            let mut ordered_candidates: Vec<Uuid> =
+                game_state_snapshot.candidates.keys().copied().collect();
+            ordered_candidates.sort_by_key(|e| {
+                let candidate = game_state_snapshot.candidates.get(e).unwrap();
+                let name = game_state_snapshot.entities.get(e).unwrap().name.clone();
+                (Reverse(candidate.level), name)
+            });
+
             game_state_snapshot.ordered_organizations_of_company = ordered_organizations_of_company;
             game_state_snapshot.ordered_employees_of_organization =
                 ordered_employees_of_organization;
             game_state_snapshot.ordered_employees_of_company = ordered_employees_of_company;
             game_state_snapshot.ordered_unemployed_entities = ordered_unemployed_entities;
+            game_state_snapshot.ordered_candidates = ordered_candidates;
             game_state_snapshot.ordered_pets_of_entity = ordered_pets_of_entity;
             game_state_snapshot.ordered_children_of_entity = ordered_children_of_entity;
         }
@@ -156,6 +177,12 @@ pub fn process_server_events(
                 rx_history.organizations.keys().cloned().collect();
             history_state_snapshot.company_order = rx_history.companies.keys().cloned().collect();
         }
+        ServerEvent::HistoryRange(rx_history_range) => {
+            history_state_snapshot.history_range = Some(rx_history_range);
+        }
+        ServerEvent::ReportStore(rx_report_store) => {
+            history_state_snapshot.reports = rx_report_store.reports;
+        }
         ServerEvent::GameCreated { game_id, game_name } => {
             info!("created game: {:?} with id: {:?}", game_name, game_id);
         }
@@ -166,7 +193,17 @@ pub fn process_server_events(
             );
         }
         ServerEvent::ListGames { games } => {
-            info!("games: {:?}", games);
+            for game in games {
+                info!(
+                    "game: {:?} ({:?}) seed: {} organizations: {} ticking: {:?} clients: {}",
+                    game.metadata.name,
+                    game.metadata.id,
+                    game.metadata.world_parameters.seed,
+                    game.metadata.world_parameters.organization_count,
+                    game.metadata.tick_mode,
+                    game.active_client_count
+                );
+            }
         }
         ServerEvent::ListGamesFailed { reason } => {
             info!("listGamesFailed: {:?}", reason);
@@ -177,5 +214,34 @@ pub fn process_server_events(
         ServerEvent::GameDeletionFailed { game_id, reason } => {
             info!("failed to delete game: {:?} because: {:?}", game_id, reason);
         }
+        ServerEvent::GameLoadFailed { game_id, reason } => {
+            error!("failed to load game: {:?} because: {}", game_id, reason);
+        }
+        ServerEvent::CommandRejected { reason } => {
+            info!("command rejected: {}", reason);
+            game_state_snapshot.last_rejection = Some(reason);
+        }
+        ServerEvent::GameOver { reason, report } => {
+            info!("Game over in week {}: {:?}", report.week, reason);
+            game_state_snapshot.board.outcome = Some(GameOutcome::GameOver { reason, report });
+        }
+        ServerEvent::Victory { reason, report } => {
+            info!("Victory in week {}: {:?}", report.week, reason);
+            game_state_snapshot.board.outcome = Some(GameOutcome::Victory { reason, report });
+        }
+        ServerEvent::CorporateEventDrawn { corporate_event } => {
+            info!(
+                "Corporate event in week {}: {:?}",
+                corporate_event.week, corporate_event.kind
+            );
+            // Shows up right away, the full state that follows carries it as well.
+            if !game_state_snapshot
+                .corporate_events
+                .iter()
+                .any(|known| known.id == corporate_event.id)
+            {
+                game_state_snapshot.corporate_events.push(corporate_event);
+            }
+        }
     }
 }
@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use renderer_api::{ClientGameState, ClientHistoryState};
 use shared::HistoryState;
+use std::time::Instant;
 
 pub fn setup_world_state(mut commands: Commands) {
     commands.insert_resource(ClientGameState {
@@ -9,10 +10,18 @@ pub fn setup_world_state(mut commands: Commands) {
         companies: Default::default(),
         organizations: Default::default(),
         entities: Default::default(),
+        former_employees: vec![],
+        candidates: Default::default(),
+        corporate_events: vec![],
+        board: Default::default(),
+        last_rejection: None,
+        clock: Default::default(),
+        clock_received_at: Instant::now(),
         ordered_organizations_of_company: Default::default(),
         ordered_employees_of_organization: Default::default(),
         ordered_employees_of_company: Default::default(),
         ordered_unemployed_entities: vec![],
+        ordered_candidates: vec![],
         ordered_pets_of_entity: Default::default(),
         ordered_children_of_entity: Default::default(),
     });
@@ -22,6 +31,8 @@ pub fn setup_world_state(mut commands: Commands) {
             organizations: Default::default(),
             companies: Default::default(),
         },
+        history_range: None,
+        reports: vec![],
         player_order: vec![],
         organization_order: vec![],
         company_order: vec![],
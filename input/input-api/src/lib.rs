@@ -8,7 +8,9 @@ pub struct PendingPlayerInputAction(pub Option<PlayerInputAction>);
 
 #[derive(Clone, Debug)]
 pub enum PlayerInputAction {
-    DoNothing,
+    EndTurn,
+    ClockSlower,
+    ClockFaster,
     Quit,
 
     CreateNewGame,
@@ -20,6 +22,16 @@ pub enum PlayerInputAction {
     SelectEmployeeToHire,
     SelectEmployeeForRaise,
     SelectEmployeeForPromotionToVP,
+    SelectEmployeeForPromotion,
+    SelectEmployeeForDemotion,
+    SelectEmployeeForTransfer,
+    PlanInitiative,
+    CancelInitiative,
+    OpenCompensation,
+    PlanLoan,
+    RepayDebt,
+    OpenReports,
+    CycleHistoryResolution,
 
     MenuUp,
     MenuDown,
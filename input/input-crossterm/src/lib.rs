@@ -63,8 +63,20 @@ fn update_input(mut pending_player_input_action: ResMut<PendingPlayerInputAction
                 KeyCode::Tab => Some(PlayerInputAction::MenuChangeTab),
 
                 KeyCode::Char('h') => Some(PlayerInputAction::SelectEmployeeToHire),
-                KeyCode::Char(' ') => Some(PlayerInputAction::DoNothing),
+                KeyCode::Char(' ') => Some(PlayerInputAction::EndTurn),
+                KeyCode::Char('-') => Some(PlayerInputAction::ClockSlower),
+                KeyCode::Char('+') | KeyCode::Char('=') => Some(PlayerInputAction::ClockFaster),
                 KeyCode::Char('p') => Some(PlayerInputAction::SelectEmployeeForPromotionToVP),
+                KeyCode::Char('u') => Some(PlayerInputAction::SelectEmployeeForPromotion),
+                KeyCode::Char('j') => Some(PlayerInputAction::SelectEmployeeForDemotion),
+                KeyCode::Char('t') => Some(PlayerInputAction::SelectEmployeeForTransfer),
+                KeyCode::Char('i') => Some(PlayerInputAction::PlanInitiative),
+                KeyCode::Char('c') => Some(PlayerInputAction::CancelInitiative),
+                KeyCode::Char('b') => Some(PlayerInputAction::PlanLoan),
+                KeyCode::Char('y') => Some(PlayerInputAction::RepayDebt),
+                KeyCode::Char('e') => Some(PlayerInputAction::OpenCompensation),
+                KeyCode::Char('o') => Some(PlayerInputAction::OpenReports),
+                KeyCode::Char('v') => Some(PlayerInputAction::CycleHistoryResolution),
                 KeyCode::Char('l') => Some(PlayerInputAction::LaunchPRCampaign),
                 KeyCode::Char('f') => Some(PlayerInputAction::SelectEmployeeToFire),
                 KeyCode::Char('r') => Some(PlayerInputAction::SelectEmployeeForRaise),
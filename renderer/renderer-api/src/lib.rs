@@ -1,10 +1,12 @@
 use bevy::prelude::{Res, ResMut, Resource};
 use input_api::PendingPlayerInputAction;
 use shared::{
-    Company, ConnectionStateResource, Entity, HistoryState, Organization, PendingClientMessage,
-    PendingPlayerAction, Player,
+    Board, Candidate, ClockSpeed, ClockState, Company, ConnectionStateResource, CorporateEvent,
+    Entity, FinancialReport, FormerEmployee, HistoryRange, HistoryResolution, HistoryState, Money,
+    Organization, PendingClientMessage, PendingPlayerAction, Player,
 };
 use std::collections::HashMap;
+use std::time::Instant;
 use uuid::Uuid;
 
 #[derive(Resource)]
@@ -14,19 +16,92 @@ pub struct ClientGameState {
     pub companies: HashMap<Uuid, Company>,
     pub organizations: HashMap<Uuid, Organization>,
     pub entities: HashMap<Uuid, Entity>,
+    pub former_employees: Vec<FormerEmployee>,
+    pub candidates: HashMap<Uuid, Candidate>,
+    pub corporate_events: Vec<CorporateEvent>,
+    pub board: Board,
+
+    // Why the server refused our last command, until the next state arrives
+    pub last_rejection: Option<String>,
+
+    pub clock: ClockState,
+    pub clock_received_at: Instant,
 
     // For Predictable ordering
     pub ordered_organizations_of_company: HashMap<Uuid, Vec<Uuid>>,
     pub ordered_employees_of_organization: HashMap<Uuid, Vec<Uuid>>,
     pub ordered_employees_of_company: HashMap<Uuid, Vec<Uuid>>,
     pub ordered_unemployed_entities: Vec<Uuid>,
+    pub ordered_candidates: Vec<Uuid>,
     pub ordered_pets_of_entity: HashMap<Uuid, Vec<Uuid>>,
     pub ordered_children_of_entity: HashMap<Uuid, Vec<Uuid>>,
 }
 
+impl ClientGameState {
+    /// Everyone who has left an organization, most recent departure first.
+    pub fn former_employees_of_organization(&self, organization_id: &Uuid) -> Vec<&FormerEmployee> {
+        self.former_employees
+            .iter()
+            .rev()
+            .filter(|former| former.organization_id == *organization_id)
+            .collect()
+    }
+
+    /// The company the operator runs, every other company is a rival.
+    pub fn player_company_id(&self) -> Option<Uuid> {
+        self.companies
+            .values()
+            .filter(|company| company.rival.is_none())
+            .map(|company| company.id)
+            .min()
+    }
+
+    /// A company's share of this week's sales across the whole market, in percent.
+    pub fn market_share_percent(&self, company_id: &Uuid) -> i64 {
+        let total: Money = self
+            .companies
+            .values()
+            .map(|company| company.financials.this_weeks_income)
+            .sum();
+        self.companies
+            .get(company_id)
+            .and_then(|company| {
+                (company.financials.this_weeks_income * 100)
+                    .get()
+                    .checked_div(total.get())
+            })
+            .unwrap_or(0)
+    }
+
+    /// The corporate event waiting for the player's decision, if any.
+    pub fn pending_corporate_event(&self) -> Option<&CorporateEvent> {
+        self.corporate_events
+            .iter()
+            .find(|corporate_event| corporate_event.is_pending())
+    }
+
+    /// Counts down locally between full states, `None` while the clock is paused.
+    pub fn seconds_until_next_week(&self) -> Option<u64> {
+        if self.clock.speed == ClockSpeed::Paused {
+            return None;
+        }
+        let elapsed = self.clock_received_at.elapsed().as_millis() as u64;
+        Some(
+            self.clock
+                .millis_until_next_week
+                .saturating_sub(elapsed)
+                .div_ceil(1000),
+        )
+    }
+}
+
 #[derive(Resource)]
 pub struct ClientHistoryState {
     pub history_state: HistoryState,
+    /// The last range of history asked for, at a coarser resolution than the recent weeks.
+    pub history_range: Option<HistoryRange>,
+    /// Quarterly and annual reports, oldest first.
+    pub reports: Vec<FinancialReport>,
 
     // For Predictable ordering
     pub player_order: Vec<Uuid>,
@@ -34,6 +109,16 @@ pub struct ClientHistoryState {
     pub company_order: Vec<Uuid>,
 }
 
+impl ClientHistoryState {
+    /// The history to chart at `resolution`, the recent weeks until a range at it has arrived.
+    pub fn history_at(&self, resolution: HistoryResolution) -> &HistoryState {
+        match &self.history_range {
+            Some(history_range) if history_range.resolution == resolution => &history_range.history,
+            _ => &self.history_state,
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct RendererResource {
     pub renderer: Box<dyn Renderer + Send + Sync>,
@@ -1,5 +1,5 @@
 use crate::dashboard_viewer::navigation::{NavigationAction, NavigationStack};
-use crate::dashboard_viewer::routes::{DashboardData, Route};
+use crate::dashboard_viewer::routes::{DashboardData, ReportsData, Route};
 use input_api::PlayerInputAction;
 use renderer_api::ClientHistoryState;
 
@@ -12,9 +12,19 @@ pub fn handle_input(
         NavigationAction::Pop.apply(nav);
         return;
     }
+    if let (PlayerInputAction::OpenReports, Route::Dashboard { .. }) =
+        (&player_input_action, nav.current())
+    {
+        NavigationAction::Push(Route::Reports {
+            data: ReportsData::default(),
+        })
+        .apply(nav);
+        return;
+    }
 
     match nav.current_mut() {
         Route::Dashboard { data } => data.handle_input(player_input_action, client_history_state),
+        Route::Reports { data } => data.handle_input(player_input_action, client_history_state),
     };
 }
 
@@ -46,6 +56,22 @@ impl InputHandler for DashboardData {
                     .map_or(0, |a| a.entity_count),
             ),
             PlayerInputAction::MenuChangeTab => self.cycle_kind(),
+            PlayerInputAction::CycleHistoryResolution => self.resolution = self.resolution.next(),
+            _ => {}
+        }
+    }
+}
+
+impl InputHandler for ReportsData {
+    fn handle_input(
+        &mut self,
+        action: PlayerInputAction,
+        client_history_state: &ClientHistoryState,
+    ) {
+        let report_count = client_history_state.reports.len();
+        match action {
+            PlayerInputAction::MenuLeft => self.page(1, report_count),
+            PlayerInputAction::MenuRight => self.page(-1, report_count),
             _ => {}
         }
     }
@@ -41,12 +41,13 @@ impl NavigationStack {
                 data: DashboardData {
                     entity_kind: EntityKind::Player,
                     selected_entity_kind_data: Default::default(),
+                    resolution: Default::default(),
+                    requested_history: None,
                 },
             }],
         }
     }
 
-    #[allow(dead_code)]
     pub fn current(&self) -> &Route {
         self.stack
             .last()
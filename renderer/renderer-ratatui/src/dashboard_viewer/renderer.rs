@@ -1,5 +1,6 @@
 use crate::dashboard_viewer::input::handle_input;
 use crate::dashboard_viewer::navigation::NavigationStack;
+use crate::dashboard_viewer::routes::Route;
 use crate::dashboard_viewer::views::render::render;
 use bevy::prelude::{Res, ResMut};
 use input_api::PendingPlayerInputAction;
@@ -35,6 +36,7 @@ impl RatatuiDashboardRenderer {
     fn try_draw_frame(
         &mut self,
         connection_state: &ConnectionState,
+        client_game_state: &ClientGameState,
         client_history_state: &ClientHistoryState,
         pending_player_input_action: &mut ResMut<PendingPlayerInputAction>,
     ) -> Result<CompletedFrame, io::Error> {
@@ -76,7 +78,12 @@ impl RatatuiDashboardRenderer {
                         handle_input(action, &mut self.navigation_stack, client_history_state);
                     }
 
-                    render(&mut self.navigation_stack, frame, client_history_state);
+                    render(
+                        &mut self.navigation_stack,
+                        frame,
+                        client_game_state,
+                        client_history_state,
+                    );
                 }
                 ConnectionState::Rejected(reason) => {
                     let paragraph =
@@ -93,15 +100,28 @@ impl RatatuiDashboardRenderer {
 impl Renderer for RatatuiDashboardRenderer {
     fn render(
         &mut self,
-        _client_game_state: &ClientGameState,
+        client_game_state: &ClientGameState,
         client_history_state: &ClientHistoryState,
-        _pending_client_message: ResMut<PendingClientMessage>,
+        mut pending_client_message: ResMut<PendingClientMessage>,
         mut pending_player_input_action: ResMut<PendingPlayerInputAction>,
         _pending_player_action: ResMut<PendingPlayerAction>,
         connection_state_resource: Res<ConnectionStateResource>,
     ) {
+        let connected = matches!(
+            connection_state_resource.connection_state,
+            ConnectionState::Connected
+        );
+        let history_request = match self.navigation_stack.current_mut() {
+            Route::Dashboard { data } if connected => data.history_request(client_game_state.week),
+            _ => None,
+        };
+        if let Some(history_request) = history_request {
+            pending_client_message.0 = Some(history_request);
+        }
+
         if let Err(e) = self.try_draw_frame(
             &connection_state_resource.connection_state,
+            client_game_state,
             client_history_state,
             &mut pending_player_input_action,
         ) {
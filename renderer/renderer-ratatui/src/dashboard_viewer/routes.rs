@@ -1,3 +1,4 @@
+use shared::{ClientMessage, FinancialReport, HistoryResolution};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
@@ -17,6 +18,9 @@ pub struct SelectedEntityKindData {
 pub struct DashboardData {
     pub entity_kind: EntityKind,
     pub selected_entity_kind_data: HashMap<EntityKind, SelectedEntityKindData>,
+    pub resolution: HistoryResolution,
+    /// The resolution and week history was last asked for, so it is asked for once a week.
+    pub requested_history: Option<(HistoryResolution, u16)>,
 }
 
 impl DashboardData {
@@ -48,6 +52,21 @@ impl DashboardData {
         }
     }
 
+    /// Asks for the whole game's history when charting coarser than the recent weeks, which
+    /// is all the server sends unasked.
+    pub fn history_request(&mut self, week: u16) -> Option<ClientMessage> {
+        let wanted = (self.resolution, week);
+        if self.resolution == HistoryResolution::Weekly || self.requested_history == Some(wanted) {
+            return None;
+        }
+        self.requested_history = Some(wanted);
+        Some(ClientMessage::RequestHistory {
+            from_week: 0,
+            to_week: week,
+            resolution: self.resolution,
+        })
+    }
+
     pub fn cycle_kind(&mut self) {
         self.entity_kind = match self.entity_kind {
             EntityKind::Player => EntityKind::Company,
@@ -57,7 +76,76 @@ impl DashboardData {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ReportsData {
+    /// How many reports back from the latest, so a newly filed report doesn't move the page.
+    pub reports_back: usize,
+}
+
+impl ReportsData {
+    pub fn selected<'a>(&self, reports: &'a [FinancialReport]) -> Option<&'a FinancialReport> {
+        reports.iter().rev().nth(self.reports_back)
+    }
+
+    pub fn page(&mut self, delta: isize, report_count: usize) {
+        self.reports_back = self
+            .reports_back
+            .saturating_add_signed(delta)
+            .min(report_count.saturating_sub(1));
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Route {
     Dashboard { data: DashboardData },
+    Reports { data: ReportsData },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::ReportPeriod;
+
+    fn reports(count: u16) -> Vec<FinancialReport> {
+        (0..count)
+            .map(|quarter| FinancialReport {
+                period: ReportPeriod::Quarterly,
+                first_week: quarter * 13,
+                last_week: quarter * 13 + 12,
+                companies: vec![],
+                organizations: vec![],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn paging_stops_at_the_oldest_and_the_latest_report() {
+        let reports = reports(3);
+        let mut data = ReportsData::default();
+
+        data.page(-1, reports.len());
+        assert_eq!(data.selected(&reports).unwrap().first_week, 26);
+
+        data.page(5, reports.len());
+        assert_eq!(data.reports_back, 2);
+        assert_eq!(data.selected(&reports).unwrap().first_week, 0);
+    }
+
+    #[test]
+    fn a_new_report_leaves_the_page_on_the_one_being_read() {
+        let mut data = ReportsData::default();
+        data.page(1, 2);
+        assert_eq!(data.selected(&reports(2)).unwrap().first_week, 0);
+
+        assert_eq!(data.selected(&reports(3)).unwrap().first_week, 13);
+    }
+
+    #[test]
+    fn there_is_nothing_to_page_without_reports() {
+        let mut data = ReportsData::default();
+        data.page(1, 0);
+
+        assert_eq!(data.reports_back, 0);
+        assert!(data.selected(&[]).is_none());
+    }
 }
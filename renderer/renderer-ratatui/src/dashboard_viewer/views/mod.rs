@@ -3,4 +3,5 @@ mod render_entities;
 mod render_entity_view;
 mod render_financials_chart;
 mod render_perception_chart;
+mod render_reports;
 mod render_satisfaction_chart;
@@ -1,29 +1,47 @@
 use crate::dashboard_viewer::navigation::NavigationStack;
 use crate::dashboard_viewer::routes::{DashboardData, EntityKind, Route, SelectedEntityKindData};
 use crate::dashboard_viewer::views::render_entities::render_entities;
+use crate::dashboard_viewer::views::render_reports::render_reports;
+use crate::final_report::render_final_report;
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Direction, Layout};
-use renderer_api::ClientHistoryState;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Color, Style};
+use ratatui::widgets::Paragraph;
+use renderer_api::{ClientGameState, ClientHistoryState};
 use std::collections::HashMap;
 
 pub fn render(
     navigation_stack: &mut NavigationStack,
     frame: &mut Frame,
+    client_game_state: &ClientGameState,
     client_history_state: &ClientHistoryState,
 ) {
     let outer_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0)])
+        .constraints([Constraint::Length(1), Constraint::Min(0)])
         .split(frame.area());
 
-    let main_area = outer_chunks[0];
+    let clock_area = outer_chunks[0];
+    let main_area = outer_chunks[1];
+    render_clock(frame, clock_area, client_game_state);
+    if let Some(outcome) = &client_game_state.board.outcome {
+        render_final_report(frame, main_area, outcome);
+        return;
+    }
     let current_mut = navigation_stack.current_mut();
 
     match current_mut {
         Route::Dashboard { data } => {
             populate_data(data, client_history_state);
-            render_entities(frame, main_area, client_history_state, data);
+            render_entities(
+                frame,
+                main_area,
+                client_game_state,
+                client_history_state,
+                data,
+            );
         }
+        Route::Reports { data } => render_reports(frame, main_area, client_history_state, data),
     }
 }
 
@@ -64,3 +82,19 @@ fn populate_data(dashboard_data: &mut DashboardData, client_history_state: &Clie
 
     dashboard_data.selected_entity_kind_data = selected_entity_kind_data;
 }
+
+fn render_clock(frame: &mut Frame, rect: Rect, client_game_state: &ClientGameState) {
+    let text = match client_game_state.seconds_until_next_week() {
+        Some(seconds) => format!(
+            "Week {} | Clock: {} | Next week in {}s | [o] Reports [v] Resolution",
+            client_game_state.week, client_game_state.clock.speed, seconds
+        ),
+        None => format!(
+            "Week {} | Clock: {} | [o] Reports [v] Resolution",
+            client_game_state.week, client_game_state.clock.speed
+        ),
+    };
+
+    let clock = Paragraph::new(text).style(Style::default().fg(Color::LightBlue));
+    frame.render_widget(clock, rect);
+}
@@ -4,13 +4,14 @@ use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Style};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState};
-use renderer_api::ClientHistoryState;
+use renderer_api::{ClientGameState, ClientHistoryState};
 use shared::HistoryState;
 use uuid::Uuid;
 
 pub fn render_entities(
     frame: &mut Frame,
     area: Rect,
+    client_game_state: &ClientGameState,
     client_history_state: &ClientHistoryState,
     dashboard_data: &DashboardData,
 ) {
@@ -22,17 +23,31 @@ pub fn render_entities(
     let sidebar_area = chunks[0];
     let main_area = chunks[1];
 
-    render_sidebar(frame, sidebar_area, client_history_state, dashboard_data);
-    render_entity_details(frame, main_area, client_history_state, dashboard_data);
+    render_sidebar(
+        frame,
+        sidebar_area,
+        client_game_state,
+        client_history_state,
+        dashboard_data,
+    );
+    render_entity_details(
+        frame,
+        main_area,
+        client_game_state,
+        client_history_state,
+        dashboard_data,
+    );
 }
 
 pub fn render_sidebar(
     frame: &mut Frame,
     area: Rect,
+    client_game_state: &ClientGameState,
     client_history_state: &ClientHistoryState,
     dashboard_data: &DashboardData,
 ) {
     let items: Vec<ListItem> = entity_labels(
+        client_game_state,
         &client_history_state.history_state,
         dashboard_data.entity_kind.clone(),
     )
@@ -57,7 +72,11 @@ pub fn render_sidebar(
     frame.render_stateful_widget(list, area, &mut state);
 }
 
-fn entity_labels(history: &HistoryState, kind: EntityKind) -> Vec<(&Uuid, String)> {
+fn entity_labels<'a>(
+    client_game_state: &ClientGameState,
+    history: &'a HistoryState,
+    kind: EntityKind,
+) -> Vec<(&'a Uuid, String)> {
     match kind {
         EntityKind::Player => history
             .players
@@ -72,7 +91,16 @@ fn entity_labels(history: &HistoryState, kind: EntityKind) -> Vec<(&Uuid, String
         EntityKind::Company => history
             .companies
             .keys()
-            .map(|id| (id, format!("Co {}", &id.to_string()[..8])))
+            .map(|id| (id, company_label(client_game_state, id)))
             .collect(),
     }
 }
+
+/// A company's name, marked as ours or a rival's.
+pub fn company_label(client_game_state: &ClientGameState, company_id: &Uuid) -> String {
+    match client_game_state.companies.get(company_id) {
+        Some(company) if company.rival.is_some() => format!("{} (rival)", company.name),
+        Some(company) => format!("{} (you)", company.name),
+        None => format!("Co {}", &company_id.to_string()[..8]),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::dashboard_viewer::routes::{DashboardData, EntityKind};
+use crate::dashboard_viewer::views::render_entities::company_label;
 use crate::dashboard_viewer::views::render_financials_chart::{
     render_financials_chart, render_financials_legend,
 };
@@ -10,25 +11,32 @@ use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Paragraph};
-use renderer_api::ClientHistoryState;
+use renderer_api::{ClientGameState, ClientHistoryState};
 use shared::{Financials, Perception};
 
 pub fn render_entity_details(
     frame: &mut Frame,
     area: Rect,
+    client_game_state: &ClientGameState,
     client_history_state: &ClientHistoryState,
     dashboard_data: &DashboardData,
 ) {
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(10),
+            Constraint::Length(11),
             Constraint::Length(15),
             Constraint::Min(8),
         ])
         .split(area);
 
-    render_header(frame, main_chunks[0], client_history_state, dashboard_data);
+    render_header(
+        frame,
+        main_chunks[0],
+        client_game_state,
+        client_history_state,
+        dashboard_data,
+    );
     render_charts(frame, main_chunks[1], client_history_state, dashboard_data);
     render_history_table(frame, main_chunks[2]);
 }
@@ -36,6 +44,7 @@ pub fn render_entity_details(
 pub fn render_header(
     frame: &mut Frame,
     area: Rect,
+    client_game_state: &ClientGameState,
     client_history_state: &ClientHistoryState,
     dashboard_data: &DashboardData,
 ) {
@@ -59,7 +68,18 @@ pub fn render_header(
                 if let Some(history) = player.recent_history.back() {
                     spans = vec![
                         Span::raw(format!("Player ID: {}\n", short_id(id))),
-                        Span::raw(format!("Cash: ${}\n", history.financials.actual_cash)),
+                        Span::raw(format!(
+                            "Personal Cash: ${}\n",
+                            history.financials.actual_cash
+                        )),
+                        Span::raw(format!(
+                            "Company Cash: ${}\n",
+                            client_game_state
+                                .player_company_id()
+                                .and_then(|company_id| client_game_state.companies.get(&company_id))
+                                .map(|company| company.financials.actual_cash)
+                                .unwrap_or_default()
+                        )),
                         Span::raw(format!(
                             "Income: ${}\n",
                             history.financials.this_weeks_income
@@ -116,7 +136,11 @@ pub fn render_header(
             if let Some((id, comp)) = companies.iter().nth(selected_index) {
                 if let Some(history) = comp.recent_history.back() {
                     spans = vec![
-                        Span::raw(format!("Company ID: {}\n", short_id(id))),
+                        Span::raw(format!(
+                            "Company: {} [{}]\n",
+                            company_label(client_game_state, id),
+                            short_id(id)
+                        )),
                         Span::raw(format!("Cash: ${}\n", history.financials.actual_cash)),
                         Span::raw(format!(
                             "Income: ${}\n",
@@ -135,6 +159,10 @@ pub fn render_header(
                             history.perception.public_opinion
                         )),
                         Span::raw(format!("Reputation: {}\n", history.perception.reputation)),
+                        Span::raw(format!(
+                            "Market Share: {}%\n",
+                            client_game_state.market_share_percent(id)
+                        )),
                         Span::raw(format!("History Points: {}", comp.recent_history.len())),
                     ];
                 }
@@ -185,19 +213,17 @@ pub fn render_charts(
         .get(&entity_kind)
         .map_or(0, |e| e.selected_index);
 
+    let history_state = client_history_state.history_at(dashboard_data.resolution);
     let entry = match entity_kind {
-        EntityKind::Player => client_history_state
-            .history_state
+        EntityKind::Player => history_state
             .players
             .get(&client_history_state.player_order[selected_index])
             .map(|player| &player.recent_history),
-        EntityKind::Company => client_history_state
-            .history_state
+        EntityKind::Company => history_state
             .companies
             .get(&client_history_state.company_order[selected_index])
             .map(|company| &company.recent_history),
-        EntityKind::Organization => client_history_state
-            .history_state
+        EntityKind::Organization => history_state
             .organizations
             .get(&client_history_state.organization_order[selected_index])
             .map(|organization| &organization.recent_history),
@@ -235,7 +261,7 @@ pub fn render_charts(
 
         let satisfaction_data: Vec<(u16, u16)> = entry
             .iter()
-            .map(|entry| (entry.week, entry.avg_employee_satisfaction))
+            .map(|entry| (entry.week, entry.avg_employee_satisfaction.get()))
             .collect();
         render_satisfaction_chart(frame, chart_chunks[2], &satisfaction_data, dashboard_data);
     }
@@ -8,7 +8,7 @@ pub fn render_financials_chart(
     frame: &mut Frame,
     area: Rect,
     client_history_state: &Vec<(u16, Financials)>,
-    _dashboard_data: &DashboardData,
+    dashboard_data: &DashboardData,
 ) {
     let mut min_y = f64::MAX;
     let mut max_y = f64::MIN;
@@ -22,10 +22,10 @@ pub fn render_financials_chart(
         let week = *week as f64;
 
         let values = [
-            f.actual_cash as f64,
-            f.this_weeks_income as f64,
-            f.this_weeks_expenses as f64,
-            f.this_weeks_net_profit as f64,
+            f.actual_cash.get() as f64,
+            f.this_weeks_income.get() as f64,
+            f.this_weeks_expenses.get() as f64,
+            f.this_weeks_net_profit.get() as f64,
         ];
 
         for v in values {
@@ -33,10 +33,10 @@ pub fn render_financials_chart(
             max_y = max_y.max(v);
         }
 
-        points_cash.push((week, f.actual_cash as f64));
-        points_income.push((week, f.this_weeks_income as f64));
-        points_expenses.push((week, f.this_weeks_expenses as f64));
-        points_net_profit.push((week, f.this_weeks_net_profit as f64));
+        points_cash.push((week, f.actual_cash.get() as f64));
+        points_income.push((week, f.this_weeks_income.get() as f64));
+        points_expenses.push((week, f.this_weeks_expenses.get() as f64));
+        points_net_profit.push((week, f.this_weeks_net_profit.get() as f64));
     }
 
     // Expand bounds slightly for padding
@@ -79,7 +79,11 @@ pub fn render_financials_chart(
     ];
 
     let chart = Chart::new(datasets)
-        .block(Block::default().title("Financials").borders(Borders::ALL))
+        .block(
+            Block::default()
+                .title(format!("Financials ({})", dashboard_data.resolution))
+                .borders(Borders::ALL),
+        )
         .x_axis(
             Axis::default()
                 .title("Week")
@@ -0,0 +1,88 @@
+use crate::dashboard_viewer::routes::ReportsData;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use renderer_api::ClientHistoryState;
+use shared::ReportLine;
+
+pub fn render_reports(
+    frame: &mut Frame,
+    area: Rect,
+    client_history_state: &ClientHistoryState,
+    reports_data: &ReportsData,
+) {
+    let reports = &client_history_state.reports;
+    let Some(report) = reports_data.selected(reports) else {
+        let paragraph = Paragraph::new("No reports yet, the first is filed after 13 weeks.")
+            .block(Block::default().title("Reports").borders(Borders::ALL));
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let [companies_area, organizations_area, help_area] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(report.companies.len() as u16 + 3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(area);
+
+    let title = format!(
+        "{} (weeks {}-{}), report {} of {}",
+        report,
+        report.first_week,
+        report.last_week,
+        reports.len() - reports_data.reports_back,
+        reports.len()
+    );
+    render_report_lines(frame, companies_area, &title, &report.companies);
+    render_report_lines(
+        frame,
+        organizations_area,
+        "Organizations",
+        &report.organizations,
+    );
+
+    let help = Paragraph::new("[←] Older [→] Newer [Esc] Back")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(help, help_area);
+}
+
+fn render_report_lines(frame: &mut Frame, area: Rect, title: &str, lines: &[ReportLine]) {
+    let header = format!(
+        "{:<24} {:>12} {:>12} {:>12} {:>6} {:>6} {:>6} {:>6}",
+        "Name", "Revenue", "Expenses", "Profit", "Staff", "Sat.", "Rep.", "Opin."
+    );
+    let rows = lines.iter().map(|line| {
+        format!(
+            "{:<24} {:>12} {:>12} {:>12} {:>6} {:>5}% {:>+6} {:>+6}",
+            truncated(&line.name, 24),
+            format!("${}", line.revenue),
+            format!("${}", line.expenses),
+            format!("${}", line.profit),
+            line.headcount,
+            line.avg_satisfaction,
+            line.reputation_change,
+            line.public_opinion_change
+        )
+    });
+    let text = [header]
+        .into_iter()
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let paragraph = Paragraph::new(text).block(
+        Block::default()
+            .title(title.to_string())
+            .borders(Borders::ALL)
+            .title_style(Style::default().add_modifier(Modifier::BOLD)),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+fn truncated(name: &str, width: usize) -> String {
+    name.chars().take(width).collect()
+}
@@ -0,0 +1,69 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use shared::{BoardDemandKind, GameOutcome, GameOverReason, VictoryReason};
+
+/// The screen a finished game is left on, in either renderer mode.
+pub(crate) fn render_final_report(frame: &mut Frame, rect: Rect, outcome: &GameOutcome) {
+    let (title, verdict, color) = match outcome {
+        GameOutcome::GameOver { reason, .. } => {
+            let verdict = match reason {
+                GameOverReason::LostConfidence => "The board has lost all confidence in you.",
+                GameOverReason::TooManyWarnings => "The board is done warning you. You're fired.",
+                GameOverReason::Bankrupt => "The company went bankrupt on your watch.",
+            };
+            ("Game Over", verdict, Color::Red)
+        }
+        GameOutcome::Victory { reason, .. } => {
+            let verdict = match reason {
+                VictoryReason::ValuationTarget => "You built a company worth the board's target.",
+                VictoryReason::FullTerm => "You served your full term with the board behind you.",
+            };
+            ("Victory", verdict, Color::Green)
+        }
+    };
+    let report = outcome.report();
+
+    let lines = [
+        verdict.to_string(),
+        String::new(),
+        format!("Company: {}", report.company_name),
+        format!("Weeks as CEO: {}", report.week),
+        format!("Company Cash: ${}", report.company_cash),
+        format!("Valuation: ${}", report.valuation),
+        format!("Debt: ${}", report.debt),
+        format!("Employees: {}", report.employees),
+        format!("Personal Cash: ${}", report.ceo_wealth),
+        format!(
+            "Equity: {}% (worth ${})",
+            report.equity_percent,
+            report.valuation.percent(report.equity_percent.get() as i64)
+        ),
+        format!("Board Confidence: {}%", report.board_confidence),
+        format!("Board Warnings: {}", report.warnings),
+        String::new(),
+        "[q] Quit".to_string(),
+    ];
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+        )
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, rect);
+}
+
+pub(crate) fn board_demand_label(kind: BoardDemandKind) -> String {
+    match kind {
+        BoardDemandKind::ReturnToProfit => "Return to profit".to_string(),
+        BoardDemandKind::RestoreReputation => "Restore the company's reputation".to_string(),
+        BoardDemandKind::ReduceDebt { below } => format!("Bring debt below ${}", below),
+        BoardDemandKind::CutCeoPay { salary } => format!("Cut your salary to ${}", salary),
+    }
+}
@@ -1,4 +1,5 @@
 mod dashboard_viewer;
+mod final_report;
 mod operator;
 mod plugin;
 mod system;
@@ -1,17 +1,56 @@
 use crate::operator::navigation::{NavigationAction, NavigationStack};
-use crate::operator::routes::{OrganizationList, OrganizationTab, OrganizationView, Route};
+use crate::operator::routes::{
+    CompensationView, CorporateEventView, HireOffer, InitiativePlan, LoanPlan, OrganizationList,
+    OrganizationTab, OrganizationView, Route,
+};
 use bevy::prelude::ResMut;
 use input_api::PlayerInputAction;
 use renderer_api::ClientGameState;
-use shared::{Budget, ClientActionCommand, PendingPlayerAction};
+use shared::{
+    Budget, ClientActionCommand, Employment, HIREABLE_ROLES, INITIATIVE_KINDS,
+    MAX_CEO_BONUS_PERCENT, MAX_INITIATIVE_WEEKS, MAX_LOAN_WEEKS, Money, OrganizationRole,
+    OrganizationType, PendingPlayerAction, Percent,
+};
+use uuid::Uuid;
 
-fn try_switch_tab(current: &Route) -> Option<Route> {
+const HIRE_OFFER_FIELDS: usize = 4;
+const INITIATIVE_PLAN_FIELDS: usize = 2;
+const INITIATIVE_BUDGET_STEP: Money = Money::new(500);
+const BUDGET_STEP: Money = Money::new(1);
+const SALARY_STEP: Money = Money::new(1);
+const SIGNING_BONUS_STEP: Money = Money::new(5);
+const COMPENSATION_ROWS: usize = 3;
+const LOAN_PLAN_FIELDS: usize = 2;
+const LOAN_STEP: Money = Money::new(5_000);
+const DEBT_STEP: Money = Money::new(1_000);
+const DEBT_AMOUNT: Money = Money::new(10_000);
+const CEO_SALARY_STEP: Money = Money::new(100);
+
+/// Steps through the hireable roles, wrapping around at either end.
+fn cycle_role(role: OrganizationRole, step: usize) -> OrganizationRole {
+    let index = HIREABLE_ROLES
+        .iter()
+        .position(|hireable| *hireable == role)
+        .unwrap_or(0);
+    HIREABLE_ROLES[(index + step) % HIREABLE_ROLES.len()]
+}
+
+fn try_switch_tab(current: &Route, client_game_state: &ClientGameState) -> Option<Route> {
     match current {
         Route::OrganizationView { data } => {
+            let is_finance = client_game_state
+                .organizations
+                .get(&data.organization_id)
+                .is_some_and(|organization| {
+                    organization.organization_type == OrganizationType::Finance
+                });
             let next_tab = match data.tab {
                 OrganizationTab::Detail => OrganizationTab::Budget,
                 OrganizationTab::Budget => OrganizationTab::Hiring,
-                OrganizationTab::Hiring => OrganizationTab::Detail,
+                OrganizationTab::Hiring => OrganizationTab::Initiatives,
+                OrganizationTab::Initiatives => OrganizationTab::Former,
+                OrganizationTab::Former if is_finance => OrganizationTab::Loans,
+                OrganizationTab::Former | OrganizationTab::Loans => OrganizationTab::Detail,
             };
             Some(Route::OrganizationView {
                 data: OrganizationView {
@@ -21,6 +60,10 @@ fn try_switch_tab(current: &Route) -> Option<Route> {
                     marketing: data.marketing,
                     rnd: data.rnd,
                     training: data.training,
+                    hire_offer: None,
+                    initiative_plan: None,
+                    loan_plan: None,
+                    debt_amount: data.debt_amount,
                 },
             })
         }
@@ -35,13 +78,27 @@ pub fn handle_input(
     pending_player_action: &mut ResMut<PendingPlayerAction>,
 ) {
     if let PlayerInputAction::MenuChangeTab = player_input_action {
-        if let Some(new_tab) = try_switch_tab(nav.current()) {
+        if let Some(new_tab) = try_switch_tab(nav.current(), client_game_state) {
             NavigationAction::Switch(new_tab).apply(nav);
         }
     }
 
-    let (company_id, _company) = client_game_state.companies.iter().next().unwrap();
+    let Some(company_id) = client_game_state.player_company_id() else {
+        return;
+    };
     match player_input_action {
+        PlayerInputAction::ClockSlower => {
+            pending_player_action.0 = Some(ClientActionCommand::SetClockSpeed {
+                speed: client_game_state.clock.speed.slower(),
+            });
+            return;
+        }
+        PlayerInputAction::ClockFaster => {
+            pending_player_action.0 = Some(ClientActionCommand::SetClockSpeed {
+                speed: client_game_state.clock.speed.faster(),
+            });
+            return;
+        }
         PlayerInputAction::MenuBack => {
             NavigationAction::Pop.apply(nav);
             return;
@@ -53,7 +110,7 @@ pub fn handle_input(
                         selected_index: 0,
                         organization_id: client_game_state
                             .ordered_organizations_of_company
-                            .get(company_id)
+                            .get(&company_id)
                             .map(|organizations| organizations[data.selected_index])
                             .unwrap(),
                         tab: OrganizationTab::Detail,
@@ -62,7 +119,7 @@ pub fn handle_input(
                             .get(
                                 &client_game_state
                                     .ordered_organizations_of_company
-                                    .get(company_id)
+                                    .get(&company_id)
                                     .map(|organizations| organizations[data.selected_index])
                                     .unwrap(),
                             )
@@ -74,7 +131,7 @@ pub fn handle_input(
                             .get(
                                 &client_game_state
                                     .ordered_organizations_of_company
-                                    .get(company_id)
+                                    .get(&company_id)
                                     .map(|organizations| organizations[data.selected_index])
                                     .unwrap(),
                             )
@@ -86,13 +143,32 @@ pub fn handle_input(
                             .get(
                                 &client_game_state
                                     .ordered_organizations_of_company
-                                    .get(company_id)
+                                    .get(&company_id)
                                     .map(|organizations| organizations[data.selected_index])
                                     .unwrap(),
                             )
                             .unwrap()
                             .budget
                             .training,
+                        hire_offer: None,
+                        initiative_plan: None,
+                        loan_plan: None,
+                        debt_amount: DEBT_AMOUNT,
+                    },
+                })
+                .apply(nav);
+            }
+        }
+        PlayerInputAction::OpenCompensation => {
+            if let (Route::OrganizationList { .. }, Some(player)) =
+                (nav.current(), client_game_state.players.first())
+            {
+                NavigationAction::Push(Route::Compensation {
+                    data: CompensationView {
+                        selected_index: 0,
+                        salary: player.compensation.salary,
+                        bonus_percent: player.compensation.bonus_percent,
+                        sell_percent: Percent::new(1),
                     },
                 })
                 .apply(nav);
@@ -108,6 +184,8 @@ pub fn handle_input(
         Route::OrganizationView { data } => {
             data.handle_input(player_input_action, client_game_state)
         }
+        Route::CorporateEvent { data } => data.handle_input(player_input_action, client_game_state),
+        Route::Compensation { data } => data.handle_input(player_input_action, client_game_state),
     };
 }
 
@@ -125,9 +203,9 @@ impl InputHandler for OrganizationList {
         action: PlayerInputAction,
         client_game_state: &ClientGameState,
     ) -> Option<ClientActionCommand> {
-        let (company_id, _company) = client_game_state.companies.iter().next().unwrap();
+        let company_id = client_game_state.player_company_id()?;
         match action {
-            PlayerInputAction::DoNothing => Some(ClientActionCommand::DoNothing),
+            PlayerInputAction::EndTurn => Some(ClientActionCommand::EndTurn),
             PlayerInputAction::MenuUp => {
                 self.selected_index = self.selected_index.saturating_sub(1);
                 None
@@ -136,7 +214,7 @@ impl InputHandler for OrganizationList {
                 self.selected_index = (self.selected_index + 1).min(
                     client_game_state
                         .ordered_organizations_of_company
-                        .get(company_id)
+                        .get(&company_id)
                         .map(|organizations| organizations.len() - 1)
                         .unwrap_or(0),
                 );
@@ -148,6 +226,109 @@ impl InputHandler for OrganizationList {
     }
 }
 
+impl InputHandler for CorporateEventView {
+    fn handle_input(
+        &mut self,
+        action: PlayerInputAction,
+        client_game_state: &ClientGameState,
+    ) -> Option<ClientActionCommand> {
+        let corporate_event = client_game_state
+            .corporate_events
+            .iter()
+            .find(|corporate_event| corporate_event.id == self.corporate_event_id)?;
+        match action {
+            PlayerInputAction::EndTurn => Some(ClientActionCommand::EndTurn),
+            PlayerInputAction::MenuUp => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+                None
+            }
+            PlayerInputAction::MenuDown => {
+                self.selected_index =
+                    (self.selected_index + 1).min(corporate_event.choices.len().saturating_sub(1));
+                None
+            }
+            PlayerInputAction::MenuCommit => Some(ClientActionCommand::ResolveCorporateEvent {
+                corporate_event_id: self.corporate_event_id,
+                choice: *corporate_event.choices.get(self.selected_index)?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl InputHandler for CompensationView {
+    fn handle_input(
+        &mut self,
+        action: PlayerInputAction,
+        _client_game_state: &ClientGameState,
+    ) -> Option<ClientActionCommand> {
+        match action {
+            PlayerInputAction::EndTurn => Some(ClientActionCommand::EndTurn),
+            PlayerInputAction::MenuUp => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+                None
+            }
+            PlayerInputAction::MenuDown => {
+                self.selected_index = (self.selected_index + 1).min(COMPENSATION_ROWS - 1);
+                None
+            }
+            PlayerInputAction::MenuDecrement => {
+                match self.selected_index {
+                    0 => self.salary = (self.salary - CEO_SALARY_STEP).max(Money::ZERO),
+                    1 => self.bonus_percent = self.bonus_percent.saturating_sub(1),
+                    2 => {
+                        self.sell_percent = self.sell_percent.saturating_sub(1).max(Percent::new(1))
+                    }
+                    _ => {}
+                }
+                None
+            }
+            PlayerInputAction::MenuIncrement => {
+                match self.selected_index {
+                    0 => self.salary += CEO_SALARY_STEP,
+                    1 => {
+                        self.bonus_percent = self
+                            .bonus_percent
+                            .saturating_add(1)
+                            .min(Percent::new(MAX_CEO_BONUS_PERCENT))
+                    }
+                    2 => self.sell_percent = self.sell_percent.saturating_add(1),
+                    _ => {}
+                }
+                None
+            }
+            PlayerInputAction::MenuCommit => match self.selected_index {
+                2 => Some(ClientActionCommand::SellEquity {
+                    percent: self.sell_percent,
+                }),
+                _ => Some(ClientActionCommand::SetCeoCompensation {
+                    salary: self.salary,
+                    bonus_percent: self.bonus_percent,
+                }),
+            },
+            _ => None,
+        }
+    }
+}
+
+impl OrganizationView {
+    fn selected_employee<'a>(
+        &self,
+        client_game_state: &'a ClientGameState,
+    ) -> Option<(Uuid, &'a Employment)> {
+        let employee_id = client_game_state
+            .ordered_employees_of_organization
+            .get(&self.organization_id)?
+            .get(self.selected_index)?;
+        let employment = client_game_state
+            .entities
+            .get(employee_id)?
+            .employment
+            .as_ref()?;
+        Some((*employee_id, employment))
+    }
+}
+
 impl InputHandler for OrganizationView {
     fn handle_input(
         &mut self,
@@ -190,9 +371,45 @@ impl InputHandler for OrganizationView {
                     .get(self.selected_index)
                     .map(|employee_id| ClientActionCommand::GiveRaise {
                         employee_id: *employee_id,
-                        amount: 1_000,
+                        amount: Money::new(1_000),
                     }),
 
+                PlayerInputAction::SelectEmployeeForPromotion => {
+                    let (employee_id, employment) = self.selected_employee(client_game_state)?;
+                    Some(ClientActionCommand::PromoteEmployee {
+                        employee_id,
+                        role: None,
+                        level: employment.level + 1,
+                    })
+                }
+
+                PlayerInputAction::SelectEmployeeForDemotion => {
+                    let (employee_id, employment) = self.selected_employee(client_game_state)?;
+                    Some(ClientActionCommand::DemoteEmployee {
+                        employee_id,
+                        role: None,
+                        level: employment.level.saturating_sub(1),
+                    })
+                }
+
+                PlayerInputAction::SelectEmployeeForTransfer => {
+                    // Sends the employee to the next organization of the company, in list order.
+                    let (employee_id, employment) = self.selected_employee(client_game_state)?;
+                    let organizations = client_game_state
+                        .ordered_organizations_of_company
+                        .values()
+                        .find(|organizations| {
+                            organizations.contains(&employment.organization_id)
+                        })?;
+                    let position = organizations
+                        .iter()
+                        .position(|id| *id == employment.organization_id)?;
+                    Some(ClientActionCommand::TransferEmployee {
+                        employee_id,
+                        organization_id: organizations[(position + 1) % organizations.len()],
+                    })
+                }
+
                 PlayerInputAction::SelectEmployeeToFire => client_game_state
                     .ordered_employees_of_organization
                     .get(&self.organization_id)?
@@ -215,18 +432,18 @@ impl InputHandler for OrganizationView {
                 }
                 PlayerInputAction::MenuDecrement => {
                     match self.selected_index {
-                        0 => self.marketing -= 1,
-                        1 => self.rnd -= 1,
-                        2 => self.training -= 1,
+                        0 => self.marketing = (self.marketing - BUDGET_STEP).max(Money::ZERO),
+                        1 => self.rnd = (self.rnd - BUDGET_STEP).max(Money::ZERO),
+                        2 => self.training = (self.training - BUDGET_STEP).max(Money::ZERO),
                         _ => {}
                     }
                     None
                 }
                 PlayerInputAction::MenuIncrement => {
                     match self.selected_index {
-                        0 => self.marketing += 1,
-                        1 => self.rnd += 1,
-                        2 => self.training += 1,
+                        0 => self.marketing += BUDGET_STEP,
+                        1 => self.rnd += BUDGET_STEP,
+                        2 => self.training += BUDGET_STEP,
                         _ => {}
                     }
                     None
@@ -241,10 +458,265 @@ impl InputHandler for OrganizationView {
                 }),
                 _ => None,
             },
-            OrganizationTab::Hiring => match action {
+            OrganizationTab::Hiring => match self.hire_offer.as_mut() {
+                Some(offer) => match action {
+                    PlayerInputAction::MenuDown => {
+                        offer.field = (offer.field + 1).min(HIRE_OFFER_FIELDS - 1);
+                        None
+                    }
+                    PlayerInputAction::MenuUp => {
+                        offer.field = offer.field.saturating_sub(1);
+                        None
+                    }
+                    PlayerInputAction::MenuDecrement => {
+                        match offer.field {
+                            0 => offer.role = cycle_role(offer.role, HIREABLE_ROLES.len() - 1),
+                            1 => offer.level = offer.level.saturating_sub(1).max(1),
+                            2 => {
+                                offer.salary_offer =
+                                    (offer.salary_offer - SALARY_STEP).max(Money::ZERO)
+                            }
+                            3 => {
+                                offer.signing_bonus =
+                                    (offer.signing_bonus - SIGNING_BONUS_STEP).max(Money::ZERO)
+                            }
+                            _ => {}
+                        }
+                        None
+                    }
+                    PlayerInputAction::MenuIncrement => {
+                        match offer.field {
+                            0 => offer.role = cycle_role(offer.role, 1),
+                            1 => offer.level = offer.level.saturating_add(1),
+                            2 => offer.salary_offer += SALARY_STEP,
+                            3 => offer.signing_bonus += SIGNING_BONUS_STEP,
+                            _ => {}
+                        }
+                        None
+                    }
+                    PlayerInputAction::MenuCommit => {
+                        let offer = self.hire_offer.take()?;
+                        Some(ClientActionCommand::HireEmployee {
+                            employee_id: offer.candidate_id,
+                            organization_id: self.organization_id,
+                            role: offer.role,
+                            level: offer.level,
+                            salary_offer: offer.salary_offer,
+                            signing_bonus: Some(offer.signing_bonus)
+                                .filter(|bonus| *bonus > Money::ZERO),
+                        })
+                    }
+                    PlayerInputAction::SelectEmployeeToHire => {
+                        self.hire_offer = None;
+                        None
+                    }
+                    _ => None,
+                },
+                None => match action {
+                    PlayerInputAction::MenuDown => {
+                        let candidate_count = client_game_state.ordered_candidates.len();
+                        self.selected_index =
+                            (self.selected_index + 1).min(candidate_count.saturating_sub(1));
+                        None
+                    }
+
+                    PlayerInputAction::MenuUp => {
+                        self.selected_index = self.selected_index.saturating_sub(1);
+                        None
+                    }
+
+                    PlayerInputAction::SelectEmployeeToHire => {
+                        // Opens the offer on the candidate's own terms, sending it is a separate step.
+                        let candidate_id = client_game_state
+                            .ordered_candidates
+                            .get(self.selected_index)?;
+                        let candidate = client_game_state.candidates.get(candidate_id)?;
+                        self.hire_offer = Some(HireOffer {
+                            candidate_id: *candidate_id,
+                            field: 0,
+                            role: candidate.preferred_roles[0],
+                            level: candidate.level,
+                            salary_offer: candidate.asking_salary,
+                            signing_bonus: Money::ZERO,
+                        });
+                        None
+                    }
+
+                    _ => None,
+                },
+            },
+            OrganizationTab::Initiatives => match self.initiative_plan.as_mut() {
+                Some(plan) => match action {
+                    PlayerInputAction::MenuDown => {
+                        plan.field = (plan.field + 1).min(INITIATIVE_PLAN_FIELDS - 1);
+                        None
+                    }
+                    PlayerInputAction::MenuUp => {
+                        plan.field = plan.field.saturating_sub(1);
+                        None
+                    }
+                    PlayerInputAction::MenuDecrement => {
+                        match plan.field {
+                            0 => plan.weeks = plan.weeks.saturating_sub(1).max(1),
+                            1 => {
+                                plan.budget = (plan.budget - INITIATIVE_BUDGET_STEP)
+                                    .max(INITIATIVE_BUDGET_STEP)
+                            }
+                            _ => {}
+                        }
+                        None
+                    }
+                    PlayerInputAction::MenuIncrement => {
+                        match plan.field {
+                            0 => plan.weeks = (plan.weeks + 1).min(MAX_INITIATIVE_WEEKS),
+                            1 => plan.budget += INITIATIVE_BUDGET_STEP,
+                            _ => {}
+                        }
+                        None
+                    }
+                    PlayerInputAction::MenuCommit => {
+                        let plan = self.initiative_plan.take()?;
+                        Some(ClientActionCommand::LaunchInitiative {
+                            organization_id: self.organization_id,
+                            kind: plan.kind,
+                            weeks: plan.weeks,
+                            budget: plan.budget,
+                        })
+                    }
+                    PlayerInputAction::PlanInitiative => {
+                        self.initiative_plan = None;
+                        None
+                    }
+                    _ => None,
+                },
+                None => match action {
+                    PlayerInputAction::MenuDown => {
+                        self.selected_index =
+                            (self.selected_index + 1).min(INITIATIVE_KINDS.len() - 1);
+                        None
+                    }
+                    PlayerInputAction::MenuUp => {
+                        self.selected_index = self.selected_index.saturating_sub(1);
+                        None
+                    }
+                    PlayerInputAction::PlanInitiative => {
+                        self.initiative_plan = Some(InitiativePlan {
+                            kind: *INITIATIVE_KINDS.get(self.selected_index)?,
+                            field: 0,
+                            weeks: 4,
+                            budget: Money::new(5_000),
+                        });
+                        None
+                    }
+                    PlayerInputAction::CancelInitiative => {
+                        Some(ClientActionCommand::CancelInitiative {
+                            organization_id: self.organization_id,
+                            kind: *INITIATIVE_KINDS.get(self.selected_index)?,
+                        })
+                    }
+                    _ => None,
+                },
+            },
+            OrganizationTab::Loans => match self.loan_plan.as_mut() {
+                Some(plan) => match action {
+                    PlayerInputAction::MenuDown => {
+                        plan.field = (plan.field + 1).min(LOAN_PLAN_FIELDS - 1);
+                        None
+                    }
+                    PlayerInputAction::MenuUp => {
+                        plan.field = plan.field.saturating_sub(1);
+                        None
+                    }
+                    PlayerInputAction::MenuDecrement => {
+                        match plan.field {
+                            0 => plan.amount = (plan.amount - LOAN_STEP).max(LOAN_STEP),
+                            1 => plan.weeks = plan.weeks.saturating_sub(1).max(1),
+                            _ => {}
+                        }
+                        None
+                    }
+                    PlayerInputAction::MenuIncrement => {
+                        match plan.field {
+                            0 => plan.amount += LOAN_STEP,
+                            1 => plan.weeks = (plan.weeks + 1).min(MAX_LOAN_WEEKS),
+                            _ => {}
+                        }
+                        None
+                    }
+                    PlayerInputAction::MenuCommit => {
+                        let plan = self.loan_plan.take()?;
+                        Some(ClientActionCommand::TakeLoan {
+                            organization_id: self.organization_id,
+                            amount: plan.amount,
+                            weeks: plan.weeks,
+                        })
+                    }
+                    PlayerInputAction::PlanLoan => {
+                        self.loan_plan = None;
+                        None
+                    }
+                    _ => None,
+                },
+                None => {
+                    // One row per loan, then the credit line.
+                    let loans = &client_game_state
+                        .player_company_id()
+                        .and_then(|company_id| client_game_state.companies.get(&company_id))?
+                        .debt
+                        .loans;
+                    match action {
+                        PlayerInputAction::MenuDown => {
+                            self.selected_index = (self.selected_index + 1).min(loans.len());
+                            None
+                        }
+                        PlayerInputAction::MenuUp => {
+                            self.selected_index = self.selected_index.saturating_sub(1);
+                            None
+                        }
+                        PlayerInputAction::MenuDecrement => {
+                            self.debt_amount = (self.debt_amount - DEBT_STEP).max(DEBT_STEP);
+                            None
+                        }
+                        PlayerInputAction::MenuIncrement => {
+                            self.debt_amount += DEBT_STEP;
+                            None
+                        }
+                        PlayerInputAction::PlanLoan => {
+                            self.loan_plan = Some(LoanPlan {
+                                field: 0,
+                                amount: Money::new(50_000),
+                                weeks: 52,
+                            });
+                            None
+                        }
+                        PlayerInputAction::MenuCommit if self.selected_index == loans.len() => {
+                            Some(ClientActionCommand::DrawCredit {
+                                organization_id: self.organization_id,
+                                amount: self.debt_amount,
+                            })
+                        }
+                        PlayerInputAction::RepayDebt => match loans.get(self.selected_index) {
+                            Some(loan) => Some(ClientActionCommand::RepayLoan {
+                                organization_id: self.organization_id,
+                                loan_id: loan.id,
+                                amount: self.debt_amount.min(loan.balance),
+                            }),
+                            None => Some(ClientActionCommand::RepayCredit {
+                                organization_id: self.organization_id,
+                                amount: self.debt_amount,
+                            }),
+                        },
+                        _ => None,
+                    }
+                }
+            },
+            OrganizationTab::Former => match action {
                 PlayerInputAction::MenuDown => {
-                    let unemployed_count = client_game_state.ordered_unemployed_entities.len();
-                    self.selected_index = (self.selected_index + 1).min(unemployed_count - 1);
+                    let former_count = client_game_state
+                        .former_employees_of_organization(&self.organization_id)
+                        .len();
+                    self.selected_index =
+                        (self.selected_index + 1).min(former_count.saturating_sub(1));
                     None
                 }
 
@@ -253,20 +725,6 @@ impl InputHandler for OrganizationView {
                     None
                 }
 
-                PlayerInputAction::SelectEmployeeToHire => {
-                    if let Some(employee_id) = client_game_state
-                        .ordered_unemployed_entities
-                        .get(self.selected_index)
-                    {
-                        Some(ClientActionCommand::HireEmployee {
-                            employee_id: *employee_id,
-                            organization_id: self.organization_id,
-                        })
-                    } else {
-                        None
-                    }
-                }
-
                 _ => None,
             },
         }
@@ -1,4 +1,5 @@
-use crate::operator::routes::{OrganizationList, Route};
+use crate::operator::routes::{CorporateEventView, OrganizationList, Route};
+use renderer_api::ClientGameState;
 
 pub enum NavigationAction {
     Push(Route),
@@ -64,3 +65,24 @@ impl NavigationStack {
         }
     }
 }
+
+/// Keeps a pending corporate event on top of the stack: pushed when one comes in, popped once
+/// it has been decided.
+pub fn sync_corporate_event(nav: &mut NavigationStack, client_game_state: &ClientGameState) {
+    let pending_id = client_game_state
+        .pending_corporate_event()
+        .map(|corporate_event| corporate_event.id);
+
+    match (nav.current(), pending_id) {
+        (Route::CorporateEvent { data }, Some(pending_id))
+            if data.corporate_event_id == pending_id => {}
+        (Route::CorporateEvent { .. }, _) => nav.pop(),
+        (_, Some(corporate_event_id)) => nav.push(Route::CorporateEvent {
+            data: CorporateEventView {
+                corporate_event_id,
+                selected_index: 0,
+            },
+        }),
+        (_, None) => {}
+    }
+}
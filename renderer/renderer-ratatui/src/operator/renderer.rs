@@ -1,5 +1,5 @@
 use crate::operator::input::handle_input;
-use crate::operator::navigation::NavigationStack;
+use crate::operator::navigation::{NavigationStack, sync_corporate_event};
 use crate::operator::views::render::render;
 use bevy::prelude::{Res, ResMut};
 use input_api::{PendingPlayerInputAction, PlayerInputAction};
@@ -14,7 +14,10 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::{CompletedFrame, Terminal};
 use renderer_api::{ClientGameState, ClientHistoryState, Renderer};
 use shared::ClientMessage::{CreateGame, DeleteGame, ListGames};
-use shared::{ConnectionState, ConnectionStateResource, PendingClientMessage, PendingPlayerAction};
+use shared::{
+    ConnectionState, ConnectionStateResource, PendingClientMessage, PendingPlayerAction,
+    WorldGenerationParameters,
+};
 use std::io;
 use std::str::FromStr;
 use tracing::{debug, error};
@@ -80,6 +83,7 @@ impl RatatuiOperatorRenderer {
                     frame.render_widget(paragraph, size);
                 }
                 ConnectionState::Connected => {
+                    sync_corporate_event(&mut self.navigation_stack, client_game_state);
                     if let Some(action) = pending_player_input_action.0.take() {
                         handle_input(
                             action,
@@ -121,7 +125,14 @@ impl Renderer for RatatuiOperatorRenderer {
                 .map(char::from)
                 .collect();
 
-            pending_client_message.0 = Some(CreateGame { game_name });
+            pending_client_message.0 = Some(CreateGame {
+                game_name,
+                world_parameters: WorldGenerationParameters {
+                    seed: rand::random(),
+                    ..Default::default()
+                },
+                tick_mode: Default::default(),
+            });
         }
 
         if let Some(PlayerInputAction::ListGames) = &pending_player_input_action.0 {
@@ -1,3 +1,4 @@
+use shared::{InitiativeKind, Money, OrganizationRole, Percent};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -10,6 +11,41 @@ pub enum OrganizationTab {
     Detail,
     Budget,
     Hiring,
+    Initiatives,
+    Former,
+    /// Only Finance organizations have it.
+    Loans,
+}
+
+/// The offer being put together for a job seeker on the hiring tab. `field` is the selected
+/// line of the form: role, level, salary, then signing bonus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HireOffer {
+    pub candidate_id: Uuid,
+    pub field: usize,
+    pub role: OrganizationRole,
+    pub level: u16,
+    pub salary_offer: Money,
+    pub signing_bonus: Money,
+}
+
+/// An initiative being planned on the initiatives tab. `field` is the selected line of the
+/// form: weeks, then budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitiativePlan {
+    pub kind: InitiativeKind,
+    pub field: usize,
+    pub weeks: u16,
+    pub budget: Money,
+}
+
+/// A loan being arranged on the loans tab. `field` is the selected line of the form: amount,
+/// then weeks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoanPlan {
+    pub field: usize,
+    pub amount: Money,
+    pub weeks: u16,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,13 +53,38 @@ pub struct OrganizationView {
     pub selected_index: usize,
     pub organization_id: Uuid,
     pub tab: OrganizationTab,
-    pub marketing: u16,
-    pub rnd: u16,
-    pub training: u16,
+    pub marketing: Money,
+    pub rnd: Money,
+    pub training: Money,
+    pub hire_offer: Option<HireOffer>,
+    pub initiative_plan: Option<InitiativePlan>,
+    pub loan_plan: Option<LoanPlan>,
+    /// What the loans tab draws from the credit line or pays back at once.
+    pub debt_amount: Money,
+}
+
+/// A corporate event waiting for the player's decision, shown over everything else until
+/// it is resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CorporateEventView {
+    pub corporate_event_id: Uuid,
+    pub selected_index: usize,
+}
+
+/// The CEO's pay and stake. The first two rows edit the salary and bonus the board is asked
+/// for, the third picks how much equity to sell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompensationView {
+    pub selected_index: usize,
+    pub salary: Money,
+    pub bonus_percent: Percent,
+    pub sell_percent: Percent,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Route {
     OrganizationList { data: OrganizationList },
     OrganizationView { data: OrganizationView },
+    CorporateEvent { data: CorporateEventView },
+    Compensation { data: CompensationView },
 }
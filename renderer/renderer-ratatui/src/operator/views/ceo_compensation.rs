@@ -0,0 +1,82 @@
+use crate::operator::routes::CompensationView;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use renderer_api::ClientGameState;
+use uuid::Uuid;
+
+pub fn render_ceo_compensation(
+    client_game_state: &ClientGameState,
+    frame: &mut Frame,
+    main_area: &Rect,
+    company_id: &Uuid,
+    data: &CompensationView,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(30), Constraint::Percentage(70)])
+        .split(*main_area);
+
+    let items = [
+        format!("Salary (p/w): ${}", data.salary),
+        format!("Bonus: {}% of profit", data.bonus_percent),
+        format!("Sell: {}% of the company", data.sell_percent),
+    ]
+    .into_iter()
+    .map(ListItem::new)
+    .collect::<Vec<_>>();
+
+    let mut state = ListState::default();
+    state.select(Some(data.selected_index));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("CEO Compensation")
+                .borders(Borders::ALL),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(Color::White)
+                .bg(Color::Blue)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("➤ ");
+
+    frame.render_stateful_widget(list, chunks[0], &mut state);
+
+    let Some(player) = client_game_state.players.first() else {
+        return;
+    };
+    let valuation = client_game_state
+        .companies
+        .get(company_id)
+        .map(|company| company.valuation())
+        .unwrap_or_default();
+    let compensation = &player.compensation;
+
+    let lines = [
+        format!("Personal Cash: ${}", player.financials.actual_cash),
+        format!("Last Week's Pay: ${}", player.financials.this_weeks_income),
+        format!("Salary (p/w): ${}", compensation.salary),
+        format!(
+            "Bonus: {}% of profit after salary",
+            compensation.bonus_percent
+        ),
+        format!(
+            "Equity: {}% (worth ${})",
+            compensation.equity_percent,
+            valuation.percent(compensation.equity_percent.get() as i64)
+        ),
+        format!("Company Valuation: ${}", valuation),
+        "—".to_string(),
+        "A raise costs the CEO reputation with the board, and so does selling stock.".to_string(),
+    ];
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(Block::default().title("CEO").borders(Borders::ALL))
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, chunks[1]);
+}
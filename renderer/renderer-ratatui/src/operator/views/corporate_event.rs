@@ -0,0 +1,111 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use renderer_api::ClientGameState;
+use shared::{CorporateEventChoice, CorporateEventKind};
+use uuid::Uuid;
+
+pub fn corporate_event_headline(kind: CorporateEventKind) -> &'static str {
+    match kind {
+        CorporateEventKind::DataBreach => "Data breach",
+        CorporateEventKind::WarehouseAccident => "Warehouse accident",
+        CorporateEventKind::ViralMarketing => "Viral marketing success",
+        CorporateEventKind::Whistleblower => "Whistleblower",
+        CorporateEventKind::SurpriseAudit => "Surprise audit",
+    }
+}
+
+fn corporate_event_story(kind: CorporateEventKind) -> &'static str {
+    match kind {
+        CorporateEventKind::DataBreach => {
+            "Attackers got into the systems and customer data has leaked."
+        }
+        CorporateEventKind::WarehouseAccident => {
+            "A worker was hurt on the floor and the press is asking questions."
+        }
+        CorporateEventKind::ViralMarketing => "A campaign took off on its own overnight.",
+        CorporateEventKind::Whistleblower => {
+            "An unhappy employee has gone to the press about how things are run."
+        }
+        CorporateEventKind::SurpriseAudit => "The auditors came by unannounced and sent a bill.",
+    }
+}
+
+fn choice_description(choice: CorporateEventChoice) -> &'static str {
+    match choice {
+        CorporateEventChoice::Disclose => "Disclose it and compensate customers ($50000)",
+        CorporateEventChoice::CoverUp => "Patch it quietly and hope nobody notices",
+        CorporateEventChoice::CompensateAndFix => "Compensate and fix safety ($30000)",
+        CorporateEventChoice::MinimalResponse => "Do the legal minimum",
+        CorporateEventChoice::Investigate => "Open an independent investigation ($20000)",
+        CorporateEventChoice::Dismiss => "Dismiss the claims",
+    }
+}
+
+pub fn render_corporate_event(
+    client_game_state: &ClientGameState,
+    frame: &mut Frame,
+    main_area: &Rect,
+    corporate_event_id: &Uuid,
+    selected_index: &usize,
+) {
+    let Some(corporate_event) = client_game_state
+        .corporate_events
+        .iter()
+        .find(|corporate_event| corporate_event.id == *corporate_event_id)
+    else {
+        return;
+    };
+    let organization_name = client_game_state
+        .organizations
+        .get(&corporate_event.organization_id)
+        .map(|organization| organization.name.as_str())
+        .unwrap_or("Unknown organization");
+
+    let [popup] = Layout::vertical([Constraint::Length(12)])
+        .flex(Flex::Center)
+        .areas(*main_area);
+    let [popup] = Layout::horizontal([Constraint::Percentage(60)])
+        .flex(Flex::Center)
+        .areas(popup);
+    let [story_area, choices_area] =
+        Layout::vertical([Constraint::Length(5), Constraint::Min(0)]).areas(popup);
+
+    frame.render_widget(Clear, popup);
+
+    let story = Paragraph::new(format!(
+        "{}\n{}\nUndecided by the end of next week, the last choice is taken.",
+        organization_name,
+        corporate_event_story(corporate_event.kind)
+    ))
+    .block(
+        Block::default()
+            .title(corporate_event_headline(corporate_event.kind))
+            .borders(Borders::ALL),
+    )
+    .style(Style::default().fg(Color::Yellow))
+    .wrap(Wrap { trim: true });
+    frame.render_widget(story, story_area);
+
+    let items: Vec<ListItem> = corporate_event
+        .choices
+        .iter()
+        .map(|choice| ListItem::new(choice_description(*choice)))
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some((*selected_index).min(items.len().saturating_sub(1))));
+
+    let list = List::new(items)
+        .block(Block::default().title("Your call").borders(Borders::ALL))
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("➤ ");
+
+    frame.render_stateful_widget(list, choices_area, &mut state);
+}
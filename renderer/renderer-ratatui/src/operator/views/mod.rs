@@ -1,16 +1,33 @@
+pub mod ceo_compensation;
+pub mod corporate_event;
 mod organization_budget;
 mod organization_details;
+mod organization_former;
 pub mod organization_hiring;
+mod organization_initiatives;
 pub mod organization_list;
+mod organization_loans;
 pub mod organization_view;
 pub mod render;
 
+pub use ceo_compensation::*;
+pub use corporate_event::*;
 pub use organization_hiring::*;
 pub use organization_list::*;
 pub use organization_view::*;
 
+use shared::InitiativeKind;
+
 fn get_age_description(weeks: u32) -> String {
     let years = weeks / 52;
     let remaining_weeks = weeks % 52;
     format!("{} years, {} weeks", years, remaining_weeks)
 }
+
+fn initiative_label(kind: InitiativeKind) -> &'static str {
+    match kind {
+        InitiativeKind::Marketing => "Marketing",
+        InitiativeKind::Training => "Training",
+        InitiativeKind::RnD => "R&D",
+    }
+}
@@ -3,6 +3,7 @@ use ratatui::layout::Rect;
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
 use renderer_api::ClientGameState;
+use shared::Money;
 use uuid::Uuid;
 
 #[allow(clippy::too_many_arguments)]
@@ -13,9 +14,9 @@ pub fn render_organization_budget(
     right_pane: &Rect,
     organization_id: &Uuid,
     selected_index: &usize,
-    marketing: &u16,
-    training: &u16,
-    rnd: &u16,
+    marketing: &Money,
+    training: &Money,
+    rnd: &Money,
 ) {
     let items = ["Marketing", "R&D", "Training"]
         .iter()
@@ -142,6 +142,7 @@ pub fn draw_employee_details(
 
     let mut lines: Vec<String>;
     if let Some(employed) = employee.employment.clone() {
+        let pay_band = employed.role.pay_band(employed.level);
         lines = vec![
             format!("Name: {}", employee.name),
             format!("Type: {:?}", employee.entity_type),
@@ -155,8 +156,24 @@ pub fn draw_employee_details(
             format!("Level: {}", employed.level),
             format!("Satisfaction: {}", employed.satisfaction),
             format!("Salary (p/w): ${}", employed.salary),
+            format!("Pay Band: ${} - ${}", pay_band.min, pay_band.max),
+            format!(
+                "Tenure: {} (raises: {})",
+                get_age_description(employed.weeks_employed as u32),
+                employed.raises
+            ),
         ];
 
+        if !employed.employee_flags.is_empty() {
+            let flags_str = employed
+                .employee_flags
+                .iter()
+                .map(|flag| format!("{:?}", flag))
+                .collect::<Vec<_>>()
+                .join(", ");
+            lines.push(format!("Flags: {}", flags_str));
+        }
+
         // Show traits/flags if any
         if !employee.flags.is_empty() {
             let traits_str = employee
@@ -0,0 +1,99 @@
+use ratatui::Frame;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use renderer_api::ClientGameState;
+use shared::FormerEmployee;
+use uuid::Uuid;
+
+pub fn render_former_employees(
+    client_game_state: &ClientGameState,
+    frame: &mut Frame,
+    left_pane: &Rect,
+    right_pane: &Rect,
+    organization_id: &Uuid,
+    selected_index: &usize,
+) {
+    let former_employees = client_game_state.former_employees_of_organization(organization_id);
+
+    draw_former_employee_list(
+        frame,
+        left_pane,
+        client_game_state,
+        &former_employees,
+        *selected_index,
+    );
+
+    if let Some(former) = former_employees.get(*selected_index) {
+        draw_former_employee_details(frame, right_pane, client_game_state, former);
+    }
+}
+
+fn draw_former_employee_list(
+    frame: &mut Frame,
+    rect: &Rect,
+    client_game_state: &ClientGameState,
+    former_employees: &[&FormerEmployee],
+    selected_index: usize,
+) {
+    let items: Vec<ListItem> = former_employees
+        .iter()
+        .map(|former| ListItem::new(former_employee_name(client_game_state, former)))
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(selected_index.min(items.len().saturating_sub(1))));
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("Former Employees")
+                .borders(Borders::ALL),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("➤ ");
+
+    frame.render_stateful_widget(list, *rect, &mut state);
+}
+
+fn draw_former_employee_details(
+    frame: &mut Frame,
+    rect: &Rect,
+    client_game_state: &ClientGameState,
+    former: &FormerEmployee,
+) {
+    let mut lines = Vec::new();
+
+    lines.push(format!(
+        "Name: {}",
+        former_employee_name(client_game_state, former)
+    ));
+    lines.push(format!("Role: {:?}", former.role));
+    lines.push(format!("Left: Week {}", former.week));
+    lines.push(format!("Status: {:?}", former.status));
+    lines.push(format!("Reason: {:?}", former.reason));
+    lines.push(format!("ID: {}", former.entity_id));
+
+    let block = Block::default()
+        .title("Departure Details")
+        .borders(Borders::ALL);
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(block)
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, *rect);
+}
+
+fn former_employee_name(client_game_state: &ClientGameState, former: &FormerEmployee) -> String {
+    client_game_state
+        .entities
+        .get(&former.entity_id)
+        .map(|entity| entity.name.clone())
+        .unwrap_or_else(|| former.entity_id.to_string())
+}
@@ -1,5 +1,6 @@
+use crate::operator::routes::HireOffer;
 use crate::operator::views::get_age_description;
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Wrap};
 use ratatui::{Frame, widgets::Paragraph};
@@ -14,54 +15,95 @@ pub fn render_hiring(
     right_pane: &Rect,
     organization_id: &Uuid,
     selected_index: &usize,
+    hire_offer: Option<&HireOffer>,
 ) {
     let organization = client_game_state
         .organizations
         .get(organization_id)
         .unwrap();
 
-    draw_unemployed_list(
+    draw_candidate_list(
         frame,
         left_pane,
         client_game_state,
-        &client_game_state.ordered_unemployed_entities,
+        &client_game_state.ordered_candidates,
         *selected_index,
     );
 
-    if let Some(person) = client_game_state
-        .ordered_unemployed_entities
-        .get(*selected_index)
-    {
-        draw_candidate_details(frame, right_pane, client_game_state, person, organization);
+    match hire_offer {
+        Some(offer) => {
+            let [details_pane, offer_pane] = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(7)])
+                .areas(*right_pane);
+            draw_candidate_details(
+                frame,
+                &details_pane,
+                client_game_state,
+                &offer.candidate_id,
+                organization,
+            );
+            draw_hire_offer(frame, &offer_pane, offer);
+        }
+        None => {
+            if let Some(person) = client_game_state.ordered_candidates.get(*selected_index) {
+                draw_candidate_details(frame, right_pane, client_game_state, person, organization);
+            }
+        }
     }
 }
 
-pub fn draw_unemployed_list(
+fn draw_hire_offer(frame: &mut Frame, rect: &Rect, offer: &HireOffer) {
+    let fields = [
+        format!("Role: {:?}", offer.role),
+        format!("Level: {}", offer.level),
+        format!("Salary (p/w): ${}", offer.salary_offer),
+        format!("Signing Bonus: ${}", offer.signing_bonus),
+    ];
+
+    let lines: Vec<String> = fields
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| {
+            if index == offer.field {
+                format!("➤ {}", field)
+            } else {
+                format!("  {}", field)
+            }
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(Block::default().title("Offer").borders(Borders::ALL))
+        .style(Style::default().fg(Color::Yellow));
+
+    frame.render_widget(paragraph, *rect);
+}
+
+pub fn draw_candidate_list(
     frame: &mut Frame,
     rect: &Rect,
     client_game_state: &ClientGameState,
-    unemployed_ids: &[Uuid],
+    candidate_ids: &[Uuid],
     selected_index: usize,
 ) {
-    let unemployed: Vec<_> = unemployed_ids
+    let items: Vec<ListItem> = candidate_ids
         .iter()
-        .map(|id| client_game_state.entities.get(id).unwrap())
-        .collect();
-
-    let items: Vec<ListItem> = unemployed
-        .iter()
-        .map(|p| ListItem::new(p.name.to_string()))
+        .map(|id| {
+            let person = client_game_state.entities.get(id).unwrap();
+            let candidate = client_game_state.candidates.get(id).unwrap();
+            ListItem::new(format!(
+                "{} (L{}) ${}",
+                person.name, candidate.level, candidate.asking_salary
+            ))
+        })
         .collect();
 
     let mut state = ListState::default();
     state.select(Some(selected_index.min(items.len().saturating_sub(1))));
 
     let list = List::new(items)
-        .block(
-            Block::default()
-                .title("Unemployed Candidates")
-                .borders(Borders::ALL),
-        )
+        .block(Block::default().title("Job Seekers").borders(Borders::ALL))
         .highlight_style(
             Style::default()
                 .bg(Color::Blue)
@@ -77,22 +119,38 @@ pub fn draw_candidate_details(
     frame: &mut Frame,
     rect: &Rect,
     client_game_state: &ClientGameState,
-    unemployed_id: &Uuid,
+    candidate_id: &Uuid,
     organization: &Organization,
 ) {
     let current_week = client_game_state.week as i32;
-    let unemployed = client_game_state.entities.get(unemployed_id).unwrap();
+    let person = client_game_state.entities.get(candidate_id).unwrap();
+    let candidate = client_game_state.candidates.get(candidate_id).unwrap();
     let mut lines = Vec::new();
 
-    lines.push(format!("Name: {}", unemployed.name));
-    lines.push(format!("Type: {:?}", unemployed.entity_type));
+    lines.push(format!("Name: {}", person.name));
+    lines.push(format!("Type: {:?}", person.entity_type));
     lines.push(format!(
         "Age: {}",
-        get_age_description(
-            current_week.saturating_sub(unemployed.origin.week_of_birth as i32) as u32
-        )
+        get_age_description(current_week.saturating_sub(person.origin.week_of_birth as i32) as u32)
+    ));
+    lines.push(format!("Level: {}", candidate.level));
+    lines.push(format!("Asking Salary (p/w): ${}", candidate.asking_salary));
+    lines.push(format!(
+        "Preferred Roles: {}",
+        candidate
+            .preferred_roles
+            .iter()
+            .map(|role| format!("{:?}", role))
+            .collect::<Vec<_>>()
+            .join(", ")
+    ));
+    lines.push(format!(
+        "On the market for: {} more weeks",
+        candidate
+            .listed_until_week
+            .saturating_sub(client_game_state.week)
     ));
-    lines.push(format!("ID: {}", unemployed.id));
+    lines.push(format!("ID: {}", person.id));
     lines.push("—".into());
     lines.push(format!("Considering Org: {}", organization.name));
 
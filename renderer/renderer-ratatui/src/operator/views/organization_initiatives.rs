@@ -0,0 +1,155 @@
+use crate::operator::routes::InitiativePlan;
+use crate::operator::views::initiative_label;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use renderer_api::ClientGameState;
+use shared::{INITIATIVE_KINDS, Initiative, InitiativeKind, Organization};
+use uuid::Uuid;
+
+const PROGRESS_BAR_WIDTH: usize = 20;
+
+pub fn render_initiatives(
+    client_game_state: &ClientGameState,
+    frame: &mut Frame,
+    left_pane: &Rect,
+    right_pane: &Rect,
+    organization_id: &Uuid,
+    selected_index: &usize,
+    initiative_plan: Option<&InitiativePlan>,
+) {
+    let organization = client_game_state
+        .organizations
+        .get(organization_id)
+        .unwrap();
+
+    draw_initiative_list(frame, left_pane, organization, *selected_index);
+
+    let Some(kind) = INITIATIVE_KINDS.get(*selected_index) else {
+        return;
+    };
+    let running = organization
+        .initiatives
+        .iter()
+        .find(|initiative| initiative.kind == *kind);
+
+    match initiative_plan {
+        Some(plan) => {
+            let [details_pane, plan_pane] = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(5)])
+                .areas(*right_pane);
+            draw_initiative_details(frame, &details_pane, *kind, running);
+            draw_initiative_plan(frame, &plan_pane, plan);
+        }
+        None => draw_initiative_details(frame, right_pane, *kind, running),
+    }
+}
+
+fn draw_initiative_list(
+    frame: &mut Frame,
+    rect: &Rect,
+    organization: &Organization,
+    selected_index: usize,
+) {
+    let items: Vec<ListItem> = INITIATIVE_KINDS
+        .iter()
+        .map(|kind| {
+            let status = match organization
+                .initiatives
+                .iter()
+                .find(|initiative| initiative.kind == *kind)
+            {
+                Some(initiative) => format!(
+                    "{}/{} weeks",
+                    initiative.weeks - initiative.weeks_remaining,
+                    initiative.weeks
+                ),
+                None => "idle".to_string(),
+            };
+            ListItem::new(format!("{} ({})", initiative_label(*kind), status))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(selected_index.min(items.len().saturating_sub(1))));
+
+    let list = List::new(items)
+        .block(Block::default().title("Initiatives").borders(Borders::ALL))
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("➤ ");
+
+    frame.render_stateful_widget(list, *rect, &mut state);
+}
+
+fn draw_initiative_details(
+    frame: &mut Frame,
+    rect: &Rect,
+    kind: InitiativeKind,
+    running: Option<&Initiative>,
+) {
+    let mut lines = vec![format!("Initiative: {}", initiative_label(kind))];
+
+    match running {
+        Some(initiative) => {
+            let weeks_done = (initiative.weeks - initiative.weeks_remaining) as usize;
+            let filled = weeks_done * PROGRESS_BAR_WIDTH / initiative.weeks.max(1) as usize;
+            lines.push(format!(
+                "Progress: [{}{}] {}/{} weeks",
+                "#".repeat(filled),
+                ".".repeat(PROGRESS_BAR_WIDTH - filled),
+                weeks_done,
+                initiative.weeks
+            ));
+            lines.push(format!("Paid up front: ${}", initiative.upfront_cost));
+            lines.push(format!("Weekly cost: ${}", initiative.weekly_cost));
+            lines.push("—".into());
+            lines.push("[c] Cancel for a partial refund of the upfront cost".into());
+        }
+        None => {
+            lines.push("Not running.".into());
+            lines.push("[i] Plan one".into());
+        }
+    }
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(Block::default().title("Details").borders(Borders::ALL))
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, *rect);
+}
+
+fn draw_initiative_plan(frame: &mut Frame, rect: &Rect, plan: &InitiativePlan) {
+    let fields = [
+        format!("Weeks: {}", plan.weeks),
+        format!("Budget: ${}", plan.budget),
+    ];
+
+    let lines: Vec<String> = fields
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| {
+            if index == plan.field {
+                format!("➤ {}", field)
+            } else {
+                format!("  {}", field)
+            }
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(
+            Block::default()
+                .title(format!("Plan {}", initiative_label(plan.kind)))
+                .borders(Borders::ALL),
+        )
+        .style(Style::default().fg(Color::Yellow));
+
+    frame.render_widget(paragraph, *rect);
+}
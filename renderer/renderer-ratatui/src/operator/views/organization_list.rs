@@ -1,9 +1,9 @@
+use crate::operator::views::initiative_label;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::widgets::{Block, Borders, Wrap};
 use ratatui::{Frame, widgets::Paragraph};
 use renderer_api::{ClientGameState, ClientHistoryState};
-use shared::Initiative;
 use uuid::Uuid;
 
 pub fn render_organization_list(
@@ -137,17 +137,11 @@ pub fn render_organization_summary(
     if !organization.initiatives.is_empty() {
         lines.push("Active Initiatives:".to_string());
         for initiative in &organization.initiatives {
-            let description = match initiative {
-                Initiative::Marketing { weeks_remaining } => {
-                    format!("• Marketing ({} weeks left)", weeks_remaining)
-                }
-                Initiative::Training { weeks_remaining } => {
-                    format!("• Training ({} weeks left)", weeks_remaining)
-                }
-                Initiative::RnD { weeks_remaining } => {
-                    format!("• R&D ({} weeks left)", weeks_remaining)
-                }
-            };
+            let description = format!(
+                "• {} ({} weeks left)",
+                initiative_label(initiative.kind),
+                initiative.weeks_remaining
+            );
             lines.push(description);
         }
     }
@@ -0,0 +1,151 @@
+use crate::operator::routes::LoanPlan;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use renderer_api::ClientGameState;
+use shared::{Company, Debt, Money};
+
+pub fn render_loans(
+    client_game_state: &ClientGameState,
+    frame: &mut Frame,
+    left_pane: &Rect,
+    right_pane: &Rect,
+    selected_index: &usize,
+    debt_amount: Money,
+    loan_plan: Option<&LoanPlan>,
+) {
+    let Some(company) = client_game_state
+        .player_company_id()
+        .and_then(|company_id| client_game_state.companies.get(&company_id))
+    else {
+        return;
+    };
+
+    draw_debt_list(frame, left_pane, &company.debt, *selected_index);
+
+    match loan_plan {
+        Some(plan) => {
+            let [details_pane, plan_pane] = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(4)])
+                .areas(*right_pane);
+            draw_debt_details(frame, &details_pane, company, *selected_index, debt_amount);
+            draw_loan_plan(frame, &plan_pane, plan);
+        }
+        None => draw_debt_details(frame, right_pane, company, *selected_index, debt_amount),
+    }
+}
+
+fn draw_debt_list(frame: &mut Frame, rect: &Rect, debt: &Debt, selected_index: usize) {
+    let items: Vec<ListItem> = debt
+        .loans
+        .iter()
+        .enumerate()
+        .map(|(index, loan)| ListItem::new(format!("Loan {}: ${}", index + 1, loan.balance)))
+        .chain([ListItem::new(format!(
+            "Credit line: ${}",
+            debt.credit_drawn
+        ))])
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(selected_index.min(debt.loans.len())));
+
+    let list = List::new(items)
+        .block(Block::default().title("Debt").borders(Borders::ALL))
+        .highlight_style(
+            Style::default()
+                .bg(Color::Blue)
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("➤ ");
+
+    frame.render_stateful_widget(list, *rect, &mut state);
+}
+
+fn draw_debt_details(
+    frame: &mut Frame,
+    rect: &Rect,
+    company: &Company,
+    selected_index: usize,
+    debt_amount: Money,
+) {
+    let debt = &company.debt;
+    let mut lines = vec![
+        format!("Company Cash: ${}", company.financials.actual_cash),
+        format!("Total Owed: ${}", debt.outstanding()),
+    ];
+
+    if debt.bankrupt {
+        lines.push("The company is bankrupt.".into());
+    } else if debt.weeks_insolvent > 0 {
+        lines.push(format!(
+            "Insolvent for {} weeks, the lenders are watching{}",
+            debt.weeks_insolvent,
+            if debt.restructured {
+                ", the next time is bankruptcy"
+            } else {
+                ""
+            }
+        ));
+    } else if debt.restructured {
+        lines.push("Restructured once, there will be no second time.".into());
+    }
+    lines.push("—".into());
+
+    match debt.loans.get(selected_index) {
+        Some(loan) => {
+            lines.push(format!("Borrowed: ${}", loan.principal));
+            lines.push(format!("Balance: ${}", loan.balance));
+            lines.push(format!("Yearly Interest: {}%", loan.annual_rate));
+            lines.push(format!(
+                "Installment: ${} (p/w), {} weeks to go",
+                loan.installment(),
+                loan.weeks_remaining()
+            ));
+            lines.push(format!(
+                "[y] Repay ${} early",
+                debt_amount.min(loan.balance)
+            ));
+        }
+        None => {
+            lines.push(format!("Drawn: ${}", debt.credit_drawn));
+            lines.push(format!("[Enter] Draw ${}", debt_amount));
+            lines.push(format!("[y] Repay ${}", debt_amount));
+        }
+    }
+    lines.push("[b] Arrange a new loan".into());
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(Block::default().title("Details").borders(Borders::ALL))
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(paragraph, *rect);
+}
+
+fn draw_loan_plan(frame: &mut Frame, rect: &Rect, plan: &LoanPlan) {
+    let fields = [
+        format!("Amount: ${}", plan.amount),
+        format!("Weeks: {}", plan.weeks),
+    ];
+
+    let lines: Vec<String> = fields
+        .into_iter()
+        .enumerate()
+        .map(|(index, field)| {
+            if index == plan.field {
+                format!("➤ {}", field)
+            } else {
+                format!("  {}", field)
+            }
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines.join("\n"))
+        .block(Block::default().title("New Loan").borders(Borders::ALL))
+        .style(Style::default().fg(Color::Yellow));
+
+    frame.render_widget(paragraph, *rect);
+}
@@ -1,6 +1,9 @@
 use crate::operator::routes::{OrganizationTab, OrganizationView};
 use crate::operator::views::organization_budget::render_organization_budget;
 use crate::operator::views::organization_details::render_organization_details;
+use crate::operator::views::organization_former::render_former_employees;
+use crate::operator::views::organization_initiatives::render_initiatives;
+use crate::operator::views::organization_loans::render_loans;
 use crate::operator::views::render_hiring;
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
@@ -8,6 +11,7 @@ use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::Line;
 use ratatui::widgets::Tabs;
 use renderer_api::ClientGameState;
+use shared::OrganizationType;
 
 pub fn render_organization_view(
     client_game_state: &ClientGameState,
@@ -28,7 +32,11 @@ pub fn render_organization_view(
     let left_pane = chunks[0];
     let right_pane = chunks[1];
 
-    draw_tab_header(frame, inner_chunks[0], organization_view.tab);
+    let is_finance = client_game_state
+        .organizations
+        .get(&organization_view.organization_id)
+        .is_some_and(|organization| organization.organization_type == OrganizationType::Finance);
+    draw_tab_header(frame, inner_chunks[0], organization_view.tab, is_finance);
 
     match organization_view.tab {
         OrganizationTab::Detail => render_organization_details(
@@ -57,20 +65,51 @@ pub fn render_organization_view(
             &right_pane,
             &organization_view.organization_id,
             &organization_view.selected_index,
+            organization_view.hire_offer.as_ref(),
+        ),
+        OrganizationTab::Initiatives => render_initiatives(
+            client_game_state,
+            frame,
+            &left_pane,
+            &right_pane,
+            &organization_view.organization_id,
+            &organization_view.selected_index,
+            organization_view.initiative_plan.as_ref(),
+        ),
+        OrganizationTab::Former => render_former_employees(
+            client_game_state,
+            frame,
+            &left_pane,
+            &right_pane,
+            &organization_view.organization_id,
+            &organization_view.selected_index,
+        ),
+        OrganizationTab::Loans => render_loans(
+            client_game_state,
+            frame,
+            &left_pane,
+            &right_pane,
+            &organization_view.selected_index,
+            organization_view.debt_amount,
+            organization_view.loan_plan.as_ref(),
         ),
     }
 }
 
-fn draw_tab_header(frame: &mut Frame, rect: Rect, active_tab: OrganizationTab) {
-    let titles = vec!["Detail", "Budget", "Hiring"]
-        .into_iter()
-        .map(Line::from)
-        .collect::<Vec<Line>>();
+fn draw_tab_header(frame: &mut Frame, rect: Rect, active_tab: OrganizationTab, is_finance: bool) {
+    let mut titles = vec!["Detail", "Budget", "Hiring", "Initiatives", "Former"];
+    if is_finance {
+        titles.push("Loans");
+    }
+    let titles = titles.into_iter().map(Line::from).collect::<Vec<Line>>();
 
     let selected_index = match active_tab {
         OrganizationTab::Detail => 0,
         OrganizationTab::Budget => 1,
         OrganizationTab::Hiring => 2,
+        OrganizationTab::Initiatives => 3,
+        OrganizationTab::Former => 4,
+        OrganizationTab::Loans => 5,
     };
 
     let tabs = Tabs::new(titles)
@@ -1,5 +1,9 @@
+use crate::final_report::{board_demand_label, render_final_report};
 use crate::operator::routes::{OrganizationTab, Route};
-use crate::operator::views::{render_organization_list, render_organization_view};
+use crate::operator::views::{
+    corporate_event_headline, render_ceo_compensation, render_corporate_event,
+    render_organization_list, render_organization_view,
+};
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 use ratatui::prelude::{Color, Style};
@@ -15,6 +19,10 @@ pub fn render(
     if client_game_state.players.is_empty() {
         return;
     }
+    if let Some(outcome) = &client_game_state.board.outcome {
+        render_final_report(frame, frame.area(), outcome);
+        return;
+    }
 
     let outer_chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -23,6 +31,7 @@ pub fn render(
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(1),
+            Constraint::Length(client_game_state.last_rejection.is_some() as u16),
         ])
         .split(frame.area());
 
@@ -30,12 +39,18 @@ pub fn render(
     let vitals_area = outer_chunks[1];
     let financial_area = outer_chunks[2];
     let tooltip_area = outer_chunks[3];
+    let rejection_area = outer_chunks[4];
 
     render_vitals_summary(frame, vitals_area, client_game_state);
     render_financial_summary(frame, financial_area, client_game_state);
     render_tooltip(frame, tooltip_area, route);
+    if let Some(reason) = &client_game_state.last_rejection {
+        render_rejection(frame, rejection_area, reason);
+    }
 
-    let (company_id, _company) = client_game_state.companies.iter().next().unwrap();
+    let Some(company_id) = client_game_state.player_company_id() else {
+        return;
+    };
 
     match route {
         Route::OrganizationList { data } => render_organization_list(
@@ -43,24 +58,68 @@ pub fn render(
             client_history_state,
             frame,
             &main_area,
-            company_id,
+            &company_id,
             &data.selected_index,
         ),
         Route::OrganizationView { data } => {
             render_organization_view(client_game_state, frame, &main_area, data)
         }
+        Route::CorporateEvent { data } => render_corporate_event(
+            client_game_state,
+            frame,
+            &main_area,
+            &data.corporate_event_id,
+            &data.selected_index,
+        ),
+        Route::Compensation { data } => {
+            render_ceo_compensation(client_game_state, frame, &main_area, &company_id, data)
+        }
     }
 }
 
 fn render_vitals_summary(frame: &mut Frame, rect: Rect, client_game_state: &ClientGameState) {
     let player = client_game_state.players.first().unwrap();
 
-    let lines = [
+    let clock = match client_game_state.seconds_until_next_week() {
+        Some(seconds) => format!(
+            "Clock: {} (next week in {}s)",
+            client_game_state.clock.speed, seconds
+        ),
+        None => format!("Clock: {}", client_game_state.clock.speed),
+    };
+
+    let mut lines = vec![
         format!("Week: {}", client_game_state.week),
+        clock,
         format!("CEO Public Opinion: {}", player.perception.public_opinion),
         format!("CEO Reputation: {}", player.perception.reputation),
+        format!("CEO Cash: ${}", player.financials.actual_cash),
+        format!(
+            "Board Confidence: {}% ({} warnings)",
+            client_game_state.board.confidence, client_game_state.board.warnings
+        ),
     ];
 
+    if let Some(demand) = &client_game_state.board.demand {
+        lines.push(format!(
+            "Board Demands: {} by week {}",
+            board_demand_label(demand.kind),
+            demand.deadline_week
+        ));
+    }
+
+    // Last week's news, drawn when that week ended.
+    if let Some(corporate_event) = client_game_state
+        .corporate_events
+        .last()
+        .filter(|corporate_event| corporate_event.week + 1 >= client_game_state.week)
+    {
+        lines.push(format!(
+            "News: {}",
+            corporate_event_headline(corporate_event.kind)
+        ));
+    }
+
     let block = Block::default().title("Vitals").borders(Borders::ALL);
 
     let paragraph = Paragraph::new(lines.join(" | "))
@@ -72,14 +131,29 @@ fn render_vitals_summary(frame: &mut Frame, rect: Rect, client_game_state: &Clie
 
 fn render_financial_summary(frame: &mut Frame, rect: Rect, client_game_state: &ClientGameState) {
     client_game_state
-        .companies
-        .iter()
-        .for_each(|(_uuid, company)| {
+        .player_company_id()
+        .and_then(|company_id| client_game_state.companies.get(&company_id))
+        .into_iter()
+        .for_each(|company| {
+            let orders = &company.orders;
+            let fulfilment = match orders.bottleneck {
+                Some(stage) => format!(
+                    "Orders: {}/{} ({:?} is the bottleneck)",
+                    orders.fulfilled, orders.placed, stage
+                ),
+                None => format!("Orders: {}/{}", orders.fulfilled, orders.placed),
+            };
             let lines = [
-                format!("Cash: ${}", company.financials.actual_cash),
+                format!("Company Cash: ${}", company.financials.actual_cash),
                 format!("Income: ${}", company.financials.this_weeks_income),
                 format!("Expenses: ${}", company.financials.this_weeks_expenses),
                 format!("Net Profit: ${}", company.financials.this_weeks_net_profit),
+                format!(
+                    "Market Share: {}%",
+                    client_game_state.market_share_percent(&company.id)
+                ),
+                fulfilment,
+                format!("Company Reputation: {}", company.perception.reputation),
             ];
 
             let block = Block::default().title("Financials").borders(Borders::ALL);
@@ -95,17 +169,39 @@ fn render_financial_summary(frame: &mut Frame, rect: Rect, client_game_state: &C
 fn render_tooltip(frame: &mut Frame, rect: Rect, route: &Route) {
     let text = match route {
         Route::OrganizationList { .. } => {
-            "↑↓ to select | → to view | [Space] Wait a week | [q] Quit"
+            "↑↓ to select | → to view | [e] CEO pay | [Space] End week | [-/+] Clock speed | [q] Quit"
         }
         Route::OrganizationView { data } => match data.tab {
             OrganizationTab::Detail => {
-                "← Back to List | ↑↓ Navigate | [Tab] Change Tab | [f] Fire | [r] Raise | [p] Promote | [q] Quit"
+                "← Back to List | ↑↓ Navigate | [Tab] Change Tab | [f] Fire | [r] Raise | [u] Promote | [j] Demote | [t] Transfer | [p] Make VP | [q] Quit"
             }
             OrganizationTab::Budget => {
                 "← Back to Detail | ↑↓ Navigate | < > Adjust | [Enter] Confirm"
             }
-            OrganizationTab::Hiring => "← Back to Detail | ↑↓ Navigate | [h] Hire | [q] Quit",
+            OrganizationTab::Hiring if data.hire_offer.is_some() => {
+                "↑↓ Field | < > Adjust | [Enter] Send offer | [h] Cancel offer"
+            }
+            OrganizationTab::Hiring => "← Back to Detail | ↑↓ Navigate | [h] Make offer | [q] Quit",
+            OrganizationTab::Initiatives if data.initiative_plan.is_some() => {
+                "↑↓ Field | < > Adjust | [Enter] Launch | [i] Cancel plan"
+            }
+            OrganizationTab::Initiatives => {
+                "← Back to Detail | ↑↓ Navigate | [i] Plan initiative | [c] Cancel initiative | [q] Quit"
+            }
+            OrganizationTab::Former => "← Back to Detail | ↑↓ Navigate | [q] Quit",
+            OrganizationTab::Loans if data.loan_plan.is_some() => {
+                "↑↓ Field | < > Adjust | [Enter] Borrow | [b] Cancel loan"
+            }
+            OrganizationTab::Loans => {
+                "← Back to Detail | ↑↓ Navigate | < > Amount | [Enter] Draw credit | [y] Repay | [b] New loan | [q] Quit"
+            }
         },
+        Route::Compensation { .. } => {
+            "← Back to List | ↑↓ Navigate | < > Adjust | [Enter] Ask the board / Sell | [q] Quit"
+        }
+        Route::CorporateEvent { .. } => {
+            "↑↓ Choose | [Enter] Decide | [Space] End week and let it ride | [q] Quit"
+        }
     };
 
     let tooltip = Paragraph::new(text)
@@ -114,3 +210,11 @@ fn render_tooltip(frame: &mut Frame, rect: Rect, route: &Route) {
 
     frame.render_widget(tooltip, rect);
 }
+
+fn render_rejection(frame: &mut Frame, rect: Rect, reason: &str) {
+    let rejection = Paragraph::new(format!("Command rejected: {}", reason))
+        .style(Style::default().fg(Color::Red))
+        .wrap(Wrap { trim: true });
+
+    frame.render_widget(rejection, rect);
+}
@@ -1,4 +1,7 @@
+use crate::simulation::{MetricsFormat, PolicyKind};
 use clap::{ArgGroup, Parser};
+use std::path::PathBuf;
+use uuid::Uuid;
 
 #[derive(Parser, Debug)]
 #[command(name = "Game Server")]
@@ -9,11 +12,48 @@ use clap::{ArgGroup, Parser};
         .multiple(false)
 ))]
 pub struct Cli {
-    /// Replays the command log
-    #[arg(short = 'c', long = "redrive-command-log", default_value_t = false)]
-    pub(crate) redrive_command_log: bool,
+    /// Re-simulates a game from its command log on a freshly generated world, compares the
+    /// result with the event log and reports any divergence, then exits.
+    #[arg(short = 'c', long = "redrive-command-log", value_name = "GAME_ID")]
+    pub(crate) redrive_command_log: Option<Uuid>,
 
     /// Replays the event log
     #[arg(short = 'e', long = "redrive-event-log", default_value_t = true)]
     pub(crate) redrive_event_log: bool,
+
+    /// Compacts a game's event log down to its newest snapshot plus the events after it, then exits.
+    /// The game must not be loaded by a running server.
+    #[arg(long = "compact-event-log", value_name = "GAME_ID")]
+    pub(crate) compact_event_log: Option<Uuid>,
+
+    /// Rewrites a game's event and command logs at the current schema version, then exits.
+    /// The game must not be loaded by a running server.
+    #[arg(long = "migrate-logs", value_name = "GAME_ID")]
+    pub(crate) migrate_logs: Option<Uuid>,
+
+    /// Runs a headless simulation of a freshly generated world for the given number of weeks,
+    /// printing per-week metrics to stdout, then exits. No game, event log or command log is
+    /// written, only the server's own log under `_out/logs`.
+    #[arg(long = "simulate-weeks", value_name = "WEEKS")]
+    pub(crate) simulate_weeks: Option<u32>,
+
+    /// World seed for the headless simulation, the default world is used otherwise.
+    #[arg(long = "seed", requires = "simulate_weeks")]
+    pub(crate) seed: Option<u64>,
+
+    /// Built in policy that picks the operator's command each simulated week.
+    #[arg(long = "policy", value_enum, default_value_t = PolicyKind::DoNothing)]
+    pub(crate) policy: PolicyKind,
+
+    /// JSON array of commands played one per simulated week, overrides --policy.
+    #[arg(
+        long = "policy-script",
+        value_name = "FILE",
+        requires = "simulate_weeks"
+    )]
+    pub(crate) policy_script: Option<PathBuf>,
+
+    /// Output format of the headless simulation's metrics.
+    #[arg(long = "metrics-format", value_enum, default_value_t = MetricsFormat::Csv)]
+    pub(crate) metrics_format: MetricsFormat,
 }
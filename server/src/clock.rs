@@ -0,0 +1,120 @@
+use shared::{ClockSpeed, ClockState, TickMode};
+use std::time::Duration;
+
+/// Drives an instance's weeks in real time. Progress through the week is kept at 1x, so
+/// changing speed mid-week keeps what has already passed.
+#[derive(Clone, Debug)]
+pub struct InstanceClock {
+    pub tick_mode: TickMode,
+    pub speed: ClockSpeed,
+    pub seconds_per_week: u32,
+    pub week_progress: Duration,
+}
+
+impl InstanceClock {
+    pub fn new(tick_mode: TickMode) -> Self {
+        InstanceClock {
+            tick_mode,
+            speed: tick_mode.initial_clock_speed(),
+            seconds_per_week: tick_mode.seconds_per_week(),
+            week_progress: Duration::ZERO,
+        }
+    }
+
+    pub fn advance(&mut self, delta: Duration) {
+        self.week_progress += delta * self.speed.multiplier();
+    }
+
+    pub fn week_is_over(&self) -> bool {
+        self.speed != ClockSpeed::Paused && self.week_progress >= self.week_length()
+    }
+
+    pub fn start_week(&mut self) {
+        self.week_progress = Duration::ZERO;
+    }
+
+    pub fn state(&self) -> ClockState {
+        let remaining = self.week_length().saturating_sub(self.week_progress);
+        let millis_until_next_week = match self.speed.multiplier() {
+            0 => remaining.as_millis(),
+            multiplier => remaining.as_millis() / multiplier as u128,
+        };
+
+        ClockState {
+            speed: self.speed,
+            seconds_per_week: self.seconds_per_week,
+            millis_until_next_week: millis_until_next_week as u64,
+        }
+    }
+
+    fn week_length(&self) -> Duration {
+        Duration::from_secs(self.seconds_per_week as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECONDS_PER_WEEK: u32 = 10;
+    const FRAME: Duration = Duration::from_millis(100);
+
+    fn real_time_clock(speed: ClockSpeed) -> InstanceClock {
+        let mut clock = InstanceClock::new(TickMode::RealTime {
+            seconds_per_week: SECONDS_PER_WEEK,
+        });
+        clock.speed = speed;
+        clock
+    }
+
+    /// Weeks ended over `seconds` of frames, the way the server's tick ends them.
+    fn weeks_ended(clock: &mut InstanceClock, seconds: u64) -> u32 {
+        let frames = Duration::from_secs(seconds).as_millis() / FRAME.as_millis();
+        let mut weeks = 0;
+        for _ in 0..frames {
+            clock.advance(FRAME);
+            if clock.week_is_over() {
+                clock.start_week();
+                weeks += 1;
+            }
+        }
+        weeks
+    }
+
+    #[test]
+    fn each_speed_ends_weeks_at_its_multiple_of_real_time() {
+        for (speed, weeks) in [
+            (ClockSpeed::Normal, 6),
+            (ClockSpeed::Double, 12),
+            (ClockSpeed::Quadruple, 24),
+        ] {
+            let mut clock = real_time_clock(speed);
+            assert_eq!(weeks_ended(&mut clock, 60), weeks, "at {:?}", speed);
+        }
+    }
+
+    #[test]
+    fn a_paused_clock_ends_no_weeks() {
+        let mut clock = real_time_clock(ClockSpeed::Paused);
+        assert_eq!(weeks_ended(&mut clock, 60), 0);
+        assert_eq!(clock.week_progress, Duration::ZERO);
+
+        let mut turn_based = InstanceClock::new(TickMode::TurnBased);
+        assert_eq!(turn_based.speed, ClockSpeed::Paused);
+        assert_eq!(weeks_ended(&mut turn_based, 600), 0);
+    }
+
+    #[test]
+    fn pausing_mid_week_keeps_what_has_passed() {
+        let mut clock = real_time_clock(ClockSpeed::Normal);
+        assert_eq!(weeks_ended(&mut clock, 5), 0);
+
+        clock.speed = ClockSpeed::Paused;
+        assert_eq!(weeks_ended(&mut clock, 60), 0);
+        assert_eq!(clock.week_progress, Duration::from_secs(5));
+
+        clock.speed = ClockSpeed::Double;
+        assert_eq!(clock.state().millis_until_next_week, 2_500);
+        assert_eq!(weeks_ended(&mut clock, 3), 1);
+    }
+}
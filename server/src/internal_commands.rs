@@ -1,7 +1,7 @@
 use crate::GameClientActionCommand;
 use crate::systems::ClientInfo;
 use async_channel::{Receiver, Sender};
-use shared::{OperatorMode, ServerEvent};
+use shared::{HistoryResolution, OperatorMode, ServerEvent, TickMode, WorldGenerationParameters};
 use uuid::Uuid;
 
 #[derive(Clone, Debug)]
@@ -20,6 +20,8 @@ pub enum InternalCommand {
     CreateGame {
         client_id: Uuid,
         game_name: String,
+        world_parameters: WorldGenerationParameters,
+        tick_mode: TickMode,
     },
     ListGames {
         client_id: Uuid,
@@ -28,4 +30,11 @@ pub enum InternalCommand {
         client_id: Uuid,
         game_id: Uuid,
     },
+    RequestHistory {
+        client_id: Uuid,
+        game_id: Uuid,
+        from_week: u16,
+        to_week: u16,
+        resolution: HistoryResolution,
+    },
 }
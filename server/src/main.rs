@@ -1,14 +1,16 @@
 mod cli;
-mod deterministic_randomization;
+mod clock;
 mod game_management;
 mod internal_commands;
 mod plugins;
+mod simulation;
 mod systems;
 
 use crate::systems::{
-    ClientInfo, create_empty_world_state, process_broadcast_world_state, process_commands,
-    process_events, process_internal_commands, process_print_active_connections,
-    redrive_event_logs, setup_command_log, setup_event_log, start_server_system,
+    ClientInfo, LogIntegrityError, compact_event_logs, migrate_logs, process_broadcast_world_state,
+    process_commands, process_events, process_internal_commands, process_print_active_connections,
+    process_snapshots, redrive_command_logs, redrive_event_logs, resume_command_log,
+    setup_command_log, setup_event_log, start_server_system,
 };
 use bevy::MinimalPlugins;
 use bevy::app::{App, FixedUpdate, PluginGroup, ScheduleRunnerPlugin, Startup};
@@ -33,9 +35,15 @@ pub struct Instances {
 
 #[derive(Clone)]
 pub struct Instance {
-    pub instance_game: GameInstanceData,
+    pub world: World,
     pub needs_broadcast: bool,
     pub needs_state_update: bool,
+    pub needs_snapshot: bool,
+    pub event_sequence: u64,
+    pub event_hash: String,
+    pub command_sequence: u64,
+    pub command_hash: String,
+    pub clock: InstanceClock,
     pub tx_internal_events: Sender<GameClientInternalEvent>,
     pub rx_internal_events: Receiver<GameClientInternalEvent>,
     pub tx_to_clients: Sender<ServerEvent>,
@@ -55,31 +63,53 @@ pub struct GameClientInternalEvent {
     pub internal_event: InternalEvent,
 }
 
-impl Instances {
-    pub fn add_new_instance(
-        &mut self,
-        game_id: &Uuid,
+impl Instance {
+    /// An instance around a freshly generated world, not yet caught up with any log.
+    pub fn new(
+        world_parameters: &WorldGenerationParameters,
+        tick_mode: TickMode,
         tx_to_clients: Sender<ServerEvent>,
-        rx_from_clients: Receiver<GameClientActionCommand>,
-    ) {
-        // send to clients
-        // receive from clients
+        rx_from_client: Receiver<GameClientActionCommand>,
+    ) -> Self {
         let (tx_internal_events, rx_internal_events) = unbounded();
 
-        let mut new_instance = Instance {
-            instance_game: GameInstanceData {
-                game_state: create_empty_world_state(),
-                history_state: Default::default(),
-            },
+        Instance {
+            world: World::generate(world_parameters),
             needs_broadcast: false,
             needs_state_update: false,
+            needs_snapshot: false,
+            event_sequence: 0,
+            event_hash: String::new(),
+            command_sequence: 0,
+            command_hash: String::new(),
+            clock: InstanceClock::new(tick_mode),
             tx_internal_events,
             rx_internal_events,
             tx_to_clients,
-            rx_from_client: rx_from_clients,
-        };
-        redrive_event_logs(&mut new_instance, *game_id);
+            rx_from_client,
+        }
+    }
+}
+
+impl Instances {
+    pub fn add_new_instance(
+        &mut self,
+        game_id: &Uuid,
+        tx_to_clients: Sender<ServerEvent>,
+        rx_from_clients: Receiver<GameClientActionCommand>,
+    ) -> Result<(), LogIntegrityError> {
+        // send to clients
+        // receive from clients
+        let world_parameters = load_world_parameters(game_id);
+        let tick_mode = load_game_metadata(game_id)
+            .map(|metadata| metadata.tick_mode)
+            .unwrap_or_default();
+        let mut new_instance =
+            Instance::new(&world_parameters, tick_mode, tx_to_clients, rx_from_clients);
+        redrive_event_logs(&mut new_instance, *game_id)?;
+        resume_command_log(&mut new_instance, *game_id)?;
         self.active_instances.insert(*game_id, new_instance);
+        Ok(())
     }
 
     pub fn remove_existing_instance(&mut self, instance_id: &Uuid) {
@@ -91,6 +121,35 @@ fn main() -> anyhow::Result<()> {
     let _guard = setup_logging();
     info!("Logging configured");
 
+    let cli = Cli::parse();
+    if let Some(game_id) = cli.compact_event_log {
+        return compact_event_logs(game_id);
+    }
+    if let Some(game_id) = cli.redrive_command_log {
+        return redrive_command_logs(game_id);
+    }
+    if let Some(game_id) = cli.migrate_logs {
+        return migrate_logs(game_id);
+    }
+    if let Some(weeks) = cli.simulate_weeks {
+        let world_parameters = WorldGenerationParameters {
+            seed: cli
+                .seed
+                .unwrap_or(WorldGenerationParameters::default().seed),
+            ..Default::default()
+        };
+        let mut policy = match &cli.policy_script {
+            Some(path) => Box::new(ScriptedPolicy::from_file(path)?),
+            None => policy_for(cli.policy),
+        };
+        return run_headless_simulation(
+            &world_parameters,
+            weeks,
+            policy.as_mut(),
+            cli.metrics_format,
+        );
+    }
+
     App::new()
         .add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::from_millis(10))))
         .add_plugins(AsyncStdReadySignalPlugin { port: 5555 })
@@ -118,13 +177,12 @@ fn main() -> anyhow::Result<()> {
                 // Process Commands
                 process_commands,
                 process_internal_commands,
-                // Core gameplay loop
-                process_organization_updates,
-                process_company_updates,
                 // clear any state update flags
                 process_clear_needs_state_update,
                 // Fan out just prior to broadcasting, so we have the opportunity to save.
                 process_events,
+                // Persist a snapshot if a week boundary asked for one.
+                process_snapshots,
                 // Broadcast the new state now that everything is done.
                 process_broadcast_world_state,
             )
@@ -152,19 +210,43 @@ fn setup_logging() -> WorkerGuard {
     guard
 }
 
+use crate::cli::Cli;
+use crate::clock::InstanceClock;
 use crate::game_management::{FilesystemGameManager, GameService};
 use crate::plugins::AsyncStdReadySignalPlugin;
+use crate::simulation::{policy_for, run_headless_simulation};
 use crate::systems::process_clear_needs_state_update::process_clear_needs_state_update;
-use crate::systems::process_company_updates::process_company_updates;
-use crate::systems::process_organization_updates::process_organization_updates;
 use async_channel::{Receiver, Sender, unbounded};
 use bevy::prelude::*;
-use shared::{ClientActionCommand, GameInstanceData, InternalEvent, ServerEvent};
-use std::fs::read_dir;
+use clap::Parser;
+use shared::{
+    ClientActionCommand, GameMetadata, InternalEvent, ServerEvent, TickMode,
+    WorldGenerationParameters,
+};
+use sim_core::{ScriptedPolicy, World};
+use std::fs::{read_dir, read_to_string};
 use std::path::PathBuf;
 use std::sync::Arc;
 use uuid::Uuid;
 
+pub fn load_game_metadata(game_id: &Uuid) -> Option<GameMetadata> {
+    let metadata_path = format!("./_out/games/{}/metadata.json", game_id);
+    let contents = read_to_string(metadata_path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+pub fn load_world_parameters(game_id: &Uuid) -> WorldGenerationParameters {
+    load_game_metadata(game_id)
+        .map(|metadata| metadata.world_parameters)
+        .unwrap_or_else(|| {
+            info!(
+                "No metadata found for game {}, using default world",
+                game_id
+            );
+            WorldGenerationParameters::default()
+        })
+}
+
 pub fn find_latest_log_file_in_folder(folder: &str) -> Option<PathBuf> {
     let dir = PathBuf::from(folder);
     let entries = read_dir(&dir).ok()?;
@@ -0,0 +1,82 @@
+use clap::ValueEnum;
+use shared::{ClientActionCommand, Money, WorldGenerationParameters};
+use sim_core::{
+    CSV_HEADER, DoNothingPolicy, PrCampaignPolicy, RaiseLeastSatisfiedPolicy, SimulationPolicy,
+    World, collect_week_metrics,
+};
+use std::io::{Write, stdout};
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum PolicyKind {
+    /// Never acts, the company runs on its own.
+    DoNothing,
+    /// Launches a PR campaign every week the player can afford one.
+    PrCampaign,
+    /// Gives the least satisfied employee a raise every week.
+    RaiseLeastSatisfied,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum MetricsFormat {
+    Csv,
+    Json,
+}
+
+pub fn policy_for(kind: PolicyKind) -> Box<dyn SimulationPolicy> {
+    match kind {
+        PolicyKind::DoNothing => Box::new(DoNothingPolicy),
+        PolicyKind::PrCampaign => Box::new(PrCampaignPolicy),
+        PolicyKind::RaiseLeastSatisfied => Box::new(RaiseLeastSatisfiedPolicy {
+            amount: Money::new(100),
+        }),
+    }
+}
+
+/// Builds a world in memory and runs it for the given number of weeks without a network or
+/// any game logs. Each week the policy picks one command, then the week is ended; both go
+/// through `World::step`, the command processing, update and event stages the server runs. Metrics are written
+/// to stdout. The run stops early if the game is won or lost.
+pub fn run_headless_simulation(
+    world_parameters: &WorldGenerationParameters,
+    weeks: u32,
+    policy: &mut dyn SimulationPolicy,
+    format: MetricsFormat,
+) -> anyhow::Result<()> {
+    let mut world = World::generate(world_parameters);
+
+    let mut out = stdout().lock();
+    if format == MetricsFormat::Csv {
+        writeln!(out, "{}", CSV_HEADER)?;
+    }
+
+    for _ in 0..weeks {
+        match policy.next_command(world.game_state()) {
+            ClientActionCommand::EndTurn => {}
+            command => {
+                if let Err(rejection) = world.step(command) {
+                    eprintln!("Command rejected, doing nothing this week: {}", rejection);
+                }
+            }
+        }
+        world.step(ClientActionCommand::EndTurn)?;
+
+        for row in collect_week_metrics(world.game_state()) {
+            match format {
+                MetricsFormat::Csv => writeln!(out, "{}", row.to_csv())?,
+                MetricsFormat::Json => writeln!(out, "{}", serde_json::to_string(&row)?)?,
+            }
+        }
+
+        if let Some(outcome) = &world.game_state().board.outcome {
+            eprintln!(
+                "The game ended in week {}: {:?}",
+                outcome.report().week,
+                outcome
+            );
+            break;
+        }
+    }
+
+    out.flush()?;
+    Ok(())
+}
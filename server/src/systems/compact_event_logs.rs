@@ -0,0 +1,168 @@
+use crate::systems::{
+    LogAnchor, LoggedEvent, list_session_files, load_latest_snapshot, read_verified_log,
+    stream_folder,
+};
+use chrono::Utc;
+use std::fs::{File, remove_file, rename};
+use std::io::{BufWriter, Write};
+use uuid::Uuid;
+
+/// Offline compaction of a game's event stream. Every session file is folded into a single
+/// file holding only the events recorded after the newest snapshot. Must not be run while a
+/// server has the game loaded.
+pub fn compact_event_logs(game_id: Uuid) -> anyhow::Result<()> {
+    let Some(snapshot) = load_latest_snapshot(&game_id) else {
+        anyhow::bail!("Game {} has no snapshot to compact against", game_id);
+    };
+    let anchor = LogAnchor {
+        sequence: snapshot.sequence,
+        hash: snapshot.event_hash,
+    };
+
+    // Refuse to compact a damaged log, the dropped records would take the evidence with them.
+    let folder = stream_folder(&game_id, "event_stream");
+    let session_files = list_session_files(&folder);
+    let events: Vec<LoggedEvent> = read_verified_log(&folder, Some(&anchor))?;
+
+    let compacted_name = Utc::now().format("session-%Y%m%d-%H%M%S-compacted.ndjson");
+    let compacted_path = folder.join(compacted_name.to_string());
+    let temporary_path = compacted_path.with_extension("ndjson.tmp");
+    let mut writer = BufWriter::new(File::create(&temporary_path)?);
+
+    let mut dropped = 0;
+    let mut kept = 0;
+    for logged in events {
+        if logged.sequence <= anchor.sequence {
+            dropped += 1;
+            continue;
+        }
+
+        // Legacy records come back numbered and hashed, so the compacted file keeps both.
+        writeln!(writer, "{}", serde_json::to_string(&logged)?)?;
+        kept += 1;
+    }
+
+    writer.flush()?;
+    drop(writer);
+    rename(&temporary_path, &compacted_path)?;
+
+    for session_file in &session_files {
+        remove_file(session_file)?;
+    }
+
+    println!(
+        "Compacted game {}: snapshot at event {}, dropped {} events, kept {} events in {:?}",
+        game_id, anchor.sequence, dropped, kept, compacted_path
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{
+        EventLog, EventLogWriter, SERVER_SOURCE_ID, apply_event, process_command,
+        restore_from_logs, write_event_to_log_stream, write_snapshot,
+    };
+    use crate::{GameClientActionCommand, Instance};
+    use async_channel::unbounded;
+    use serde_json::Value;
+    use shared::{ClientActionCommand, TickMode, WorldGenerationParameters};
+    use std::collections::HashMap;
+    use std::fs::remove_dir_all;
+
+    fn new_instance() -> Instance {
+        let (tx_to_clients, _rx_to_clients) = unbounded();
+        let (_tx_from_client, rx_from_client) = unbounded();
+        Instance::new(
+            &WorldGenerationParameters::default(),
+            TickMode::TurnBased,
+            tx_to_clients,
+            rx_from_client,
+        )
+    }
+
+    /// Runs the commands and logs their events, as a server tick would.
+    fn run(
+        game_id: Uuid,
+        instance: &mut Instance,
+        event_log: &mut EventLog,
+        commands: &[ClientActionCommand],
+    ) {
+        for command in commands {
+            let command = GameClientActionCommand {
+                source_client_id: SERVER_SOURCE_ID,
+                game_id,
+                command: command.clone(),
+            };
+            process_command(&game_id, command, instance).unwrap();
+            while let Ok(event) = instance.rx_internal_events.try_recv() {
+                instance.event_sequence += 1;
+                instance.event_hash = write_event_to_log_stream(
+                    event_log,
+                    &game_id,
+                    instance.event_sequence,
+                    &instance.event_hash,
+                    event,
+                );
+            }
+        }
+    }
+
+    fn game_of(instance: &Instance) -> Value {
+        serde_json::to_value(&instance.world.instance_game).unwrap()
+    }
+
+    #[test]
+    fn a_compacted_log_restores_the_game_a_full_replay_does() {
+        let game_id = Uuid::new_v4();
+        let mut played = new_instance();
+        let mut event_log = EventLog {
+            writer: EventLogWriter {
+                writers: HashMap::new(),
+            },
+        };
+        run(
+            game_id,
+            &mut played,
+            &mut event_log,
+            &[
+                ClientActionCommand::EndTurn,
+                ClientActionCommand::LaunchPRCampaign,
+                ClientActionCommand::EndTurn,
+            ],
+        );
+        let snapshot = write_snapshot(&game_id, &played);
+        run(
+            game_id,
+            &mut played,
+            &mut event_log,
+            &[
+                ClientActionCommand::LaunchPRCampaign,
+                ClientActionCommand::EndTurn,
+                ClientActionCommand::EndTurn,
+            ],
+        );
+        drop(event_log);
+
+        // Every event from the start, ignoring the snapshot.
+        let mut replayed = new_instance();
+        let events: Result<Vec<LoggedEvent>, _> =
+            read_verified_log(&stream_folder(&game_id, "event_stream"), None);
+        let compacted = compact_event_logs(game_id);
+        let mut restored = new_instance();
+        let restore = restore_from_logs(&mut restored, game_id);
+        remove_dir_all(format!("./_out/games/{}", game_id)).unwrap();
+
+        snapshot.unwrap();
+        compacted.unwrap();
+        restore.unwrap();
+        for logged in events.unwrap() {
+            apply_event(&logged.event, &mut replayed);
+        }
+        assert_eq!(game_of(&replayed), game_of(&played));
+        assert_eq!(game_of(&restored), game_of(&played));
+        assert_eq!(restored.event_sequence, played.event_sequence);
+        assert_eq!(restored.event_hash, played.event_hash);
+    }
+}
@@ -0,0 +1,396 @@
+use crate::systems::{Upcaster, upcast_record};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+/// A record in one of the per-game ndjson streams. Each record carries its position in the
+/// stream and a rolling hash over the previous record's hash and its own contents.
+pub trait ChainedRecord: Serialize + DeserializeOwned {
+    /// Schema version written by this build.
+    const CURRENT_VERSION: u32;
+    /// Upcasters for older schema versions, see `upcast_record`.
+    const UPCASTERS: &'static [Upcaster];
+
+    fn sequence(&self) -> u64;
+    fn set_hash(&mut self, hash: String);
+}
+
+/// The point a stream is known to be good up to, usually taken from a snapshot.
+#[derive(Debug, Clone)]
+pub struct LogAnchor {
+    pub sequence: u64,
+    pub hash: String,
+}
+
+#[derive(Debug)]
+pub enum LogIntegrityError {
+    Unreadable {
+        file: PathBuf,
+        reason: String,
+    },
+    Truncated {
+        file: PathBuf,
+        line: usize,
+    },
+    Corrupted {
+        file: PathBuf,
+        line: usize,
+        reason: String,
+    },
+    MissingRecords {
+        file: PathBuf,
+        line: usize,
+        expected: u64,
+        found: u64,
+    },
+    OutOfOrder {
+        file: PathBuf,
+        line: usize,
+        expected: u64,
+        found: u64,
+    },
+    HashMismatch {
+        file: PathBuf,
+        line: usize,
+        sequence: u64,
+    },
+    SnapshotMismatch {
+        sequence: u64,
+    },
+    EndsBeforeSnapshot {
+        last_sequence: u64,
+        snapshot_sequence: u64,
+    },
+}
+
+impl fmt::Display for LogIntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogIntegrityError::Unreadable { file, reason } => {
+                write!(f, "{:?} could not be read: {}", file, reason)
+            }
+            LogIntegrityError::Truncated { file, line } => write!(
+                f,
+                "{:?} is truncated: line {} was never completely written",
+                file, line
+            ),
+            LogIntegrityError::Corrupted { file, line, reason } => {
+                write!(f, "{:?} line {} is corrupted: {}", file, line, reason)
+            }
+            LogIntegrityError::MissingRecords {
+                file,
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{:?} line {}: expected sequence {} but found {}, records {} to {} are missing",
+                file,
+                line,
+                expected,
+                found,
+                expected,
+                found - 1
+            ),
+            LogIntegrityError::OutOfOrder {
+                file,
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{:?} line {}: expected sequence {} but found {}, the log is reordered or duplicated",
+                file, line, expected, found
+            ),
+            LogIntegrityError::HashMismatch {
+                file,
+                line,
+                sequence,
+            } => write!(
+                f,
+                "{:?} line {}: hash of record {} does not match its contents or predecessor",
+                file, line, sequence
+            ),
+            LogIntegrityError::SnapshotMismatch { sequence } => write!(
+                f,
+                "the snapshot taken at sequence {} does not match the log",
+                sequence
+            ),
+            LogIntegrityError::EndsBeforeSnapshot {
+                last_sequence,
+                snapshot_sequence,
+            } => write!(
+                f,
+                "the log ends at sequence {} but the snapshot was taken at sequence {}",
+                last_sequence, snapshot_sequence
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LogIntegrityError {}
+
+/// Hash of a record chained onto the hash of the record before it. The record's own hash
+/// field is left out, and the contents are hashed as canonical (key sorted) JSON.
+pub fn chain_hash<T: Serialize>(previous_hash: &str, record: &T) -> String {
+    let mut value = serde_json::to_value(record).expect("Log records always serialize");
+    if let Some(fields) = value.as_object_mut() {
+        fields.remove("hash");
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(previous_hash.as_bytes());
+    hasher.update(value.to_string().as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Session files of a stream, oldest first. Session names embed their start time, so lexical
+/// order is chronological order.
+pub fn list_session_files(folder: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = read_dir(folder) else {
+        return vec![];
+    };
+
+    let mut files: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|path| path.extension().map(|ext| ext == "ndjson").unwrap_or(false))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Reads every session file of a stream in order and verifies that sequence numbers are
+/// contiguous and that the hash chain is intact. Records written before sequence numbers and
+/// hashes existed are numbered by position and have their hash filled in, but only up to the
+/// first hashed record; a blank hash after it breaks the chain. Records written by
+/// older builds are verified as written and then upcast to the current schema.
+///
+/// A stream may start after sequence 1 when it was compacted; the anchor then supplies the
+/// hash the first remaining record chains onto.
+pub fn read_verified_log<T: ChainedRecord>(
+    folder: &Path,
+    anchor: Option<&LogAnchor>,
+) -> Result<Vec<T>, LogIntegrityError> {
+    Ok(read_verified_sessions(folder, anchor)?
+        .into_iter()
+        .flat_map(|(_file, records)| records)
+        .collect())
+}
+
+/// Same as `read_verified_log`, with the records grouped by the session file they came from.
+pub fn read_verified_sessions<T: ChainedRecord>(
+    folder: &Path,
+    anchor: Option<&LogAnchor>,
+) -> Result<Vec<(PathBuf, Vec<T>)>, LogIntegrityError> {
+    let mut sessions: Vec<(PathBuf, Vec<T>)> = vec![];
+    let mut first_record = true;
+    let mut hashed = false;
+    let mut previous_hash = String::new();
+    let mut expected_sequence = 1;
+
+    for file in list_session_files(folder) {
+        let contents = read_to_string(&file).map_err(|e| LogIntegrityError::Unreadable {
+            file: file.clone(),
+            reason: e.to_string(),
+        })?;
+
+        let mut records = vec![];
+        let terminated = contents.is_empty() || contents.ends_with('\n');
+        let lines: Vec<&str> = contents.lines().collect();
+        for (index, line) in lines.iter().enumerate() {
+            let line_number = index + 1;
+            if !terminated && line_number == lines.len() {
+                return Err(LogIntegrityError::Truncated {
+                    file: file.clone(),
+                    line: line_number,
+                });
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let corrupted = |reason: String| LogIntegrityError::Corrupted {
+                file: file.clone(),
+                line: line_number,
+                reason,
+            };
+            let mut raw: Value =
+                serde_json::from_str(line).map_err(|e| corrupted(e.to_string()))?;
+            let Some(fields) = raw.as_object_mut() else {
+                return Err(corrupted("record is not a JSON object".to_string()));
+            };
+            let recorded_sequence = fields.get("sequence").and_then(Value::as_u64).unwrap_or(0);
+            let recorded_hash = fields
+                .remove("hash")
+                .and_then(|hash| hash.as_str().map(str::to_string))
+                .unwrap_or_default();
+
+            // A stream that does not start at 1 was compacted. Its first record either chains
+            // onto the snapshot it was compacted against, or onto an older snapshot that has
+            // since been pruned, in which case its recorded hash is taken as given and the
+            // chain is checked against the newer snapshot further on.
+            let mut unanchored = false;
+            let sequence = match recorded_sequence {
+                0 => expected_sequence,
+                sequence if first_record && sequence > 1 => match anchor {
+                    Some(anchor) if anchor.sequence + 1 == sequence && !anchor.hash.is_empty() => {
+                        previous_hash = anchor.hash.clone();
+                        sequence
+                    }
+                    Some(anchor) if anchor.sequence + 1 >= sequence => {
+                        unanchored = true;
+                        sequence
+                    }
+                    _ => {
+                        return Err(LogIntegrityError::MissingRecords {
+                            file: file.clone(),
+                            line: line_number,
+                            expected: 1,
+                            found: sequence,
+                        });
+                    }
+                },
+                sequence => sequence,
+            };
+
+            if sequence > expected_sequence && !first_record {
+                return Err(LogIntegrityError::MissingRecords {
+                    file: file.clone(),
+                    line: line_number,
+                    expected: expected_sequence,
+                    found: sequence,
+                });
+            }
+            if sequence < expected_sequence {
+                return Err(LogIntegrityError::OutOfOrder {
+                    file: file.clone(),
+                    line: line_number,
+                    expected: expected_sequence,
+                    found: sequence,
+                });
+            }
+            fields.insert("sequence".to_string(), Value::from(sequence));
+
+            // The hash covers the record exactly as it was written, before any upcasting.
+            let hash = chain_hash(&previous_hash, &raw);
+            let legacy = recorded_hash.is_empty() && !hashed;
+            hashed |= !recorded_hash.is_empty();
+            let hash = if unanchored && !recorded_hash.is_empty() {
+                recorded_hash
+            } else if legacy || recorded_hash == hash {
+                hash
+            } else {
+                return Err(LogIntegrityError::HashMismatch {
+                    file: file.clone(),
+                    line: line_number,
+                    sequence,
+                });
+            };
+
+            if let Some(anchor) = anchor
+                .filter(|anchor| anchor.sequence == sequence && !anchor.hash.is_empty())
+                .filter(|anchor| anchor.hash != hash)
+            {
+                return Err(LogIntegrityError::SnapshotMismatch {
+                    sequence: anchor.sequence,
+                });
+            }
+
+            let upcast = upcast_record(raw, T::CURRENT_VERSION, T::UPCASTERS).map_err(corrupted)?;
+            let mut record = serde_json::from_value::<T>(upcast).map_err(|e| {
+                corrupted(format!(
+                    "does not match the version {} schema: {}",
+                    T::CURRENT_VERSION,
+                    e
+                ))
+            })?;
+            record.set_hash(hash.clone());
+            previous_hash = hash;
+            expected_sequence = sequence + 1;
+            first_record = false;
+            records.push(record);
+        }
+        sessions.push((file, records));
+    }
+
+    // An empty stream is fine, everything up to the snapshot may have been compacted away.
+    if let Some(anchor) = anchor {
+        let last_sequence = expected_sequence - 1;
+        if !first_record && last_sequence < anchor.sequence {
+            return Err(LogIntegrityError::EndsBeforeSnapshot {
+                last_sequence,
+                snapshot_sequence: anchor.sequence,
+            });
+        }
+    }
+
+    Ok(sessions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::{CURRENT_COMMAND_VERSION, LoggedCommand};
+    use shared::ClientActionCommand;
+    use std::fs::{create_dir_all, remove_dir_all, write};
+    use uuid::Uuid;
+
+    /// A command stream whose records are hashed from `first_hashed` on, one session file.
+    fn command_stream(records: u64, first_hashed: u64, blank_hash: Option<u64>) -> PathBuf {
+        let folder = std::env::temp_dir().join(format!("log_integrity_{}", Uuid::new_v4()));
+        create_dir_all(&folder).unwrap();
+
+        let mut previous_hash = String::new();
+        let mut lines = String::new();
+        for sequence in 1..=records {
+            let mut record = LoggedCommand {
+                version: CURRENT_COMMAND_VERSION,
+                sequence,
+                hash: String::new(),
+                timestamp_epoch_millis: sequence,
+                source_client_id: Uuid::nil(),
+                game_id: Uuid::nil(),
+                command: ClientActionCommand::EndTurn,
+            };
+            let hash = chain_hash(&previous_hash, &record);
+            if sequence >= first_hashed && blank_hash != Some(sequence) {
+                record.hash = hash.clone();
+            }
+            previous_hash = hash;
+            lines.push_str(&serde_json::to_string(&record).unwrap());
+            lines.push('\n');
+        }
+        write(folder.join("session.ndjson"), lines).unwrap();
+        folder
+    }
+
+    #[test]
+    fn blank_hashes_are_accepted_before_the_first_hashed_record() {
+        let folder = command_stream(5, 3, None);
+        let records = read_verified_log::<LoggedCommand>(&folder, None);
+        remove_dir_all(&folder).unwrap();
+
+        assert_eq!(records.unwrap().len(), 5);
+    }
+
+    #[test]
+    fn a_blank_hash_after_a_hashed_record_breaks_the_chain() {
+        let folder = command_stream(5, 1, Some(4));
+        let records = read_verified_log::<LoggedCommand>(&folder, None);
+        remove_dir_all(&folder).unwrap();
+
+        assert!(matches!(
+            records,
+            Err(LogIntegrityError::HashMismatch { sequence: 4, .. })
+        ));
+    }
+}
@@ -0,0 +1,575 @@
+use crate::systems::{
+    ChainedRecord, LogAnchor, LoggedCommand, LoggedEvent, chain_hash, load_latest_snapshot,
+    read_verified_sessions, stream_folder, update_snapshot_hashes,
+};
+use serde_json::Value;
+use sim_core::CEO_STARTING_WEALTH;
+use std::collections::HashMap;
+use std::fs::{File, rename};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use uuid::Uuid;
+
+/// Converts a record written at one schema version into the shape of the next version. It
+/// receives the whole record, so it can reshape the payload as well as the envelope; the
+/// `version` field is bumped by the caller.
+pub type Upcaster = fn(Value) -> Result<Value, String>;
+
+/// Upcasters for `LoggedEvent`, `EVENT_UPCASTERS[n]` upgrades version `n + 1` to `n + 2`.
+/// Whenever `InternalEvent` changes shape, append an upcaster here rather than editing old
+/// ones, so every log ever written stays readable.
+pub const EVENT_UPCASTERS: &[Upcaster] = &[
+    add_hiring_terms,
+    reshape_initiatives,
+    rescale_history_satisfaction,
+];
+
+/// Upcasters for `LoggedCommand`, indexed like `EVENT_UPCASTERS`.
+pub const COMMAND_UPCASTERS: &[Upcaster] = &[add_hiring_offer];
+
+/// Upcasters for `GameSnapshot`, indexed like `EVENT_UPCASTERS`.
+pub const SNAPSHOT_UPCASTERS: &[Upcaster] =
+    &[rescale_snapshot_percentages, move_operating_cash_to_company];
+
+pub const CURRENT_EVENT_VERSION: u32 = EVENT_UPCASTERS.len() as u32 + 1;
+pub const CURRENT_COMMAND_VERSION: u32 = COMMAND_UPCASTERS.len() as u32 + 1;
+pub const CURRENT_SNAPSHOT_VERSION: u32 = SNAPSHOT_UPCASTERS.len() as u32 + 1;
+
+/// Turns a percentage stored in hundredths of a percent into a whole percentage.
+fn hundredths_to_percent(value: &mut Value) {
+    if let Some(hundredths) = value.as_u64() {
+        *value = Value::from((hundredths / 100).min(100));
+    }
+}
+
+/// Employees were generated with satisfaction and productivity either in whole percents, which
+/// raises could push a little past a hundred, or in hundredths of a percent for VPs. Only the
+/// latter run into the thousands.
+const HUNDREDTHS_FLOOR: u64 = 1_000;
+
+/// Rescales the average satisfaction of every history point in a map of histories keyed by
+/// id, as found in `HistoryState`.
+fn rescale_history_points(histories: Option<&mut Value>) {
+    let Some(histories) = histories.and_then(Value::as_object_mut) else {
+        return;
+    };
+    for history_point in histories
+        .values_mut()
+        .filter_map(|history| history.get_mut("recent_history"))
+        .filter_map(Value::as_array_mut)
+        .flatten()
+    {
+        if let Some(satisfaction) = history_point.get_mut("avg_employee_satisfaction") {
+            hundredths_to_percent(satisfaction);
+        }
+    }
+}
+
+/// Version 2 gave `AddEmployedStatus` the role, level and salary the hire was made on. Every
+/// hire before that was made on the same fixed terms.
+fn add_hiring_terms(mut record: Value) -> Result<Value, String> {
+    if let Some(hire) = record
+        .pointer_mut("/event/AddEmployedStatus")
+        .and_then(Value::as_object_mut)
+    {
+        hire.entry("role").or_insert(Value::from("SalesRep"));
+        hire.entry("level").or_insert(Value::from(10_000));
+        hire.entry("salary").or_insert(Value::from(7_000));
+    }
+    Ok(record)
+}
+
+/// Version 3 turned `Initiative` from one variant per kind into a struct with a kind and the
+/// costs it was planned with. Initiatives from before had no costs.
+fn reshape_initiatives(mut record: Value) -> Result<Value, String> {
+    let Some(initiatives) = record
+        .pointer_mut("/event/SetOrgInitiatives/initiatives")
+        .and_then(Value::as_array_mut)
+    else {
+        return Ok(record);
+    };
+
+    for initiative in initiatives.iter_mut() {
+        let Some((kind, fields)) = initiative
+            .as_object()
+            .and_then(|variant| variant.iter().next())
+        else {
+            return Err(format!("unexpected initiative {}", initiative));
+        };
+        let weeks_remaining = fields
+            .get("weeks_remaining")
+            .cloned()
+            .unwrap_or(Value::from(0));
+        *initiative = serde_json::json!({
+            "kind": kind,
+            "weeks": weeks_remaining,
+            "weeks_remaining": weeks_remaining,
+            "upfront_cost": 0,
+            "weekly_cost": 0,
+        });
+    }
+    Ok(record)
+}
+
+/// Version 4 made satisfaction a percentage. History points recorded it in hundredths of a
+/// percent.
+fn rescale_history_satisfaction(mut record: Value) -> Result<Value, String> {
+    let Some(history_points) = record
+        .pointer_mut("/event/AppendHistoryPoint")
+        .and_then(Value::as_object_mut)
+    else {
+        return Ok(record);
+    };
+
+    for history_point in history_points
+        .values_mut()
+        .filter_map(Value::as_object_mut)
+        .flat_map(|points| points.values_mut())
+    {
+        if let Some(satisfaction) = history_point.get_mut("avg_employee_satisfaction") {
+            hundredths_to_percent(satisfaction);
+        }
+    }
+    Ok(record)
+}
+
+/// Snapshot version 2 made satisfaction and productivity percentages, clamped to a hundred.
+/// VPs had theirs in hundredths of a percent, and so did the history's average satisfaction.
+fn rescale_snapshot_percentages(mut record: Value) -> Result<Value, String> {
+    if let Some(entities) = record
+        .pointer_mut("/instance_game/game_state/entities")
+        .and_then(Value::as_object_mut)
+    {
+        for employment in entities
+            .values_mut()
+            .filter_map(|entity| entity.get_mut("employment"))
+            .filter_map(Value::as_object_mut)
+        {
+            for field in ["satisfaction", "productivity"] {
+                match employment.get_mut(field) {
+                    Some(value) if value.as_u64() >= Some(HUNDREDTHS_FLOOR) => {
+                        hundredths_to_percent(value)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    for histories in ["players", "companies", "organizations"] {
+        rescale_history_points(
+            record.pointer_mut(&format!("/instance_game/history_state/{}", histories)),
+        );
+    }
+    Ok(record)
+}
+
+/// Snapshot version 3 gave the CEO a personal account apart from the company's. The player's
+/// cash used to be the operator's company account, so it moves over to the company and the
+/// CEO starts out with what a new one would.
+fn move_operating_cash_to_company(mut record: Value) -> Result<Value, String> {
+    let Some(game_state) = record.pointer_mut("/instance_game/game_state") else {
+        return Err("snapshot has no game state".to_string());
+    };
+
+    // The operator's company is the rival-less one with the lowest id, and hyphenated ids
+    // sort the same as the ids themselves.
+    let company_id = game_state
+        .get("companies")
+        .and_then(Value::as_object)
+        .and_then(|companies| {
+            companies
+                .iter()
+                .filter(|(_, company)| company.get("rival").is_none_or(Value::is_null))
+                .map(|(company_id, _)| company_id.clone())
+                .min()
+        });
+    let Some(company_id) = company_id else {
+        return Ok(record);
+    };
+
+    let operating_cash = game_state
+        .pointer_mut("/players/0/financials/actual_cash")
+        .map(|cash| std::mem::replace(cash, Value::from(CEO_STARTING_WEALTH.get())))
+        .unwrap_or(Value::from(0));
+    if let Some(cash) =
+        game_state.pointer_mut(&format!("/companies/{}/financials/actual_cash", company_id))
+    {
+        *cash = operating_cash;
+    }
+    Ok(record)
+}
+
+/// Command version 2 made `HireEmployee` an offer with a role, level and salary. Older hires
+/// are read as the fixed terms every hire was made on before, with no signing bonus.
+fn add_hiring_offer(mut record: Value) -> Result<Value, String> {
+    if let Some(hire) = record
+        .pointer_mut("/command/HireEmployee")
+        .and_then(Value::as_object_mut)
+    {
+        hire.entry("role").or_insert(Value::from("SalesRep"));
+        hire.entry("level").or_insert(Value::from(10_000));
+        hire.entry("salary_offer").or_insert(Value::from(7_000));
+        hire.entry("signing_bonus").or_insert(Value::Null);
+    }
+    Ok(record)
+}
+
+/// Brings a raw record up to the current schema version by running every upcaster between
+/// the version it was written at and the current one.
+pub fn upcast_record(
+    mut record: Value,
+    current_version: u32,
+    upcasters: &[Upcaster],
+) -> Result<Value, String> {
+    let mut version = record
+        .get("version")
+        .and_then(Value::as_u64)
+        .unwrap_or(1)
+        .max(1) as u32;
+
+    if version > current_version {
+        return Err(format!(
+            "written at version {} by a newer server, this build reads up to version {}",
+            version, current_version
+        ));
+    }
+
+    while version < current_version {
+        let upcaster = upcasters
+            .get(version as usize - 1)
+            .ok_or_else(|| format!("no upcaster registered for version {}", version))?;
+        record = upcaster(record)
+            .map_err(|e| format!("cannot upgrade from version {}: {}", version, e))?;
+        version += 1;
+        record["version"] = Value::from(version);
+    }
+
+    Ok(record)
+}
+
+/// Offline rewrite of a game's event and command logs at the current schema version. The hash
+/// chains are recomputed over the rewritten records and the snapshots are updated to match.
+/// Must not be run while a server has the game loaded.
+pub fn migrate_logs(game_id: Uuid) -> anyhow::Result<()> {
+    let anchor = load_latest_snapshot(&game_id).map(|snapshot| LogAnchor {
+        sequence: snapshot.sequence,
+        hash: snapshot.event_hash,
+    });
+
+    let event_hashes = migrate_stream::<LoggedEvent>(&game_id, "event_stream", anchor.as_ref())?;
+    migrate_stream::<LoggedCommand>(&game_id, "command_stream", None)?;
+
+    let updated = update_snapshot_hashes(&game_id, &event_hashes)?;
+    println!(
+        "Migrated game {} to event version {} and command version {}, updated {} snapshots",
+        game_id, CURRENT_EVENT_VERSION, CURRENT_COMMAND_VERSION, updated
+    );
+    Ok(())
+}
+
+/// Rewrites every session file of a stream in place and returns the new hash of each record.
+fn migrate_stream<T: ChainedRecord>(
+    game_id: &Uuid,
+    stream_loc: &str,
+    anchor: Option<&LogAnchor>,
+) -> anyhow::Result<HashMap<u64, String>> {
+    let sessions: Vec<(PathBuf, Vec<T>)> =
+        read_verified_sessions(&stream_folder(game_id, stream_loc), anchor)?;
+
+    // A compacted stream chains onto its snapshot, anything else starts a fresh chain.
+    let first_sequence = sessions
+        .iter()
+        .flat_map(|(_, records)| records.first())
+        .map(|record| record.sequence())
+        .next();
+    let mut previous_hash = anchor
+        .filter(|anchor| Some(anchor.sequence + 1) == first_sequence)
+        .map(|anchor| anchor.hash.clone())
+        .unwrap_or_default();
+
+    let mut hashes = HashMap::new();
+    let mut rewritten = vec![];
+    for (file, records) in sessions {
+        let temporary_path = file.with_extension("ndjson.tmp");
+        let mut writer = BufWriter::new(File::create(&temporary_path)?);
+        for mut record in records {
+            record.set_hash(String::new());
+            let hash = chain_hash(&previous_hash, &record);
+            record.set_hash(hash.clone());
+            writeln!(writer, "{}", serde_json::to_string(&record)?)?;
+
+            hashes.insert(record.sequence(), hash.clone());
+            previous_hash = hash;
+        }
+        writer.flush()?;
+        rewritten.push((temporary_path, file));
+    }
+
+    // Only swap files in once every session has been written out.
+    let file_count = rewritten.len();
+    for (temporary_path, file) in rewritten {
+        rename(&temporary_path, &file)?;
+    }
+
+    println!(
+        "Rewrote {} records across {} files of {}",
+        hashes.len(),
+        file_count,
+        stream_loc
+    );
+    Ok(hashes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+
+    const EMPLOYEE_ID: &str = "00000000-0000-0000-0000-000000000001";
+    const ORGANIZATION_ID: &str = "00000000-0000-0000-0000-000000000002";
+
+    #[test]
+    fn hires_get_the_fixed_terms_they_were_made_on() {
+        let record = json!({
+            "version": 1,
+            "event": {"AddEmployedStatus": {
+                "employee_id": EMPLOYEE_ID,
+                "organization_id": ORGANIZATION_ID,
+            }},
+        });
+
+        assert_eq!(
+            add_hiring_terms(record).unwrap(),
+            json!({
+                "version": 1,
+                "event": {"AddEmployedStatus": {
+                    "employee_id": EMPLOYEE_ID,
+                    "organization_id": ORGANIZATION_ID,
+                    "role": "SalesRep",
+                    "level": 10_000,
+                    "salary": 7_000,
+                }},
+            })
+        );
+    }
+
+    #[test]
+    fn initiatives_become_structs_without_costs() {
+        let record = json!({
+            "version": 2,
+            "event": {"SetOrgInitiatives": {
+                "organization_id": ORGANIZATION_ID,
+                "initiatives": [{"Marketing": {"weeks_remaining": 3}}],
+            }},
+        });
+
+        assert_eq!(
+            reshape_initiatives(record).unwrap(),
+            json!({
+                "version": 2,
+                "event": {"SetOrgInitiatives": {
+                    "organization_id": ORGANIZATION_ID,
+                    "initiatives": [{
+                        "kind": "Marketing",
+                        "weeks": 3,
+                        "weeks_remaining": 3,
+                        "upfront_cost": 0,
+                        "weekly_cost": 0,
+                    }],
+                }},
+            })
+        );
+    }
+
+    #[test]
+    fn an_initiative_that_is_not_a_variant_is_rejected() {
+        let record = json!({
+            "version": 2,
+            "event": {"SetOrgInitiatives": {
+                "organization_id": ORGANIZATION_ID,
+                "initiatives": ["Marketing"],
+            }},
+        });
+
+        assert!(reshape_initiatives(record).is_err());
+    }
+
+    #[test]
+    fn history_point_satisfaction_becomes_a_percentage() {
+        let record = json!({
+            "version": 3,
+            "event": {"AppendHistoryPoint": {
+                "new_player_history_points": {EMPLOYEE_ID: {"avg_employee_satisfaction": 7_550}},
+                "new_organization_history_points": {
+                    ORGANIZATION_ID: {"avg_employee_satisfaction": 12_000},
+                },
+            }},
+        });
+
+        assert_eq!(
+            rescale_history_satisfaction(record).unwrap(),
+            json!({
+                "version": 3,
+                "event": {"AppendHistoryPoint": {
+                    "new_player_history_points": {EMPLOYEE_ID: {"avg_employee_satisfaction": 75}},
+                    "new_organization_history_points": {
+                        ORGANIZATION_ID: {"avg_employee_satisfaction": 100},
+                    },
+                }},
+            })
+        );
+    }
+
+    #[test]
+    fn hire_commands_become_offers_on_the_fixed_terms() {
+        let record = json!({
+            "version": 1,
+            "command": {"HireEmployee": {
+                "employee_id": EMPLOYEE_ID,
+                "organization_id": ORGANIZATION_ID,
+            }},
+        });
+
+        assert_eq!(
+            add_hiring_offer(record).unwrap(),
+            json!({
+                "version": 1,
+                "command": {"HireEmployee": {
+                    "employee_id": EMPLOYEE_ID,
+                    "organization_id": ORGANIZATION_ID,
+                    "role": "SalesRep",
+                    "level": 10_000,
+                    "salary_offer": 7_000,
+                    "signing_bonus": null,
+                }},
+            })
+        );
+    }
+
+    #[test]
+    fn snapshot_percentages_are_rescaled_only_where_stored_in_hundredths() {
+        let record = json!({
+            "version": 1,
+            "instance_game": {
+                "game_state": {"entities": {
+                    EMPLOYEE_ID: {"employment": {"satisfaction": 8_000, "productivity": 104}},
+                }},
+                "history_state": {"companies": {
+                    ORGANIZATION_ID: {"recent_history": [{"avg_employee_satisfaction": 6_000}]},
+                }},
+            },
+        });
+
+        assert_eq!(
+            rescale_snapshot_percentages(record).unwrap(),
+            json!({
+                "version": 1,
+                "instance_game": {
+                    "game_state": {"entities": {
+                        EMPLOYEE_ID: {"employment": {"satisfaction": 80, "productivity": 104}},
+                    }},
+                    "history_state": {"companies": {
+                        ORGANIZATION_ID: {"recent_history": [{"avg_employee_satisfaction": 60}]},
+                    }},
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn the_players_cash_moves_to_the_operators_company() {
+        let record = json!({
+            "version": 2,
+            "instance_game": {"game_state": {
+                "players": [{"financials": {"actual_cash": 250_000}}],
+                "companies": {
+                    EMPLOYEE_ID: {"rival": null, "financials": {"actual_cash": 0}},
+                    ORGANIZATION_ID: {"rival": {}, "financials": {"actual_cash": 90_000}},
+                },
+            }},
+        });
+
+        assert_eq!(
+            move_operating_cash_to_company(record).unwrap(),
+            json!({
+                "version": 2,
+                "instance_game": {"game_state": {
+                    "players": [{"financials": {"actual_cash": CEO_STARTING_WEALTH.get()}}],
+                    "companies": {
+                        EMPLOYEE_ID: {"rival": null, "financials": {"actual_cash": 250_000}},
+                        ORGANIZATION_ID: {"rival": {}, "financials": {"actual_cash": 90_000}},
+                    },
+                }},
+            })
+        );
+    }
+
+    #[test]
+    fn a_first_version_hire_reads_as_a_current_event() {
+        let record = json!({
+            "version": 1,
+            "timestamp_epoch_millis": 1,
+            "game_id": Uuid::nil(),
+            "event": {"AddEmployedStatus": {
+                "employee_id": EMPLOYEE_ID,
+                "organization_id": ORGANIZATION_ID,
+            }},
+        });
+
+        let record = upcast_record(record, CURRENT_EVENT_VERSION, EVENT_UPCASTERS).unwrap();
+        let logged: LoggedEvent = serde_json::from_value(record).unwrap();
+        assert_eq!(logged.version, CURRENT_EVENT_VERSION);
+    }
+
+    #[test]
+    fn migrating_twice_leaves_the_logs_as_the_first_migration_wrote_them() {
+        let game_id = Uuid::new_v4();
+        let game_folder = PathBuf::from(format!("./_out/games/{}", game_id));
+        let event_line = json!({
+            "version": 1,
+            "timestamp_epoch_millis": 1,
+            "game_id": game_id,
+            "event": {"AddEmployedStatus": {
+                "employee_id": EMPLOYEE_ID,
+                "organization_id": ORGANIZATION_ID,
+            }},
+        });
+        let command_line = json!({
+            "version": 1,
+            "timestamp_epoch_millis": 1,
+            "source_client_id": Uuid::nil(),
+            "game_id": game_id,
+            "command": {"HireEmployee": {
+                "employee_id": EMPLOYEE_ID,
+                "organization_id": ORGANIZATION_ID,
+            }},
+        });
+        for (stream_loc, line) in [
+            ("event_stream", event_line),
+            ("command_stream", command_line),
+        ] {
+            let folder = stream_folder(&game_id, stream_loc);
+            create_dir_all(&folder).unwrap();
+            write(folder.join("session.ndjson"), format!("{}\n", line)).unwrap();
+        }
+        let read_streams = || {
+            ["event_stream", "command_stream"]
+                .map(|stream_loc| {
+                    read_to_string(stream_folder(&game_id, stream_loc).join("session.ndjson"))
+                })
+                .map(Result::unwrap)
+        };
+
+        let first = migrate_logs(game_id).map(|_| read_streams());
+        let second = migrate_logs(game_id).map(|_| read_streams());
+        remove_dir_all(&game_folder).unwrap();
+
+        let first = first.unwrap();
+        assert_eq!(first, second.unwrap());
+        let logged: LoggedEvent = serde_json::from_str(first[0].trim()).unwrap();
+        assert_eq!(logged.version, CURRENT_EVENT_VERSION);
+        assert!(!logged.hash.is_empty());
+    }
+}
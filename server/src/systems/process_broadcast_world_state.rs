@@ -10,12 +10,17 @@ pub fn process_broadcast_world_state(mut instances: ResMut<Instances>) {
             continue;
         }
 
-        let _ = instance.tx_to_clients.try_send(ServerEvent::FullState(
-            instance.instance_game.game_state.clone(),
-        ));
+        let _ = instance.tx_to_clients.try_send(ServerEvent::FullState {
+            game_state: instance.world.instance_game.game_state.clone(),
+            clock: instance.clock.state(),
+        });
 
         let _ = instance.tx_to_clients.try_send(ServerEvent::HistoryState(
-            instance.instance_game.history_state.clone(),
+            instance.world.instance_game.history_state.recent(),
+        ));
+
+        let _ = instance.tx_to_clients.try_send(ServerEvent::ReportStore(
+            instance.world.instance_game.report_store.clone(),
         ));
     }
 }
@@ -1,380 +1,261 @@
-use crate::systems::{CommandLog, write_command_to_log_stream};
+use crate::systems::{CommandLog, apply_event, write_command_to_log_stream};
 use crate::{GameClientActionCommand, GameClientInternalEvent, Instance, Instances};
-use bevy::prelude::ResMut;
-use bevy::utils::HashMap;
-use shared::{ClientActionCommand, HistoryPoint, InternalEvent, OrganizationRole};
-use tracing::{debug, info};
+use bevy::prelude::{Res, ResMut, Time};
+use shared::{ClientActionCommand, ClockSpeed, GameOutcome, InternalEvent, ServerEvent, TickMode};
+use sim_core::{CommandRejection, company_updates, ends_week, organization_updates};
+use tracing::{debug, error, info};
 use uuid::Uuid;
 
+/// Source of commands the server issues on its own, such as ending a real-time week.
+pub const SERVER_SOURCE_ID: Uuid = Uuid::nil();
+
 // Provides fan-out capabilities. Consumes events via the receiver and fans them out
 // to all who need to listen.
-pub fn process_commands(mut command_log: ResMut<CommandLog>, mut instances: ResMut<Instances>) {
+pub fn process_commands(
+    time: Res<Time>,
+    mut command_log: ResMut<CommandLog>,
+    mut instances: ResMut<Instances>,
+) {
+    let instances = &mut *instances;
     for (game_id, instance) in instances.active_instances.iter_mut() {
+        let mut client_action_commands = vec![];
         while let Ok(client_action_command) = instance.rx_from_client.try_recv() {
-            debug!("Writing command: {:?}", client_action_command);
-            write_command_to_log_stream(
+            client_action_commands.push(client_action_command);
+        }
+
+        // The clock only runs while someone is connected to the game.
+        let has_clients = instances
+            .active_connections
+            .values()
+            .any(|client_info| client_info.game_id == *game_id);
+        if has_clients {
+            instance.clock.advance(time.delta());
+        }
+        // A finished game stays where it ended.
+        let is_finished = instance.world.game_state().board.outcome.is_some();
+        if instance.clock.week_is_over() && !is_finished {
+            // Start the next week right away so the tick is not issued twice.
+            instance.clock.start_week();
+            client_action_commands.push(GameClientActionCommand {
+                source_client_id: SERVER_SOURCE_ID,
+                game_id: *game_id,
+                command: ClientActionCommand::EndTurn,
+            });
+        }
+
+        for client_action_command in client_action_commands {
+            instance.command_sequence += 1;
+            debug!(
+                "Writing command {}: {:?}",
+                instance.command_sequence, client_action_command
+            );
+            instance.command_hash = write_command_to_log_stream(
                 &mut command_log,
                 game_id,
+                instance.command_sequence,
+                &instance.command_hash,
                 &client_action_command.source_client_id,
                 client_action_command.command.clone(),
             );
 
             debug!("Processing command: {:?}", client_action_command);
-            process_command(game_id, client_action_command, instance);
+            let source_client_id = client_action_command.source_client_id;
+            if let Err(rejection) = process_command(game_id, client_action_command, instance) {
+                info!(
+                    "Rejected command from client {}: {}",
+                    source_client_id, rejection
+                );
+                if let Some(client_info) = instances.active_connections.get(&source_client_id) {
+                    let _ = client_info.sender.try_send(ServerEvent::CommandRejected {
+                        reason: rejection.to_string(),
+                    });
+                }
+            }
         }
     }
 }
 
-fn process_command(
+pub fn process_command(
     game_id: &Uuid,
     client_action_command: GameClientActionCommand,
     instance: &mut Instance,
-) {
-    let client_action_command = client_action_command.command;
-
+) -> Result<(), CommandRejection> {
+    let command = client_action_command.command;
+    // Weeks of a turn-based game only pass when the operator ends them.
+    if instance.clock.tick_mode == TickMode::TurnBased
+        && matches!(command, ClientActionCommand::SetClockSpeed { speed } if speed != ClockSpeed::Paused)
+    {
+        return Err(CommandRejection::TurnBasedClock);
+    }
+    let internal_events = instance.world.process_command(command.clone())?;
     instance.needs_state_update = true;
 
-    info!(
-        "Server has clients command for processing {:?}",
-        client_action_command
-    );
-
-    match client_action_command {
-        ClientActionCommand::FireEmployee { employee_id } => {
-            if let Some(employee) = instance.instance_game.game_state.entities.get(&employee_id) {
-                info!("Firing employee: {}", employee.name);
-
-                let _ = instance
-                    .tx_internal_events
-                    .try_send(GameClientInternalEvent {
-                        game_id: *game_id,
-                        internal_event: InternalEvent::RemoveEmployedStatus { employee_id },
-                    });
-
-                for (org_id, org) in &instance.instance_game.game_state.organizations {
-                    if org.vp == Some(employee_id) {
-                        let _ = instance
-                            .tx_internal_events
-                            .try_send(GameClientInternalEvent {
-                                game_id: *game_id,
-                                internal_event: InternalEvent::SetOrgVp {
-                                    organization_id: *org_id,
-                                    employee_id: None,
-                                },
-                            });
-                    }
-                }
-            }
+    match command {
+        ClientActionCommand::EndTurn => instance.clock.start_week(),
+        ClientActionCommand::SetClockSpeed { speed } => {
+            instance.clock.speed = speed;
+            instance.needs_broadcast = true;
         }
+        _ => {}
+    }
 
-        ClientActionCommand::HireEmployee {
-            organization_id,
-            employee_id,
-        } => {
-            if let Some((employee_id, employee)) = instance
-                .instance_game
-                .game_state
-                .entities
-                .iter()
-                .find(|(entity_id, _)| **entity_id == employee_id)
-            {
-                info!("Hiring employee: {}", employee.name);
-                instance
-                    .tx_internal_events
-                    .try_send(GameClientInternalEvent {
-                        game_id: *game_id,
-                        internal_event: InternalEvent::AddEmployedStatus {
-                            organization_id,
-                            employee_id: *employee_id,
-                        },
-                    })
-                    .unwrap();
-            }
-        }
-
-        ClientActionCommand::GiveRaise {
-            employee_id,
-            amount,
-        } => {
-            if let Some((employee_id, employee)) = instance
-                .instance_game
-                .game_state
-                .entities
-                .iter()
-                .find(|(entity_id, _)| **entity_id == employee_id)
-            {
-                info!("Growing employee: {}", employee.name);
-
-                instance
-                    .tx_internal_events
-                    .try_send(GameClientInternalEvent {
-                        game_id: *game_id,
-                        internal_event: InternalEvent::IncrementEmployeeSatisfaction {
-                            employee_id: *employee_id,
-                            amount: 1,
-                        },
-                    })
-                    .unwrap();
-
-                instance
-                    .tx_internal_events
-                    .try_send(GameClientInternalEvent {
-                        game_id: *game_id,
-                        internal_event: InternalEvent::IncrementSalary {
-                            employee_id: *employee_id,
-                            amount,
-                        },
-                    })
-                    .unwrap();
-            }
-        }
-
-        ClientActionCommand::LaunchPRCampaign => {
-            instance
-                .tx_internal_events
-                .try_send(GameClientInternalEvent {
-                    game_id: *game_id,
-                    internal_event: InternalEvent::IncrementReputation { amount: 1 },
-                })
-                .unwrap();
-
-            instance
-                .tx_internal_events
-                .try_send(GameClientInternalEvent {
-                    game_id: *game_id,
-                    internal_event: InternalEvent::DecrementMoney { amount: 1_000 },
-                })
-                .unwrap();
-        }
+    // The stages of `World::step` in its order, so the command log alone replays the game.
+    let week_ended = ends_week(&internal_events);
+    queue_events(game_id, internal_events, instance)?;
+    if week_ended {
+        let internal_events = organization_updates(instance.world.game_state());
+        queue_events(game_id, internal_events, instance)?;
+    }
+    let internal_events = company_updates(instance.world.game_state());
+    queue_events(game_id, internal_events, instance)
+}
 
-        ClientActionCommand::DoNothing => {
-            info!("Player did nothing this turn.");
+/// Sends events on to be logged and applies them, so whatever comes next sees them.
+fn queue_events(
+    game_id: &Uuid,
+    internal_events: Vec<InternalEvent>,
+    instance: &mut Instance,
+) -> Result<(), CommandRejection> {
+    for internal_event in internal_events {
+        if let InternalEvent::RecordCorporateEvent { corporate_event } = &internal_event {
+            let _ = instance
+                .tx_to_clients
+                .try_send(ServerEvent::CorporateEventDrawn {
+                    corporate_event: corporate_event.clone(),
+                });
         }
-
-        ClientActionCommand::PromoteToVp {
-            organization_id,
-            employee_id,
-        } => {
-            instance
-                .tx_internal_events
-                .try_send(GameClientInternalEvent {
-                    game_id: *game_id,
-                    internal_event: InternalEvent::SetOrganizationRole {
-                        employee_id,
-                        new_role: OrganizationRole::VP,
-                    },
-                })
-                .unwrap();
-
-            let existing_vp_id = instance
-                .instance_game
-                .game_state
-                .organizations
-                .get(&organization_id)
-                .and_then(|org| org.vp);
-
-            if let Some(employee_id) = existing_vp_id {
-                instance
-                    .tx_internal_events
-                    .try_send(GameClientInternalEvent {
-                        game_id: *game_id,
-                        internal_event: InternalEvent::SetOrganizationRole {
-                            employee_id,
-                            new_role: OrganizationRole::HRManager,
-                        },
-                    })
-                    .unwrap();
-            }
-
-            instance
-                .tx_internal_events
-                .try_send(GameClientInternalEvent {
-                    game_id: *game_id,
-                    internal_event: InternalEvent::SetOrgVp {
-                        organization_id,
-                        employee_id: Some(employee_id),
-                    },
-                })
-                .unwrap();
+        if let InternalEvent::EndGame { outcome } = &internal_event {
+            let server_event = match outcome.clone() {
+                GameOutcome::GameOver { reason, report } => {
+                    ServerEvent::GameOver { reason, report }
+                }
+                GameOutcome::Victory { reason, report } => ServerEvent::Victory { reason, report },
+            };
+            let _ = instance.tx_to_clients.try_send(server_event);
         }
-
-        ClientActionCommand::UpdateBudget {
-            organization_id,
-            organization_budget,
-        } => instance
-            .tx_internal_events
-            .try_send(GameClientInternalEvent {
-                game_id: *game_id,
-                internal_event: InternalEvent::SetOrgBudget {
-                    organization_id,
-                    budget: organization_budget,
-                },
-            })
-            .unwrap(),
-    }
-
-    let total_productivity: i32 = instance
-        .instance_game
-        .game_state
-        .entities
-        .values()
-        .filter(|entity| entity.employment.is_some())
-        .filter_map(|entity| entity.employment.as_ref().map(|e| e.satisfaction as i32))
-        .sum();
-
-    let total_expenses = instance
-        .instance_game
-        .game_state
-        .entities
-        .values()
-        .filter(|entity| entity.employment.is_some())
-        .filter_map(|entity| entity.employment.as_ref().map(|e| e.salary as i32))
-        .sum();
-
-    instance
-        .tx_internal_events
-        .try_send(GameClientInternalEvent {
+        // Logged first, a command whose events can't be logged is rejected rather than
+        // applied. Events sent before a failure are already applied and will be logged.
+        let internal_event = GameClientInternalEvent {
             game_id: *game_id,
-            internal_event: InternalEvent::IncrementMoney {
-                amount: total_productivity,
-            },
-        })
-        .unwrap();
-
-    instance
-        .tx_internal_events
-        .try_send(GameClientInternalEvent {
-            game_id: *game_id,
-            internal_event: InternalEvent::DecrementMoney {
-                amount: total_expenses,
-            },
-        })
-        .unwrap();
-
-    let mut new_player_history_points = HashMap::new();
-    let mut new_company_history_points = HashMap::new();
-    let mut new_organization_history_points = HashMap::new();
-
-    for player in &instance.instance_game.game_state.players {
-        let employees = instance
-            .instance_game
-            .game_state
-            .entities
-            .values()
-            .filter_map(|entity| entity.employment.as_ref());
-
-        let (total_satisfaction, employee_count) = employees
-            .map(|e| e.satisfaction as u32)
-            .fold((0, 0), |(sum, count), sat| (sum + sat, count + 1));
-
-        let avg_employee_satisfaction = if employee_count > 0 {
-            ((total_satisfaction * 10000) / employee_count).min(10000) as u16
-        } else {
-            0
+            internal_event,
         };
-
-        let history_point = HistoryPoint {
-            week: instance.instance_game.game_state.week,
-            financials: player.financials.clone(),
-            perception: player.perception.clone(),
-            avg_employee_satisfaction,
-        };
-
-        new_player_history_points.insert(
-            player.id.unwrap_or(Uuid::from_u128(987123564738)),
-            history_point,
-        );
+        if let Err(e) = instance.tx_internal_events.try_send(internal_event.clone()) {
+            error!("Failed to queue event for game {}: {}", game_id, e);
+            return Err(CommandRejection::EventsNotRecorded);
+        }
+        apply_event(&internal_event.internal_event, instance);
     }
+    Ok(())
+}
 
-    for (company_id, company) in &instance.instance_game.game_state.companies {
-        let (total_satisfaction, employee_count): (u32, u32) = instance
-            .instance_game
-            .game_state
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_channel::unbounded;
+    use shared::WorldGenerationParameters;
+    use sim_core::company_of;
+
+    #[test]
+    fn commands_in_one_tick_see_each_others_events() {
+        let (tx_to_clients, _rx_to_clients) = unbounded();
+        let (_tx_from_client, rx_from_client) = unbounded();
+        let mut instance = Instance::new(
+            &WorldGenerationParameters::default(),
+            TickMode::TurnBased,
+            tx_to_clients,
+            rx_from_client,
+        );
+        let game_id = Uuid::new_v4();
+        let game_state = instance.world.game_state();
+        let company_id = game_state.player_company_id();
+        let employee_id = game_state
             .entities
             .values()
-            .filter_map(|entity| {
-                let employment = entity.employment.as_ref()?;
-                let organization = instance
-                    .instance_game
-                    .game_state
-                    .organizations
-                    .get(&employment.organization_id)?;
-                if organization.company_relation.entity_id == *company_id {
-                    Some((employment.satisfaction as u32, 1))
-                } else {
-                    None
-                }
+            .find(|entity| {
+                entity.employment.as_ref().is_some_and(|employment| {
+                    company_of(game_state, &employment.organization_id) == company_id
+                })
             })
-            .fold((0, 0), |(sum, count), (satisfaction, one)| {
-                (sum + satisfaction, count + one)
-            });
-
-        let avg_employee_satisfaction: u16 = if employee_count > 0 {
-            ((total_satisfaction * 10000) / employee_count).min(10000) as u16
-        } else {
-            0
-        };
+            .map(|entity| entity.id)
+            .expect("the player's company has staff");
 
-        let history_point = HistoryPoint {
-            week: instance.instance_game.game_state.week,
-            financials: company.financials.clone(),
-            perception: company.perception.clone(),
-            avg_employee_satisfaction,
+        let fire = || GameClientActionCommand {
+            source_client_id: Uuid::new_v4(),
+            game_id,
+            command: ClientActionCommand::FireEmployee { employee_id },
         };
-
-        new_company_history_points.insert(*company_id, history_point);
+        assert!(process_command(&game_id, fire(), &mut instance).is_ok());
+        assert!(matches!(
+            process_command(&game_id, fire(), &mut instance),
+            Err(CommandRejection::NotEmployed { .. })
+        ));
+
+        // The accepted command's events still go on to be logged.
+        assert!(instance.rx_internal_events.try_recv().is_ok());
     }
 
-    for (organization_id, organization) in &instance.instance_game.game_state.organizations {
-        let (total_satisfaction, employee_count): (u32, u32) = instance
-            .instance_game
-            .game_state
-            .entities
-            .values()
-            .filter_map(|entity| {
-                entity
-                    .employment
-                    .as_ref()
-                    .filter(|e| e.organization_id == *organization_id)
-                    .map(|e| (e.satisfaction as u32, 1))
-            })
-            .fold((0, 0), |(sum, count), (satisfaction, one)| {
-                (sum + satisfaction, count + one)
-            });
+    #[test]
+    fn the_server_runs_commands_as_world_step_does() {
+        let (tx_to_clients, _rx_to_clients) = unbounded();
+        let (_tx_from_client, rx_from_client) = unbounded();
+        let parameters = WorldGenerationParameters::default();
+        let mut instance = Instance::new(
+            &parameters,
+            TickMode::TurnBased,
+            tx_to_clients,
+            rx_from_client,
+        );
+        let mut world = sim_core::World::generate(&parameters);
+        let game_id = Uuid::new_v4();
+
+        let commands = [
+            ClientActionCommand::LaunchPRCampaign,
+            ClientActionCommand::EndTurn,
+            ClientActionCommand::DoNothing,
+            ClientActionCommand::EndTurn,
+            ClientActionCommand::EndTurn,
+        ];
+        for command in commands {
+            let server_result = process_command(
+                &game_id,
+                GameClientActionCommand {
+                    source_client_id: SERVER_SOURCE_ID,
+                    game_id,
+                    command: command.clone(),
+                },
+                &mut instance,
+            );
+            assert_eq!(server_result.is_ok(), world.step(command).is_ok());
+        }
 
-        let avg_employee_satisfaction: u16 = if employee_count > 0 {
-            ((total_satisfaction * 10000) / employee_count).min(10000) as u16
-        } else {
-            0
-        };
+        assert_eq!(
+            serde_json::to_value(&instance.world.instance_game).unwrap(),
+            serde_json::to_value(&world.instance_game).unwrap()
+        );
+    }
 
-        let history_point = HistoryPoint {
-            week: instance.instance_game.game_state.week,
-            financials: organization.financials.clone(),
-            perception: organization.perception.clone(),
-            avg_employee_satisfaction,
+    #[test]
+    fn a_turn_based_clock_cannot_be_started() {
+        let (tx_to_clients, _rx_to_clients) = unbounded();
+        let (_tx_from_client, rx_from_client) = unbounded();
+        let mut instance = Instance::new(
+            &WorldGenerationParameters::default(),
+            TickMode::TurnBased,
+            tx_to_clients,
+            rx_from_client,
+        );
+        let game_id = Uuid::new_v4();
+        let set_speed = |speed| GameClientActionCommand {
+            source_client_id: SERVER_SOURCE_ID,
+            game_id,
+            command: ClientActionCommand::SetClockSpeed { speed },
         };
 
-        new_organization_history_points.insert(*organization_id, history_point);
+        assert!(matches!(
+            process_command(&game_id, set_speed(ClockSpeed::Normal), &mut instance),
+            Err(CommandRejection::TurnBasedClock)
+        ));
+        assert_eq!(instance.clock.speed, ClockSpeed::Paused);
+        assert!(process_command(&game_id, set_speed(ClockSpeed::Paused), &mut instance).is_ok());
     }
-
-    instance
-        .tx_internal_events
-        .try_send(GameClientInternalEvent {
-            game_id: *game_id,
-            internal_event: InternalEvent::AppendHistoryPoint {
-                new_player_history_points,
-                new_organization_history_points,
-                new_company_history_points,
-            },
-        })
-        .unwrap();
-
-    instance
-        .tx_internal_events
-        .try_send(GameClientInternalEvent {
-            game_id: *game_id,
-            internal_event: InternalEvent::AdvanceWeek,
-        })
-        .unwrap();
 }
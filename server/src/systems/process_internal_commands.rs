@@ -3,7 +3,7 @@ use crate::systems::{ClientInfo, InternalCommandReceiver};
 use crate::{GameServiceResource, Instances};
 use bevy::prelude::{Res, ResMut};
 use log::{debug, error};
-use shared::{AvailableGame, OperatorMode, ServerEvent};
+use shared::{AvailableGame, HistoryRange, OperatorMode, ServerEvent};
 use tracing::info;
 
 pub fn process_internal_commands(
@@ -42,11 +42,19 @@ pub fn process_internal_commands(
                     instance.needs_broadcast = true;
                 } else {
                     debug!("Client connected to server, game not in memory");
-                    instances.add_new_instance(
+                    if let Err(e) = instances.add_new_instance(
                         &client_info.game_id,
                         tx_to_clients,
                         rx_from_clients,
-                    );
+                    ) {
+                        error!("Failed to load game {}: {}", client_info.game_id, e);
+                        instances.active_connections.remove(&client_info.id);
+                        let _ = client_info.sender.try_send(ServerEvent::GameLoadFailed {
+                            game_id: client_info.game_id,
+                            reason: e.to_string(),
+                        });
+                        continue;
+                    }
                     let instance = instances
                         .active_instances
                         .get_mut(&client_info.game_id)
@@ -71,6 +79,8 @@ pub fn process_internal_commands(
             InternalCommand::CreateGame {
                 client_id,
                 game_name,
+                world_parameters,
+                tick_mode,
             } => {
                 let game_service = game_service.game_service.clone();
                 let tx_to_clients = instances
@@ -79,9 +89,15 @@ pub fn process_internal_commands(
                     .map(|c| c.sender.clone());
 
                 async_std::task::spawn(async move {
-                    match game_service.create_game(game_name.clone()).await {
+                    match game_service
+                        .create_game(game_name.clone(), world_parameters, tick_mode)
+                        .await
+                    {
                         Ok(game_metadata) => {
-                            info!("Game created successfully: {:?}", game_metadata.id);
+                            info!(
+                                "Game created successfully: {:?} with seed {}",
+                                game_metadata.id, game_metadata.world_parameters.seed
+                            );
                             if let Some(tx) = tx_to_clients {
                                 let _ = tx
                                     .send(ServerEvent::GameCreated {
@@ -187,6 +203,34 @@ pub fn process_internal_commands(
                     }
                 });
             }
+            InternalCommand::RequestHistory {
+                client_id,
+                game_id,
+                from_week,
+                to_week,
+                resolution,
+            } => {
+                let (Some(instance), Some(client_info)) = (
+                    instances.active_instances.get(&game_id),
+                    instances.active_connections.get(&client_id),
+                ) else {
+                    continue;
+                };
+
+                let history = instance
+                    .world
+                    .instance_game
+                    .history_state
+                    .range(from_week, to_week, resolution);
+                let _ = client_info
+                    .sender
+                    .try_send(ServerEvent::HistoryRange(HistoryRange {
+                        from_week,
+                        to_week,
+                        resolution,
+                        history,
+                    }));
+            }
         }
     }
 }
@@ -0,0 +1,153 @@
+use crate::systems::{CURRENT_SNAPSHOT_VERSION, SNAPSHOT_UPCASTERS, current_millis, upcast_record};
+use crate::{Instance, Instances};
+use bevy::prelude::ResMut;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shared::GameInstanceData;
+use std::collections::HashMap;
+use std::fs::{File, create_dir_all, read_dir, remove_file, rename};
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+pub const SNAPSHOT_INTERVAL_WEEKS: u16 = 10;
+const MAX_RETAINED_SNAPSHOTS: usize = 3;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GameSnapshot {
+    pub version: u32,
+    pub sequence: u64,
+    #[serde(default)]
+    pub event_hash: String,
+    pub timestamp_epoch_millis: u64,
+    pub game_id: Uuid,
+    pub instance_game: GameInstanceData,
+}
+
+pub fn process_snapshots(mut instances: ResMut<Instances>) {
+    for (game_id, instance) in instances.active_instances.iter_mut() {
+        if !instance.needs_snapshot {
+            continue;
+        }
+        instance.needs_snapshot = false;
+
+        match write_snapshot(game_id, instance) {
+            Ok(path) => info!(
+                "Wrote snapshot at event {} to {:?}",
+                instance.event_sequence, path
+            ),
+            Err(e) => error!("Failed to write snapshot for game {}: {:?}", game_id, e),
+        }
+    }
+}
+
+pub fn snapshot_folder(game_id: &Uuid) -> PathBuf {
+    PathBuf::from(format!("./_out/games/{}/snapshots", game_id))
+}
+
+pub fn write_snapshot(game_id: &Uuid, instance: &Instance) -> anyhow::Result<PathBuf> {
+    let folder = snapshot_folder(game_id);
+    create_dir_all(&folder)?;
+
+    let snapshot = GameSnapshot {
+        version: CURRENT_SNAPSHOT_VERSION,
+        sequence: instance.event_sequence,
+        event_hash: instance.event_hash.clone(),
+        timestamp_epoch_millis: current_millis(),
+        game_id: *game_id,
+        instance_game: instance.world.instance_game.clone(),
+    };
+
+    let path = folder.join(format!("snapshot-{:020}.json", snapshot.sequence));
+    write_snapshot_file(&path, &snapshot)?;
+
+    prune_snapshots(game_id);
+    Ok(path)
+}
+
+fn write_snapshot_file(path: &Path, snapshot: &GameSnapshot) -> anyhow::Result<()> {
+    // Write to a temporary file first so a crash never leaves a half written snapshot behind.
+    let temporary_path = path.with_extension("json.tmp");
+    let mut writer = BufWriter::new(File::create(&temporary_path)?);
+    serde_json::to_writer(&mut writer, snapshot)?;
+    writer.flush()?;
+    drop(writer);
+    rename(&temporary_path, path)?;
+    Ok(())
+}
+
+/// Points existing snapshots at new event hashes after the event log was rewritten. Returns
+/// the number of snapshots updated.
+pub fn update_snapshot_hashes(
+    game_id: &Uuid,
+    event_hashes: &HashMap<u64, String>,
+) -> anyhow::Result<usize> {
+    let mut updated = 0;
+    for path in list_snapshots(game_id) {
+        let mut snapshot = read_snapshot(&path)?;
+        let Some(hash) = event_hashes.get(&snapshot.sequence) else {
+            continue;
+        };
+
+        snapshot.event_hash = hash.clone();
+        write_snapshot_file(&path, &snapshot)?;
+        updated += 1;
+    }
+    Ok(updated)
+}
+
+/// Snapshot files for a game, ordered from oldest to newest event sequence.
+pub fn list_snapshots(game_id: &Uuid) -> Vec<PathBuf> {
+    let Ok(entries) = read_dir(snapshot_folder(game_id)) else {
+        return vec![];
+    };
+
+    let mut files: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|e| e.path())
+        .filter(|path| {
+            path.extension().map(|ext| ext == "json").unwrap_or(false)
+                && path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().starts_with("snapshot-"))
+                    .unwrap_or(false)
+        })
+        .collect();
+
+    // Sequence numbers are zero padded, so lexical order is sequence order.
+    files.sort();
+    files
+}
+
+/// Reads a snapshot written by any version of the server, upgraded to the current schema.
+fn read_snapshot(path: &Path) -> anyhow::Result<GameSnapshot> {
+    let raw: Value = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+    let upcast = upcast_record(raw, CURRENT_SNAPSHOT_VERSION, SNAPSHOT_UPCASTERS)
+        .map_err(anyhow::Error::msg)?;
+    Ok(serde_json::from_value(upcast)?)
+}
+
+pub fn load_latest_snapshot(game_id: &Uuid) -> Option<GameSnapshot> {
+    for path in list_snapshots(game_id).iter().rev() {
+        match read_snapshot(path) {
+            Ok(snapshot) => return Some(snapshot),
+            Err(e) => warn!("Skipping unreadable snapshot {:?}: {:?}", path, e),
+        }
+    }
+
+    None
+}
+
+fn prune_snapshots(game_id: &Uuid) {
+    let snapshots = list_snapshots(game_id);
+    if snapshots.len() <= MAX_RETAINED_SNAPSHOTS {
+        return;
+    }
+
+    for path in &snapshots[..snapshots.len() - MAX_RETAINED_SNAPSHOTS] {
+        if let Err(e) = remove_file(path) {
+            warn!("Failed to remove old snapshot {:?}: {:?}", path, e);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::systems::{LoggedCommand, read_verified_log, redrive_event_logs, stream_folder};
+use crate::{Instance, load_world_parameters};
+use async_channel::unbounded;
+use serde_json::Value;
+use shared::TickMode;
+use sim_core::World;
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+const MAX_REPORTED_DIFFERENCES: usize = 50;
+
+/// Offline re-simulation of a game. Every logged command is fed back through the same command
+/// processing and update steps the server runs, one `World::step` per command, against a
+/// freshly generated world. The result is compared with the world rebuilt from the event log. Nothing
+/// is written to the game's logs.
+pub fn redrive_command_logs(game_id: Uuid) -> anyhow::Result<()> {
+    let world_parameters = load_world_parameters(&game_id);
+    let commands: Vec<LoggedCommand> =
+        read_verified_log(&stream_folder(&game_id, "command_stream"), None)?;
+
+    let mut simulated = World::generate(&world_parameters);
+    // Rejected commands were logged too but produced no events, the step leaves the world as is.
+    for logged in &commands {
+        let _ = simulated.step(logged.command.clone());
+    }
+
+    let (tx_to_clients, _rx_to_clients) = unbounded();
+    let (_tx_from_client, rx_from_client) = unbounded();
+    let mut recorded = Instance::new(
+        &world_parameters,
+        TickMode::TurnBased,
+        tx_to_clients,
+        rx_from_client,
+    );
+    redrive_event_logs(&mut recorded, game_id)?;
+
+    let mut differences = vec![];
+    collect_differences(
+        "game_state",
+        &serde_json::to_value(&recorded.world.instance_game.game_state)?,
+        &serde_json::to_value(&simulated.instance_game.game_state)?,
+        &mut differences,
+    );
+
+    println!(
+        "Re-simulated game {} from {} commands: event log is at week {}, re-simulation at week {}",
+        game_id,
+        commands.len(),
+        recorded.world.instance_game.game_state.week,
+        simulated.instance_game.game_state.week
+    );
+
+    if differences.is_empty() {
+        println!("No divergence, the re-simulation matches the event log.");
+        return Ok(());
+    }
+
+    for difference in differences.iter().take(MAX_REPORTED_DIFFERENCES) {
+        println!("  {}", difference);
+    }
+    if differences.len() > MAX_REPORTED_DIFFERENCES {
+        println!(
+            "  ... and {} more",
+            differences.len() - MAX_REPORTED_DIFFERENCES
+        );
+    }
+    anyhow::bail!(
+        "Re-simulation diverged from the event log in {} places",
+        differences.len()
+    )
+}
+
+fn collect_differences(path: &str, recorded: &Value, simulated: &Value, out: &mut Vec<String>) {
+    match (recorded, simulated) {
+        (Value::Object(recorded), Value::Object(simulated)) => {
+            let keys: BTreeSet<&String> = recorded.keys().chain(simulated.keys()).collect();
+            for key in keys {
+                let child = format!("{}.{}", path, key);
+                match (recorded.get(key), simulated.get(key)) {
+                    (Some(r), Some(s)) => collect_differences(&child, r, s, out),
+                    (Some(_), None) => out.push(format!("{}: only in event log", child)),
+                    (None, _) => out.push(format!("{}: only in re-simulation", child)),
+                }
+            }
+        }
+        (Value::Array(recorded), Value::Array(simulated)) if recorded.len() == simulated.len() => {
+            for (index, (r, s)) in recorded.iter().zip(simulated).enumerate() {
+                collect_differences(&format!("{}[{}]", path, index), r, s, out);
+            }
+        }
+        _ if recorded != simulated => out.push(format!(
+            "{}: event log {} / re-simulation {}",
+            path, recorded, simulated
+        )),
+        _ => {}
+    }
+}
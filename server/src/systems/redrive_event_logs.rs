@@ -1,40 +1,87 @@
+use crate::Instance;
 use crate::cli::Cli;
-use crate::systems::{LoggedEvent, apply_event};
-use crate::{Instance, find_latest_log_file_in_folder};
+use crate::systems::{
+    LogAnchor, LogIntegrityError, LoggedCommand, LoggedEvent, apply_event, load_latest_snapshot,
+    read_verified_log,
+};
 use clap::Parser;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
 use tracing::info;
 use uuid::Uuid;
 
-pub fn redrive_event_logs(instance: &mut Instance, game_id: Uuid) {
+pub fn stream_folder(game_id: &Uuid, stream_loc: &str) -> PathBuf {
+    PathBuf::from(format!("./_out/games/{}/{}", game_id, stream_loc))
+}
+
+pub fn redrive_event_logs(instance: &mut Instance, game_id: Uuid) -> Result<(), LogIntegrityError> {
     let cli = Cli::parse();
     if !cli.redrive_event_log {
-        return;
+        return Ok(());
     }
+    restore_from_logs(instance, game_id)
+}
 
-    let Some(log_path) =
-        find_latest_log_file_in_folder(format!("./_out/games/{:?}/event_stream", game_id).as_str())
-    else {
-        info!("No valid event log file found.");
-        return;
-    };
-
-    let file = match File::open(&log_path) {
-        Ok(f) => f,
-        Err(e) => {
-            info!("Failed to open event log file: {}", e);
-            return;
+/// Rebuilds the instance from its newest snapshot and the verified event stream. Stops at the
+/// first sign of a truncated, reordered or corrupted log rather than rebuilding a wrong world.
+pub fn restore_from_logs(instance: &mut Instance, game_id: Uuid) -> Result<(), LogIntegrityError> {
+    let anchor = load_latest_snapshot(&game_id).map(|snapshot| {
+        info!(
+            "Restoring snapshot taken at event {} (week {})",
+            snapshot.sequence, snapshot.instance_game.game_state.week
+        );
+        instance.world.instance_game = snapshot.instance_game;
+        instance.event_sequence = snapshot.sequence;
+        instance.event_hash = snapshot.event_hash.clone();
+        LogAnchor {
+            sequence: snapshot.sequence,
+            hash: snapshot.event_hash,
         }
-    };
+    });
 
-    let reader = BufReader::new(file);
-    for line in reader.lines().map_while(Result::ok) {
-        if let Ok(logged) = serde_json::from_str::<LoggedEvent>(&line) {
-            info!("Replaying event: {:?}", logged);
-            apply_event(&logged.event, instance);
+    let events: Vec<LoggedEvent> =
+        read_verified_log(&stream_folder(&game_id, "event_stream"), anchor.as_ref())?;
+
+    let snapshot_sequence = instance.event_sequence;
+    for logged in events {
+        if logged.sequence < snapshot_sequence {
+            continue;
+        }
+        if logged.sequence == snapshot_sequence {
+            // Snapshots written before hashes existed take theirs from the log.
+            instance.event_hash = logged.hash;
+            continue;
         }
+
+        info!("Replaying event {}: {:?}", logged.sequence, logged);
+        apply_event(&logged.event, instance);
+        instance.event_sequence = logged.sequence;
+        instance.event_hash = logged.hash;
+    }
+
+    // The snapshot already exists on disk, replaying must not request another one.
+    instance.needs_snapshot = false;
+
+    info!(
+        "Redrive Event Log Complete, {} events replayed after snapshot.",
+        instance.event_sequence - snapshot_sequence
+    );
+    Ok(())
+}
+
+/// Verifies the command stream and positions the instance at its end, so new commands extend
+/// the existing sequence and hash chain.
+pub fn resume_command_log(instance: &mut Instance, game_id: Uuid) -> Result<(), LogIntegrityError> {
+    let commands: Vec<LoggedCommand> =
+        read_verified_log(&stream_folder(&game_id, "command_stream"), None)?;
+
+    if let Some(last) = commands.last() {
+        instance.command_sequence = last.sequence;
+        instance.command_hash = last.hash.clone();
     }
 
-    info!("Redrive Event Log Complete.");
+    info!(
+        "Command log verified, {} commands recorded.",
+        instance.command_sequence
+    );
+    Ok(())
 }
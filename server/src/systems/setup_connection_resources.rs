@@ -185,11 +185,17 @@ async fn read_from_client_loop(
 ) {
     loop {
         match read_bincode_message::<ClientMessage>(&mut stream).await {
-            Ok(ClientMessage::CreateGame { game_name }) => {
+            Ok(ClientMessage::CreateGame {
+                game_name,
+                world_parameters,
+                tick_mode,
+            }) => {
                 let _ = tx_internal_commands
                     .send(InternalCommand::CreateGame {
                         client_id: uuid,
                         game_name,
+                        world_parameters,
+                        tick_mode,
                     })
                     .await;
             }
@@ -224,6 +230,21 @@ async fn read_from_client_loop(
                     })
                     .await;
             }
+            Ok(ClientMessage::RequestHistory {
+                from_week,
+                to_week,
+                resolution,
+            }) => {
+                let _ = tx_internal_commands
+                    .send(InternalCommand::RequestHistory {
+                        client_id: uuid,
+                        game_id,
+                        from_week,
+                        to_week,
+                        resolution,
+                    })
+                    .await;
+            }
             Ok(ClientMessage::ClientActionCommand {
                 requested_game_id,
                 command,
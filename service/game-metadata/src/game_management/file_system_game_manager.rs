@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use shared::GameMetadata;
+use shared::{GameMetadata, TickMode, WorldGenerationParameters};
 use std::fs;
 use std::path::PathBuf;
 use std::time::SystemTime;
@@ -28,7 +28,12 @@ impl FileSystemGameManager {
 
 #[async_trait]
 impl GameManager for FileSystemGameManager {
-    async fn create_game(&self, game_name: String) -> anyhow::Result<GameMetadata> {
+    async fn create_game(
+        &self,
+        game_name: String,
+        world_parameters: WorldGenerationParameters,
+        tick_mode: TickMode,
+    ) -> anyhow::Result<GameMetadata> {
         let game_id = Uuid::new_v4();
         let game_dir = self.game_path(game_id);
         fs::create_dir_all(&game_dir)?;
@@ -39,6 +44,8 @@ impl GameManager for FileSystemGameManager {
             id: game_id,
             name: game_name,
             created_at: SystemTime::now(),
+            world_parameters,
+            tick_mode,
         };
 
         let metadata_file = self.metadata_path(game_id);
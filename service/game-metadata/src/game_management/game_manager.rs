@@ -1,10 +1,15 @@
 use async_trait::async_trait;
-use shared::GameMetadata;
+use shared::{GameMetadata, TickMode, WorldGenerationParameters};
 use uuid::Uuid;
 
 #[async_trait]
 pub trait GameManager: Send + Sync {
-    async fn create_game(&self, game_name: String) -> anyhow::Result<GameMetadata>;
+    async fn create_game(
+        &self,
+        game_name: String,
+        world_parameters: WorldGenerationParameters,
+        tick_mode: TickMode,
+    ) -> anyhow::Result<GameMetadata>;
     async fn list_games(&self) -> anyhow::Result<Vec<GameMetadata>>;
     async fn delete_game(&self, game_id: Uuid) -> anyhow::Result<()>;
 }
@@ -1,4 +1,4 @@
-use shared::GameMetadata;
+use shared::{GameMetadata, TickMode, WorldGenerationParameters};
 use std::sync::Arc;
 use uuid::Uuid;
 use crate::game_management::GameManager;
@@ -13,12 +13,25 @@ impl GameService {
         Self { manager }
     }
 
-    pub async fn create_game(&self, game_name: String) -> anyhow::Result<GameMetadata> {
+    pub async fn create_game(
+        &self,
+        game_name: String,
+        world_parameters: WorldGenerationParameters,
+        tick_mode: TickMode,
+    ) -> anyhow::Result<GameMetadata> {
         if game_name.trim().is_empty() {
             anyhow::bail!("Game name cannot be empty");
         }
 
-        let metadata = self.manager.create_game(game_name).await?;
+        if let Err(reason) = world_parameters.validate() {
+            anyhow::bail!(reason);
+        }
+
+        if let Err(reason) = tick_mode.validate() {
+            anyhow::bail!(reason);
+        }
+
+        let metadata = self.manager.create_game(game_name, world_parameters, tick_mode).await?;
         Ok(metadata)
     }
 
@@ -3,7 +3,7 @@ use axum::{
     routing::{get, delete},
     Json, Router,
 };
-use shared::GameMetadata;
+use shared::{GameMetadata, TickMode, WorldGenerationParameters};
 use std::sync::Arc;
 use uuid::Uuid;
 use crate::game_management::GameService;
@@ -19,7 +19,7 @@ async fn create_game(
     State(service): State<Arc<GameService>>,
     Json(payload): Json<CreateGameRequest>,
 ) -> Result<Json<GameMetadata>, (axum::http::StatusCode, String)> {
-    service.create_game(payload.name)
+    service.create_game(payload.name, payload.world_parameters, payload.tick_mode)
         .await
         .map(Json)
         .map_err(|e| (axum::http::StatusCode::BAD_REQUEST, e.to_string()))
@@ -46,4 +46,8 @@ async fn delete_game(
 #[derive(serde::Deserialize)]
 pub struct CreateGameRequest {
     pub name: String,
+    #[serde(flatten)]
+    pub world_parameters: WorldGenerationParameters,
+    #[serde(default)]
+    pub tick_mode: TickMode,
 }
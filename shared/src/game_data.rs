@@ -1,4 +1,4 @@
-use crate::HistoryState;
+use crate::{HistoryState, Money, Percent, ReportStore};
 use bevy::prelude::Component;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -8,6 +8,8 @@ use uuid::Uuid;
 pub struct GameInstanceData {
     pub game_state: GameState,
     pub history_state: HistoryState,
+    #[serde(default)]
+    pub report_store: ReportStore,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -17,6 +19,174 @@ pub struct GameState {
     pub companies: HashMap<Uuid, Company>,
     pub organizations: HashMap<Uuid, Organization>,
     pub entities: HashMap<Uuid, Entity>,
+    #[serde(default)]
+    pub former_employees: Vec<FormerEmployee>,
+    #[serde(default)]
+    pub candidates: HashMap<Uuid, Candidate>,
+    /// The world's seed, so later generation such as job seekers stays tied to the game.
+    #[serde(default)]
+    pub seed: u64,
+    #[serde(default)]
+    pub corporate_events: Vec<CorporateEvent>,
+    #[serde(default)]
+    pub board: Board,
+}
+
+/// The board of directors the CEO answers to, on behalf of the shareholders.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Board {
+    pub confidence: Percent,
+    pub warnings: u16,
+    /// What the board told the CEO to fix, if it is waiting on anything.
+    pub demand: Option<BoardDemand>,
+    /// How the game ended, once it has. No command is taken after that.
+    pub outcome: Option<GameOutcome>,
+}
+
+/// Confidence of a board that has only just appointed its CEO.
+pub const STARTING_BOARD_CONFIDENCE: Percent = Percent::new(60);
+
+impl Default for Board {
+    fn default() -> Self {
+        Board {
+            confidence: STARTING_BOARD_CONFIDENCE,
+            warnings: 0,
+            demand: None,
+            outcome: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BoardDemand {
+    pub kind: BoardDemandKind,
+    pub deadline_week: u16,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BoardDemandKind {
+    ReturnToProfit,
+    RestoreReputation,
+    /// Bring what the company owes below `below`.
+    ReduceDebt {
+        below: Money,
+    },
+    /// Take a weekly salary of at most `salary`.
+    CutCeoPay {
+        salary: Money,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum GameOutcome {
+    GameOver {
+        reason: GameOverReason,
+        report: Box<FinalReport>,
+    },
+    Victory {
+        reason: VictoryReason,
+        report: Box<FinalReport>,
+    },
+}
+
+impl GameOutcome {
+    pub fn report(&self) -> &FinalReport {
+        match self {
+            GameOutcome::GameOver { report, .. } | GameOutcome::Victory { report, .. } => report,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum GameOverReason {
+    /// The board lost all confidence in the CEO.
+    LostConfidence,
+    /// The CEO ignored one warning too many.
+    TooManyWarnings,
+    Bankrupt,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum VictoryReason {
+    /// The company grew to be worth the board's target.
+    ValuationTarget,
+    /// The CEO served out their term with the board behind them.
+    FullTerm,
+}
+
+/// Where the CEO and their company stood when the game ended.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct FinalReport {
+    pub week: u16,
+    pub company_name: String,
+    pub company_cash: Money,
+    pub valuation: Money,
+    pub debt: Money,
+    pub ceo_wealth: Money,
+    pub equity_percent: Percent,
+    pub board_confidence: Percent,
+    pub warnings: u16,
+    pub employees: u32,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CorporateEventKind {
+    DataBreach,
+    WarehouseAccident,
+    ViralMarketing,
+    Whistleblower,
+    SurpriseAudit,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CorporateEventChoice {
+    Disclose,
+    CoverUp,
+    CompensateAndFix,
+    MinimalResponse,
+    Investigate,
+    Dismiss,
+}
+
+/// Something that happened to one of the company's organizations out of the blue. Events with
+/// choices wait for the player to pick one; left alone until the end of the next week, the
+/// last choice is taken for them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CorporateEvent {
+    pub id: Uuid,
+    pub kind: CorporateEventKind,
+    pub organization_id: Uuid,
+    pub week: u16,
+    pub choices: Vec<CorporateEventChoice>,
+    pub resolution: Option<CorporateEventChoice>,
+}
+
+impl GameState {
+    /// The company the operator runs, every other company is a rival.
+    pub fn player_company_id(&self) -> Option<Uuid> {
+        self.companies
+            .values()
+            .filter(|company| company.rival.is_none())
+            .map(|company| company.id)
+            .min()
+    }
+}
+
+impl CorporateEvent {
+    pub fn is_pending(&self) -> bool {
+        !self.choices.is_empty() && self.resolution.is_none()
+    }
+}
+
+/// A job seeker on the labor market. The person is in `entities`, without employment, for as
+/// long as they are listed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Candidate {
+    pub entity_id: Uuid,
+    pub level: u16,
+    pub asking_salary: Money,
+    pub preferred_roles: Vec<OrganizationRole>,
+    pub listed_until_week: u16,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -25,9 +195,28 @@ pub struct Employment {
     pub role: OrganizationRole,
     pub employee_flags: Vec<EmployeeFlag>,
     pub level: u16,
-    pub salary: u16,
-    pub satisfaction: u16,
-    pub productivity: u16,
+    pub salary: Money,
+    pub satisfaction: Percent,
+    pub productivity: Percent,
+    #[serde(default)]
+    pub weeks_employed: u16,
+    /// Consecutive weeks spent in an understaffed organization.
+    #[serde(default)]
+    pub weeks_overworked: u16,
+    #[serde(default)]
+    pub raises: u16,
+}
+
+/// Someone who left the company, kept so their departure stays visible after `employment`
+/// is cleared.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FormerEmployee {
+    pub entity_id: Uuid,
+    pub organization_id: Uuid,
+    pub role: OrganizationRole,
+    pub status: EmploymentStatus,
+    pub reason: DepartureReason,
+    pub week: u16,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -47,10 +236,10 @@ pub struct Origin {
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct Financials {
-    pub actual_cash: i32,
-    pub this_weeks_income: i32,
-    pub this_weeks_expenses: i32,
-    pub this_weeks_net_profit: i32,
+    pub actual_cash: Money,
+    pub this_weeks_income: Money,
+    pub this_weeks_expenses: Money,
+    pub this_weeks_net_profit: Money,
 }
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
@@ -61,9 +250,9 @@ pub struct Perception {
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Budget {
-    pub marketing: u16,
-    pub rnd: u16,
-    pub training: u16,
+    pub marketing: Money,
+    pub rnd: Money,
+    pub training: Money,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -73,13 +262,146 @@ pub struct Company {
     pub company_type: CompanyType,
     pub perception: Perception,
     pub financials: Financials,
+    /// How the company is run when it is not the operator's.
+    #[serde(default)]
+    pub rival: Option<RivalStrategy>,
+    #[serde(default)]
+    pub orders: OrderBook,
+    #[serde(default)]
+    pub debt: Debt,
+}
+
+/// Weeks of its current profit a buyer pays for a company, on top of its cash.
+pub const VALUATION_WEEKS: i64 = 52;
+
+impl Company {
+    /// What the company would sell for: its cash plus a year of this week's profit, less what
+    /// it owes, never less than nothing.
+    pub fn valuation(&self) -> Money {
+        (self.financials.actual_cash + self.financials.this_weeks_net_profit * VALUATION_WEEKS
+            - self.debt.outstanding())
+        .max(Money::ZERO)
+    }
+}
+
+/// What a company owes and how it is coping, managed through its Finance organization.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct Debt {
+    pub loans: Vec<Loan>,
+    /// Drawn from the revolving credit line and not yet paid back.
+    pub credit_drawn: Money,
+    /// Every loan the company has ever taken, to tell new ones apart.
+    pub loans_taken: u32,
+    /// Consecutive weeks the company has gone into a week with negative cash.
+    pub weeks_insolvent: u16,
+    /// Lenders force a restructuring only once, the next insolvency is the end.
+    pub restructured: bool,
+    pub bankrupt: bool,
+}
+
+impl Debt {
+    pub fn outstanding(&self) -> Money {
+        self.loans.iter().map(|loan| loan.balance).sum::<Money>() + self.credit_drawn
+    }
 }
 
+/// Longest a term loan can run.
+pub const MAX_LOAN_WEEKS: u16 = 104;
+
+/// A term loan, paid back in equal weekly installments of its principal plus interest on
+/// what is left.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Loan {
+    pub id: Uuid,
+    pub principal: Money,
+    pub balance: Money,
+    /// Yearly interest before any covenant penalty.
+    pub annual_rate: Percent,
+    pub weeks: u16,
+}
+
+impl Loan {
+    /// The principal due this week.
+    pub fn installment(&self) -> Money {
+        Money::new(self.principal.get() / self.weeks.max(1) as i64)
+            .max(Money::new(1))
+            .min(self.balance)
+    }
+
+    pub fn weeks_remaining(&self) -> i64 {
+        let installment = self.installment().get();
+        (self.balance.get() + installment - 1)
+            .checked_div(installment)
+            .unwrap_or(0)
+    }
+}
+
+/// The steps an order goes through once a customer places it, each handled by different
+/// kinds of organization.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FulfilmentStage {
+    Picking,
+    Shipping,
+    Support,
+}
+
+/// A company's orders this week.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct OrderBook {
+    pub placed: u32,
+    pub fulfilled: u32,
+    /// The stage that turned orders away, if any did.
+    pub bottleneck: Option<FulfilmentStage>,
+}
+
+/// What a rival company's management goes for each week.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RivalStrategy {
+    /// Spends on marketing and publicity.
+    Marketer,
+    /// Hires from the labor market to grow its organizations.
+    Builder,
+    /// Keeps its people happy with raises.
+    Caretaker,
+}
+
+pub const RIVAL_STRATEGIES: [RivalStrategy; 3] = [
+    RivalStrategy::Marketer,
+    RivalStrategy::Builder,
+    RivalStrategy::Caretaker,
+];
+
+/// The operator as CEO. `financials` is their personal account, the company's money is in
+/// its own `Company::financials`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Player {
     pub id: Option<Uuid>,
     pub financials: Financials,
     pub perception: Perception,
+    #[serde(default)]
+    pub compensation: CeoCompensation,
+}
+
+/// Highest bonus the board lets a CEO take, in percent of the company's weekly profit.
+pub const MAX_CEO_BONUS_PERCENT: u16 = 25;
+
+/// What the CEO is paid by the company: a weekly salary, a bonus share of each week's profit
+/// after that salary, and the part of the company they own.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CeoCompensation {
+    pub salary: Money,
+    pub bonus_percent: Percent,
+    pub equity_percent: Percent,
+}
+
+impl Default for CeoCompensation {
+    fn default() -> Self {
+        CeoCompensation {
+            salary: Money::new(2_000),
+            bonus_percent: Percent::new(5),
+            equity_percent: Percent::new(10),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -139,11 +461,12 @@ pub enum EntityFlag {
     Hoarder,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum EmployeeFlag {
     WantsRaise,
     BurnedOut,
     Loyal,
+    PassedOver,
 }
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -178,6 +501,78 @@ pub enum OrganizationRole {
     RnDEngineer,
 }
 
+/// Highest level anyone can reach.
+pub const MAX_LEVEL: u16 = 20;
+
+/// The weekly salary range a role pays at a given level. `midpoint` is what an employee in that
+/// position expects to earn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayBand {
+    pub min: Money,
+    pub midpoint: Money,
+    pub max: Money,
+}
+
+impl OrganizationRole {
+    /// Seniority tier of the role, 0 for the VP down to 3 for individual contributors.
+    pub fn rank(&self) -> u32 {
+        match self {
+            OrganizationRole::VP => 0,
+            OrganizationRole::CFO | OrganizationRole::COO => 1,
+            OrganizationRole::HRManager
+            | OrganizationRole::LegalCounsel
+            | OrganizationRole::Accountant
+            | OrganizationRole::ProductManager => 2,
+            OrganizationRole::SoftwareEngineer
+            | OrganizationRole::DataScientist
+            | OrganizationRole::DevOpsEngineer
+            | OrganizationRole::UXDesigner
+            | OrganizationRole::MarketingSpecialist
+            | OrganizationRole::ContentCreator
+            | OrganizationRole::SalesRep
+            | OrganizationRole::WarehouseManager
+            | OrganizationRole::LogisticsCoordinator
+            | OrganizationRole::CustomerSupport
+            | OrganizationRole::ResearchScientist
+            | OrganizationRole::RnDEngineer => 3,
+        }
+    }
+
+    /// Pay for the role at a level. Every level is worth around 1.6 a week, each tier above
+    /// individual contributors adds a tenth on top, and the band spans 15% either side.
+    pub fn pay_band(&self, level: u16) -> PayBand {
+        let tier_percent = 100 + (3 - self.rank().min(3) as i64) * 10;
+        let midpoint = Money::new((level as i64 * 8 / 5 * tier_percent / 100).max(1));
+        PayBand {
+            min: midpoint.percent(85).max(Money::new(1)),
+            midpoint,
+            max: midpoint.percent(115),
+        }
+    }
+}
+
+/// Every role someone can be hired into. VPs are only ever promoted from within.
+pub const HIREABLE_ROLES: &[OrganizationRole] = &[
+    OrganizationRole::CFO,
+    OrganizationRole::COO,
+    OrganizationRole::HRManager,
+    OrganizationRole::LegalCounsel,
+    OrganizationRole::Accountant,
+    OrganizationRole::SoftwareEngineer,
+    OrganizationRole::DataScientist,
+    OrganizationRole::ProductManager,
+    OrganizationRole::DevOpsEngineer,
+    OrganizationRole::UXDesigner,
+    OrganizationRole::WarehouseManager,
+    OrganizationRole::LogisticsCoordinator,
+    OrganizationRole::CustomerSupport,
+    OrganizationRole::MarketingSpecialist,
+    OrganizationRole::ContentCreator,
+    OrganizationRole::SalesRep,
+    OrganizationRole::ResearchScientist,
+    OrganizationRole::RnDEngineer,
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum EmploymentStatus {
     Active,
@@ -185,11 +580,41 @@ pub enum EmploymentStatus {
     Quit,
 }
 
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DepartureReason {
+    Dismissed,
+    Underpaid,
+    Overworked,
+    PoorLeadership,
+    BadReputation,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum InitiativeKind {
+    Marketing,
+    Training,
+    RnD,
+}
+
+pub const INITIATIVE_KINDS: &[InitiativeKind] = &[
+    InitiativeKind::Marketing,
+    InitiativeKind::Training,
+    InitiativeKind::RnD,
+];
+
+/// Longest an initiative can be planned for.
+pub const MAX_INITIATIVE_WEEKS: u16 = 26;
+
+/// A time-boxed push an organization runs alongside its regular budget. An organization runs at
+/// most one initiative of each kind. The upfront cost is paid at launch, the weekly cost at the
+/// end of every week it runs.
 #[derive(Component, Clone, Debug, Serialize, Deserialize)]
-pub enum Initiative {
-    Marketing { weeks_remaining: u16 },
-    Training { weeks_remaining: u16 },
-    RnD { weeks_remaining: u16 },
+pub struct Initiative {
+    pub kind: InitiativeKind,
+    pub weeks: u16,
+    pub weeks_remaining: u16,
+    pub upfront_cost: Money,
+    pub weekly_cost: Money,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
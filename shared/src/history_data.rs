@@ -1,10 +1,18 @@
-use crate::{Financials, Perception};
+use crate::{Financials, Percent, Perception, QUARTER_WEEKS, YEAR_WEEKS};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
+use std::fmt;
 use uuid::Uuid;
 
+/// Weeks of history kept at full resolution.
 pub const MAX_HISTORY_POINTS: usize = 50;
 
+/// Months kept once they have left the weekly history, before they are rolled into quarters.
+/// Quarters are kept for the whole game.
+pub const MAX_MONTHLY_POINTS: usize = 24;
+
+const MONTHS_PER_YEAR: u16 = 12;
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct HistoryState {
     pub players: HashMap<Uuid, PlayerHistory>,
@@ -12,25 +20,357 @@ pub struct HistoryState {
     pub companies: HashMap<Uuid, CompanyHistory>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct OrganizationHistory {
-    pub recent_history: VecDeque<HistoryPoint>,
+impl HistoryState {
+    /// Only the weeks kept at full resolution, what clients are sent as the game goes on.
+    pub fn recent(&self) -> Self {
+        self.map(|history| EntityHistory {
+            recent_history: history.recent_history.clone(),
+            ..Default::default()
+        })
+    }
+
+    /// The points from `from_week` to `to_week`, at `resolution` where the history still has
+    /// it and at the finest resolution kept for older weeks. Rolled-up points fall in the range
+    /// when their period starts in it. Every point comes in `recent_history`.
+    pub fn range(&self, from_week: u16, to_week: u16, resolution: HistoryResolution) -> Self {
+        self.map(|history| history.range(from_week, to_week, resolution))
+    }
+
+    fn map(&self, f: impl Fn(&EntityHistory) -> EntityHistory) -> Self {
+        let map = |histories: &HashMap<Uuid, EntityHistory>| {
+            histories
+                .iter()
+                .map(|(id, history)| (*id, f(history)))
+                .collect()
+        };
+
+        HistoryState {
+            players: map(&self.players),
+            organizations: map(&self.organizations),
+            companies: map(&self.companies),
+        }
+    }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct CompanyHistory {
+pub type OrganizationHistory = EntityHistory;
+pub type CompanyHistory = EntityHistory;
+pub type PlayerHistory = EntityHistory;
+
+/// An entity's history in tiers: the recent weeks as they were recorded, then whole months
+/// and, furthest back, whole quarters. Each tier only holds what has left the one before it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct EntityHistory {
     pub recent_history: VecDeque<HistoryPoint>,
+    #[serde(default)]
+    pub monthly_history: VecDeque<HistoryPoint>,
+    #[serde(default)]
+    pub quarterly_history: Vec<HistoryPoint>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct PlayerHistory {
-    pub recent_history: VecDeque<HistoryPoint>,
+impl EntityHistory {
+    /// Records a week, rolling whole months out of the weekly history once it is full, and
+    /// whole quarters out of the monthly one.
+    pub fn push(&mut self, history_point: HistoryPoint) {
+        self.recent_history.push_back(history_point);
+
+        while self.recent_history.len() > MAX_HISTORY_POINTS {
+            let month = drain_period(&mut self.recent_history, month_of);
+            self.monthly_history.push_back(month);
+        }
+        while self.monthly_history.len() > MAX_MONTHLY_POINTS {
+            let quarter = drain_period(&mut self.monthly_history, quarter_of);
+            self.quarterly_history.push(quarter);
+        }
+    }
+
+    fn range(&self, from_week: u16, to_week: u16, resolution: HistoryResolution) -> Self {
+        let in_range = |point: &&HistoryPoint| (from_week..=to_week).contains(&point.week);
+        let quarters = self.quarterly_history.iter().filter(in_range);
+        let months = self.monthly_history.iter().filter(in_range);
+        let weeks = self.recent_history.iter().filter(in_range);
+
+        let recent_history = match resolution {
+            HistoryResolution::Weekly => quarters.chain(months).chain(weeks).cloned().collect(),
+            HistoryResolution::Monthly => quarters
+                .cloned()
+                .chain(roll_up(months.chain(weeks), month_of))
+                .collect(),
+            HistoryResolution::Quarterly => {
+                roll_up(quarters.chain(months).chain(weeks), quarter_of).into()
+            }
+        };
+
+        EntityHistory {
+            recent_history,
+            ..Default::default()
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HistoryPoint {
+    /// The week recorded, or the first week of a rolled-up period.
     pub week: u16,
+    /// Of a rolled-up period: the week's figures are totals over it and the cash is what it
+    /// ended with.
     pub financials: Financials,
+    /// As it stood at the end of a rolled-up period.
     pub perception: Perception,
-    pub avg_employee_satisfaction: u16,
+    /// Averaged over a rolled-up period.
+    pub avg_employee_satisfaction: Percent,
+}
+
+impl HistoryPoint {
+    /// One point standing for several consecutive ones.
+    fn rolled_up(points: &[&HistoryPoint]) -> Option<HistoryPoint> {
+        let (first, last) = (points.first()?, points.last()?);
+        let satisfaction: u32 = points
+            .iter()
+            .map(|point| point.avg_employee_satisfaction.get() as u32)
+            .sum();
+
+        Some(HistoryPoint {
+            week: first.week,
+            financials: Financials {
+                actual_cash: last.financials.actual_cash,
+                this_weeks_income: points.iter().map(|p| p.financials.this_weeks_income).sum(),
+                this_weeks_expenses: points
+                    .iter()
+                    .map(|p| p.financials.this_weeks_expenses)
+                    .sum(),
+                this_weeks_net_profit: points
+                    .iter()
+                    .map(|p| p.financials.this_weeks_net_profit)
+                    .sum(),
+            },
+            perception: last.perception.clone(),
+            avg_employee_satisfaction: Percent::new((satisfaction / points.len() as u32) as u16),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HistoryResolution {
+    #[default]
+    Weekly,
+    Monthly,
+    Quarterly,
+}
+
+impl HistoryResolution {
+    pub fn next(self) -> Self {
+        match self {
+            HistoryResolution::Weekly => HistoryResolution::Monthly,
+            HistoryResolution::Monthly => HistoryResolution::Quarterly,
+            HistoryResolution::Quarterly => HistoryResolution::Weekly,
+        }
+    }
+}
+
+impl fmt::Display for HistoryResolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryResolution::Weekly => write!(f, "weekly"),
+            HistoryResolution::Monthly => write!(f, "monthly"),
+            HistoryResolution::Quarterly => write!(f, "quarterly"),
+        }
+    }
+}
+
+/// Points of a history sent on request, see `HistoryState::range`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct HistoryRange {
+    pub from_week: u16,
+    pub to_week: u16,
+    pub resolution: HistoryResolution,
+    pub history: HistoryState,
+}
+
+/// Twelve months to the year, of four or five weeks, so three make up a quarter of the
+/// reports exactly.
+fn month_of(week: u16) -> u16 {
+    week / YEAR_WEEKS * MONTHS_PER_YEAR + week % YEAR_WEEKS * MONTHS_PER_YEAR / YEAR_WEEKS
+}
+
+fn quarter_of(week: u16) -> u16 {
+    week / QUARTER_WEEKS
+}
+
+/// Takes the points of the oldest period off the front and rolls them into one.
+fn drain_period(points: &mut VecDeque<HistoryPoint>, period_of: fn(u16) -> u16) -> HistoryPoint {
+    let period = points.front().map(|point| period_of(point.week));
+    let count = points
+        .iter()
+        .take_while(|point| Some(period_of(point.week)) == period)
+        .count();
+    let drained: Vec<HistoryPoint> = points.drain(..count).collect();
+    HistoryPoint::rolled_up(&drained.iter().collect::<Vec<_>>())
+        .expect("a period has at least its first point")
+}
+
+/// Consecutive points rolled into one per period.
+fn roll_up<'a>(
+    points: impl Iterator<Item = &'a HistoryPoint>,
+    period_of: fn(u16) -> u16,
+) -> Vec<HistoryPoint> {
+    let points: Vec<&HistoryPoint> = points.collect();
+    points
+        .chunk_by(|a, b| period_of(a.week) == period_of(b.week))
+        .filter_map(HistoryPoint::rolled_up)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Money;
+
+    const WEEKLY_INCOME: i64 = 100;
+
+    fn point(week: u16) -> HistoryPoint {
+        HistoryPoint {
+            week,
+            financials: Financials {
+                actual_cash: Money::new(week as i64),
+                this_weeks_income: Money::new(WEEKLY_INCOME),
+                ..Default::default()
+            },
+            perception: Perception::default(),
+            avg_employee_satisfaction: Percent::new(50),
+        }
+    }
+
+    fn history(weeks: u16) -> EntityHistory {
+        let mut history = EntityHistory::default();
+        (0..weeks).for_each(|week| history.push(point(week)));
+        history
+    }
+
+    fn weeks_of<'a>(points: impl IntoIterator<Item = &'a HistoryPoint>) -> Vec<u16> {
+        points.into_iter().map(|point| point.week).collect()
+    }
+
+    fn income_of<'a>(points: impl IntoIterator<Item = &'a HistoryPoint>) -> Money {
+        points
+            .into_iter()
+            .map(|point| point.financials.this_weeks_income)
+            .sum()
+    }
+
+    #[test]
+    fn months_nest_in_quarters_across_years() {
+        for week in 0..10 * YEAR_WEEKS {
+            assert_eq!(month_of(week) / 3, quarter_of(week), "week {}", week);
+        }
+        assert_eq!(month_of(YEAR_WEEKS - 1), MONTHS_PER_YEAR - 1);
+        assert_eq!(month_of(YEAR_WEEKS), MONTHS_PER_YEAR);
+    }
+
+    #[test]
+    fn a_full_weekly_history_keeps_every_week() {
+        let history = history(MAX_HISTORY_POINTS as u16);
+
+        assert_eq!(history.recent_history.len(), MAX_HISTORY_POINTS);
+        assert!(history.monthly_history.is_empty());
+    }
+
+    #[test]
+    fn one_week_too_many_rolls_out_the_oldest_month() {
+        let history = history(MAX_HISTORY_POINTS as u16 + 1);
+
+        // The first month is weeks 0 to 4.
+        let month = &history.monthly_history[0];
+        assert_eq!(history.monthly_history.len(), 1);
+        assert_eq!(month.week, 0);
+        assert_eq!(
+            month.financials.this_weeks_income,
+            Money::new(5 * WEEKLY_INCOME)
+        );
+        assert_eq!(month.financials.actual_cash, Money::new(4));
+        assert_eq!(month.avg_employee_satisfaction, Percent::new(50));
+        assert_eq!(history.recent_history[0].week, 5);
+    }
+
+    #[test]
+    fn months_roll_into_quarters_without_losing_a_week() {
+        let weeks = 4 * YEAR_WEEKS;
+        let history = history(weeks);
+
+        assert!(!history.quarterly_history.is_empty());
+        assert!(history.monthly_history.len() <= MAX_MONTHLY_POINTS);
+        assert!(history.recent_history.len() <= MAX_HISTORY_POINTS);
+        assert!(
+            history
+                .quarterly_history
+                .iter()
+                .all(|quarter| quarter.week % QUARTER_WEEKS == 0)
+        );
+
+        // The tiers follow on from each other and together hold every week.
+        let tiers = history
+            .quarterly_history
+            .iter()
+            .chain(&history.monthly_history)
+            .chain(&history.recent_history);
+        assert!(weeks_of(tiers.clone()).is_sorted());
+        assert_eq!(income_of(tiers), Money::new(weeks as i64 * WEEKLY_INCOME));
+    }
+
+    #[test]
+    fn a_weekly_range_over_every_tier_takes_the_finest_points_kept() {
+        let weeks = 4 * YEAR_WEEKS;
+        let history = history(weeks);
+
+        let range = history.range(0, weeks, HistoryResolution::Weekly);
+        let kept = history.quarterly_history.len()
+            + history.monthly_history.len()
+            + history.recent_history.len();
+        assert_eq!(range.recent_history.len(), kept);
+        assert_eq!(
+            income_of(&range.recent_history),
+            Money::new(weeks as i64 * WEEKLY_INCOME)
+        );
+    }
+
+    #[test]
+    fn a_coarser_range_rolls_up_the_finer_tiers() {
+        let weeks = 4 * YEAR_WEEKS;
+        let history = history(weeks);
+
+        let quarters = history.range(0, weeks, HistoryResolution::Quarterly);
+        let expected: Vec<u16> = (0..weeks / QUARTER_WEEKS)
+            .map(|quarter| quarter * QUARTER_WEEKS)
+            .collect();
+        assert_eq!(weeks_of(&quarters.recent_history), expected);
+        assert!(quarters.recent_history.iter().all(
+            |quarter| income_of([quarter]) == Money::new(QUARTER_WEEKS as i64 * WEEKLY_INCOME)
+        ));
+
+        let months = history.range(0, weeks, HistoryResolution::Monthly);
+        let month_starts = weeks_of(&months.recent_history);
+        assert!(month_starts.is_sorted());
+        assert!(month_starts.len() < weeks as usize);
+        assert_eq!(
+            income_of(&months.recent_history),
+            Money::new(weeks as i64 * WEEKLY_INCOME)
+        );
+    }
+
+    #[test]
+    fn a_range_takes_the_periods_starting_in_it() {
+        let history = history(4 * YEAR_WEEKS);
+        let (from_week, to_week) = (YEAR_WEEKS + 1, 3 * YEAR_WEEKS);
+
+        let range = history.range(from_week, to_week, HistoryResolution::Weekly);
+
+        let weeks = weeks_of(&range.recent_history);
+        assert!(
+            weeks
+                .iter()
+                .all(|week| (from_week..=to_week).contains(week))
+        );
+        // The quarter starting at week 52 is left out, the one starting at week 65 is not.
+        assert_eq!(weeks.first(), Some(&(YEAR_WEEKS + QUARTER_WEEKS)));
+        assert_eq!(weeks.last(), Some(&to_week));
+    }
 }
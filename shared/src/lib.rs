@@ -1,12 +1,16 @@
 mod client_args;
 pub mod game_data;
 pub mod history_data;
+mod numeric;
+pub mod report_data;
 pub mod resources;
 
 use bevy::utils::HashMap;
 pub use client_args::*;
 pub use game_data::*;
 pub use history_data::*;
+pub use numeric::*;
+pub use report_data::*;
 pub use resources::*;
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
@@ -28,6 +32,9 @@ pub enum ClientMessage {
 
     CreateGame {
         game_name: String,
+        world_parameters: WorldGenerationParameters,
+        #[serde(default)]
+        tick_mode: TickMode,
     },
     ListGames,
     DeleteGame {
@@ -38,6 +45,13 @@ pub enum ClientMessage {
         requested_game_id: Uuid,
         command: ClientActionCommand,
     },
+    /// Asks for the connected game's history over a range of weeks, answered with a
+    /// `ServerEvent::HistoryRange`.
+    RequestHistory {
+        from_week: u16,
+        to_week: u16,
+        resolution: HistoryResolution,
+    },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -48,17 +62,50 @@ pub enum InternalEvent {
     AddEmployedStatus {
         employee_id: Uuid,
         organization_id: Uuid,
+        role: OrganizationRole,
+        level: u16,
+        salary: Money,
+    },
+    ListCandidate {
+        entity: Entity,
+        candidate: Candidate,
+    },
+    DelistCandidate {
+        entity_id: Uuid,
     },
     DecrementReputation {
         amount: i16,
     },
+    /// Takes money from the operator's company.
     DecrementMoney {
-        amount: i32,
+        amount: Money,
     },
     IncrementEmployeeSatisfaction {
         employee_id: Uuid,
         amount: u16,
     },
+    DecrementEmployeeSatisfaction {
+        employee_id: Uuid,
+        amount: u16,
+    },
+    RecordEmploymentWeek {
+        employee_id: Uuid,
+        overworked: bool,
+    },
+    SetEmployeeFlag {
+        employee_id: Uuid,
+        flag: EmployeeFlag,
+    },
+    ClearEmployeeFlag {
+        employee_id: Uuid,
+        flag: EmployeeFlag,
+    },
+    EmployeeDeparted {
+        employee_id: Uuid,
+        organization_id: Uuid,
+        status: EmploymentStatus,
+        reason: DepartureReason,
+    },
     IncrementOrgPublicOpinion {
         organization_id: Uuid,
         amount: i16,
@@ -69,13 +116,14 @@ pub enum InternalEvent {
     },
     IncrementSalary {
         employee_id: Uuid,
-        amount: u16,
+        amount: Money,
     },
     IncrementReputation {
         amount: i16,
     },
+    /// Gives money to the operator's company.
     IncrementMoney {
-        amount: i32,
+        amount: Money,
     },
     SetOrgVp {
         organization_id: Uuid,
@@ -85,10 +133,33 @@ pub enum InternalEvent {
         organization_id: Uuid,
         financials: Financials,
     },
+    RecordCorporateEvent {
+        corporate_event: CorporateEvent,
+    },
+    ResolveCorporateEvent {
+        corporate_event_id: Uuid,
+        choice: CorporateEventChoice,
+    },
+    /// Replaces an organization's initiatives wholesale. No longer emitted, kept so logs from
+    /// before the per-initiative events below still replay.
     SetOrgInitiatives {
         organization_id: Uuid,
         initiatives: Vec<Initiative>,
     },
+    /// Starts an initiative, unless one of its kind is already running.
+    AddOrgInitiative {
+        organization_id: Uuid,
+        initiative: Initiative,
+    },
+    RemoveOrgInitiative {
+        organization_id: Uuid,
+        kind: InitiativeKind,
+    },
+    /// A week passes for the initiatives of these kinds, the ones that run out end.
+    TickOrgInitiatives {
+        organization_id: Uuid,
+        kinds: Vec<InitiativeKind>,
+    },
     SetOrgPublicOpinion {
         organization_id: Uuid,
         perception: Perception,
@@ -97,6 +168,14 @@ pub enum InternalEvent {
         organization_id: Uuid,
         budget: Budget,
     },
+    SetEmployeeLevel {
+        employee_id: Uuid,
+        level: u16,
+    },
+    SetEmployeeOrganization {
+        employee_id: Uuid,
+        organization_id: Uuid,
+    },
     SetOrganizationRole {
         employee_id: Uuid,
         new_role: OrganizationRole,
@@ -105,6 +184,82 @@ pub enum InternalEvent {
         company_id: Uuid,
         financials: Financials,
     },
+    AdjustCompanyCash {
+        company_id: Uuid,
+        amount: Money,
+    },
+    SetCompanyOrders {
+        company_id: Uuid,
+        orders: OrderBook,
+    },
+    AdjustCompanyReputation {
+        company_id: Uuid,
+        amount: i16,
+    },
+    /// The week's pay moving from a company's account to its CEO's.
+    PayCeo {
+        company_id: Uuid,
+        salary: Money,
+        bonus: Money,
+    },
+    SetCeoCompensation {
+        salary: Money,
+        bonus_percent: Percent,
+    },
+    /// The CEO selling `percent` of the company out of their stake.
+    SellCeoEquity {
+        percent: Percent,
+        proceeds: Money,
+    },
+    /// Adds the loan to the company's debt and its principal to the company's cash.
+    TakeLoan {
+        company_id: Uuid,
+        loan: Loan,
+    },
+    /// Pays `amount` off a loan, which is settled once nothing is left on it.
+    RepayLoan {
+        company_id: Uuid,
+        loan_id: Uuid,
+        amount: Money,
+    },
+    DrawCredit {
+        company_id: Uuid,
+        amount: Money,
+    },
+    RepayCredit {
+        company_id: Uuid,
+        amount: Money,
+    },
+    PayInterest {
+        company_id: Uuid,
+        amount: Money,
+    },
+    SetCompanyInsolvency {
+        company_id: Uuid,
+        weeks_insolvent: u16,
+    },
+    /// Lenders replacing all of a company's debt with a single loan.
+    RestructureDebt {
+        company_id: Uuid,
+        loan: Loan,
+    },
+    DeclareBankruptcy {
+        company_id: Uuid,
+    },
+    SetBoardConfidence {
+        confidence: Percent,
+    },
+    /// A warning from the board, usually with something to fix by a deadline.
+    IssueBoardWarning {
+        demand: Option<BoardDemand>,
+    },
+    CloseBoardDemand,
+    EndGame {
+        outcome: GameOutcome,
+    },
+    FileReport {
+        report: FinancialReport,
+    },
     AppendHistoryPoint {
         new_player_history_points: HashMap<Uuid, HistoryPoint>,
         new_organization_history_points: HashMap<Uuid, HistoryPoint>,
@@ -119,17 +274,64 @@ pub enum ServerEvent {
 
     Hello(HelloState),
 
-    GameCreated { game_id: Uuid, game_name: String },
-    GameCreationFailed { game_name: String, reason: String },
+    GameCreated {
+        game_id: Uuid,
+        game_name: String,
+    },
+    GameCreationFailed {
+        game_name: String,
+        reason: String,
+    },
 
-    ListGames { games: Vec<AvailableGame> },
-    ListGamesFailed { reason: String },
+    ListGames {
+        games: Vec<AvailableGame>,
+    },
+    ListGamesFailed {
+        reason: String,
+    },
 
-    GameDeleted { game_id: Uuid },
-    GameDeletionFailed { game_id: Uuid, reason: String },
+    GameDeleted {
+        game_id: Uuid,
+    },
+    GameDeletionFailed {
+        game_id: Uuid,
+        reason: String,
+    },
+
+    GameLoadFailed {
+        game_id: Uuid,
+        reason: String,
+    },
+
+    CommandRejected {
+        reason: String,
+    },
+
+    /// A corporate event was drawn this week. Any choices it offers are resolved with
+    /// `ClientActionCommand::ResolveCorporateEvent`.
+    CorporateEventDrawn {
+        corporate_event: CorporateEvent,
+    },
+
+    /// The game was lost. The instance takes no more commands.
+    GameOver {
+        reason: GameOverReason,
+        report: Box<FinalReport>,
+    },
+    /// The game was won. The instance takes no more commands.
+    Victory {
+        reason: VictoryReason,
+        report: Box<FinalReport>,
+    },
 
-    FullState(GameState),
+    FullState {
+        game_state: GameState,
+        clock: ClockState,
+    },
+    /// The recent weeks of history, see `HistoryState::recent`.
     HistoryState(HistoryState),
+    HistoryRange(HistoryRange),
+    ReportStore(ReportStore),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -146,21 +348,90 @@ pub enum ClientActionCommand {
     HireEmployee {
         employee_id: Uuid,
         organization_id: Uuid,
+        role: OrganizationRole,
+        level: u16,
+        salary_offer: Money,
+        #[serde(default)]
+        signing_bonus: Option<Money>,
     },
     GiveRaise {
         employee_id: Uuid,
-        amount: u16,
+        amount: Money,
     },
     LaunchPRCampaign,
+    /// Starts an initiative in an organization, spending `budget` over its `weeks`.
+    LaunchInitiative {
+        organization_id: Uuid,
+        kind: InitiativeKind,
+        weeks: u16,
+        budget: Money,
+    },
+    CancelInitiative {
+        organization_id: Uuid,
+        kind: InitiativeKind,
+    },
+    ResolveCorporateEvent {
+        corporate_event_id: Uuid,
+        choice: CorporateEventChoice,
+    },
     DoNothing,
+    EndTurn,
+    SetClockSpeed {
+        speed: ClockSpeed,
+    },
     PromoteToVp {
         organization_id: Uuid,
         employee_id: Uuid,
     },
+    /// Moves an employee up the ladder. `role` of `None` keeps their current role.
+    PromoteEmployee {
+        employee_id: Uuid,
+        role: Option<OrganizationRole>,
+        level: u16,
+    },
+    /// Moves an employee down the ladder. `role` of `None` keeps their current role, or for a
+    /// VP, steps them down into a role that fits their organization.
+    DemoteEmployee {
+        employee_id: Uuid,
+        role: Option<OrganizationRole>,
+        level: u16,
+    },
+    TransferEmployee {
+        employee_id: Uuid,
+        organization_id: Uuid,
+    },
     UpdateBudget {
         organization_id: Uuid,
         organization_budget: Budget,
     },
+    /// Sets the CEO's own pay. The bonus is capped at `MAX_CEO_BONUS_PERCENT`.
+    SetCeoCompensation {
+        salary: Money,
+        bonus_percent: Percent,
+    },
+    /// Sells part of the CEO's stake at the company's current valuation.
+    SellEquity {
+        percent: Percent,
+    },
+    /// Borrows `amount` over `weeks` through a Finance organization.
+    TakeLoan {
+        organization_id: Uuid,
+        amount: Money,
+        weeks: u16,
+    },
+    RepayLoan {
+        organization_id: Uuid,
+        loan_id: Uuid,
+        amount: Money,
+    },
+    DrawCredit {
+        organization_id: Uuid,
+        amount: Money,
+    },
+    RepayCredit {
+        organization_id: Uuid,
+        amount: Money,
+    },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -175,4 +446,212 @@ pub struct GameMetadata {
     pub id: Uuid,
     pub name: String,
     pub created_at: SystemTime,
+    #[serde(default)]
+    pub world_parameters: WorldGenerationParameters,
+    #[serde(default)]
+    pub tick_mode: TickMode,
+}
+
+/// How a game's weeks pass. Turn-based games keep the clock paused and wait for the operator
+/// to end the week, real-time games start it at 1x, ending a week every
+/// `seconds_per_week` while a client is connected.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum TickMode {
+    #[default]
+    TurnBased,
+    RealTime {
+        seconds_per_week: u32,
+    },
+}
+
+/// Length of a week reported for turn-based games, whose clock never runs.
+pub const DEFAULT_SECONDS_PER_WEEK: u32 = 30;
+
+impl TickMode {
+    pub fn initial_clock_speed(&self) -> ClockSpeed {
+        match self {
+            TickMode::TurnBased => ClockSpeed::Paused,
+            TickMode::RealTime { .. } => ClockSpeed::Normal,
+        }
+    }
+
+    pub fn seconds_per_week(&self) -> u32 {
+        match self {
+            TickMode::TurnBased => DEFAULT_SECONDS_PER_WEEK,
+            TickMode::RealTime { seconds_per_week } => *seconds_per_week,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            TickMode::RealTime { seconds_per_week } if *seconds_per_week == 0 => {
+                Err("A real-time week must last at least 1 second".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum ClockSpeed {
+    #[default]
+    Paused,
+    Normal,
+    Double,
+    Quadruple,
+}
+
+impl ClockSpeed {
+    pub fn multiplier(&self) -> u32 {
+        match self {
+            ClockSpeed::Paused => 0,
+            ClockSpeed::Normal => 1,
+            ClockSpeed::Double => 2,
+            ClockSpeed::Quadruple => 4,
+        }
+    }
+
+    pub fn faster(&self) -> ClockSpeed {
+        match self {
+            ClockSpeed::Paused => ClockSpeed::Normal,
+            ClockSpeed::Normal => ClockSpeed::Double,
+            ClockSpeed::Double | ClockSpeed::Quadruple => ClockSpeed::Quadruple,
+        }
+    }
+
+    pub fn slower(&self) -> ClockSpeed {
+        match self {
+            ClockSpeed::Paused | ClockSpeed::Normal => ClockSpeed::Paused,
+            ClockSpeed::Double => ClockSpeed::Normal,
+            ClockSpeed::Quadruple => ClockSpeed::Double,
+        }
+    }
+}
+
+impl std::fmt::Display for ClockSpeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClockSpeed::Paused => write!(f, "Paused"),
+            speed => write!(f, "{}x", speed.multiplier()),
+        }
+    }
+}
+
+/// An instance's clock as sent along with the full state.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct ClockState {
+    pub speed: ClockSpeed,
+    pub seconds_per_week: u32,
+    /// Real time left in the week at the current speed, as of when the state was sent.
+    pub millis_until_next_week: u64,
+}
+
+/// Inputs to world generation. Stored with the game metadata so an instance can be
+/// rebuilt identically every time it is loaded.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(default)]
+pub struct WorldGenerationParameters {
+    pub seed: u64,
+    pub organization_count: usize,
+    pub company_type: CompanyType,
+    pub min_organization_size: usize,
+    pub max_organization_size: usize,
+    /// Companies competing with the operator's. Games created before there were rivals
+    /// have none.
+    #[serde(default)]
+    pub rival_count: usize,
+}
+
+/// Most rival companies a world can be generated with.
+pub const MAX_RIVALS: usize = 5;
+
+/// Most organizations the operator's company can be generated with.
+pub const MAX_ORGANIZATION_COUNT: usize = 20;
+
+/// Most people an organization can be generated with.
+pub const MAX_ORGANIZATION_SIZE: usize = 100;
+
+impl Default for WorldGenerationParameters {
+    fn default() -> Self {
+        Self {
+            seed: 12345,
+            organization_count: 7,
+            company_type: CompanyType::ECommerce,
+            min_organization_size: 9,
+            max_organization_size: 24,
+            rival_count: 3,
+        }
+    }
+}
+
+impl WorldGenerationParameters {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.organization_count < CORE_ORGANIZATION_TYPES.len() {
+            return Err(format!(
+                "Organization count must be at least {}",
+                CORE_ORGANIZATION_TYPES.len()
+            ));
+        }
+        if self.organization_count > MAX_ORGANIZATION_COUNT {
+            return Err(format!(
+                "Organization count must be at most {}",
+                MAX_ORGANIZATION_COUNT
+            ));
+        }
+        if self.min_organization_size == 0 {
+            return Err("Minimum organization size must be at least 1".to_string());
+        }
+        if self.min_organization_size > self.max_organization_size {
+            return Err(format!(
+                "Minimum organization size {} is larger than maximum {}",
+                self.min_organization_size, self.max_organization_size
+            ));
+        }
+        if self.max_organization_size > MAX_ORGANIZATION_SIZE {
+            return Err(format!(
+                "Maximum organization size must be at most {}",
+                MAX_ORGANIZATION_SIZE
+            ));
+        }
+        if self.rival_count > MAX_RIVALS {
+            return Err(format!(
+                "There can be at most {} rival companies",
+                MAX_RIVALS
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn world_parameters_above_the_caps_are_rejected() {
+        let largest = WorldGenerationParameters {
+            organization_count: MAX_ORGANIZATION_COUNT,
+            max_organization_size: MAX_ORGANIZATION_SIZE,
+            rival_count: MAX_RIVALS,
+            ..Default::default()
+        };
+        assert!(largest.validate().is_ok());
+
+        for too_large in [
+            WorldGenerationParameters {
+                organization_count: MAX_ORGANIZATION_COUNT + 1,
+                ..largest.clone()
+            },
+            WorldGenerationParameters {
+                max_organization_size: MAX_ORGANIZATION_SIZE + 1,
+                ..largest.clone()
+            },
+            WorldGenerationParameters {
+                rival_count: MAX_RIVALS + 1,
+                ..largest.clone()
+            },
+        ] {
+            assert!(too_large.validate().is_err());
+        }
+    }
 }
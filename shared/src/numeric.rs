@@ -0,0 +1,205 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// An amount of money in whole dollars. Arithmetic saturates instead of overflowing, and the
+/// value serializes as a plain number so logs written before it existed still read.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Money(pub i64);
+
+impl Money {
+    pub const ZERO: Money = Money(0);
+
+    pub const fn new(dollars: i64) -> Self {
+        Money(dollars)
+    }
+
+    pub const fn get(self) -> i64 {
+        self.0
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    pub fn abs(self) -> Self {
+        Money(self.0.saturating_abs())
+    }
+
+    /// `percent` of this amount, rounded toward zero.
+    pub fn percent(self, percent: i64) -> Self {
+        Money(
+            (self.0 as i128 * percent as i128 / 100).clamp(i64::MIN as i128, i64::MAX as i128)
+                as i64,
+        )
+    }
+
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    pub fn checked_sub(self, other: Money) -> Option<Money> {
+        self.0.checked_sub(other.0).map(Money)
+    }
+}
+
+impl From<i64> for Money {
+    fn from(dollars: i64) -> Self {
+        Money(dollars)
+    }
+}
+
+impl From<i32> for Money {
+    fn from(dollars: i32) -> Self {
+        Money(dollars as i64)
+    }
+}
+
+impl From<u32> for Money {
+    fn from(dollars: u32) -> Self {
+        Money(dollars as i64)
+    }
+}
+
+impl From<u16> for Money {
+    fn from(dollars: u16) -> Self {
+        Money(dollars as i64)
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        Money(self.0.saturating_add(other.0))
+    }
+}
+
+impl AddAssign for Money {
+    fn add_assign(&mut self, other: Money) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        Money(self.0.saturating_sub(other.0))
+    }
+}
+
+impl SubAssign for Money {
+    fn sub_assign(&mut self, other: Money) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Money {
+    type Output = Money;
+
+    fn neg(self) -> Money {
+        Money(self.0.saturating_neg())
+    }
+}
+
+impl Mul<i64> for Money {
+    type Output = Money;
+
+    fn mul(self, factor: i64) -> Money {
+        Money(self.0.saturating_mul(factor))
+    }
+}
+
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Money>>(iter: I) -> Money {
+        iter.fold(Money::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Money> for Money {
+    fn sum<I: Iterator<Item = &'a Money>>(iter: I) -> Money {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A percentage held between 0 and 100. Anything outside that range, including values read
+/// from a log, is clamped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct Percent(u16);
+
+impl Percent {
+    pub const MAX: u16 = 100;
+    pub const ZERO: Percent = Percent(0);
+    pub const FULL: Percent = Percent(Self::MAX);
+
+    pub const fn new(value: u16) -> Self {
+        if value > Self::MAX {
+            Percent(Self::MAX)
+        } else {
+            Percent(value)
+        }
+    }
+
+    pub const fn get(self) -> u16 {
+        self.0
+    }
+
+    pub fn saturating_add(self, points: u16) -> Self {
+        Percent::new(self.0.saturating_add(points))
+    }
+
+    pub fn saturating_sub(self, points: u16) -> Self {
+        Percent::new(self.0.saturating_sub(points))
+    }
+}
+
+impl<'de> Deserialize<'de> for Percent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u16::deserialize(deserializer).map(Percent::new)
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_round_trips_through_bincode() {
+        let bytes = bincode::serialize(&Percent::new(80)).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Percent>(&bytes).unwrap(),
+            Percent::new(80)
+        );
+    }
+
+    #[test]
+    fn percent_clamps_when_read() {
+        let bytes = bincode::serialize(&250u16).unwrap();
+        assert_eq!(
+            bincode::deserialize::<Percent>(&bytes).unwrap(),
+            Percent::FULL
+        );
+        assert_eq!(
+            serde_json::from_str::<Percent>("250").unwrap(),
+            Percent::FULL
+        );
+    }
+}
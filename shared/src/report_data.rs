@@ -0,0 +1,57 @@
+use crate::{Money, Percent};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use uuid::Uuid;
+
+pub const QUARTER_WEEKS: u16 = 13;
+pub const YEAR_WEEKS: u16 = 52;
+
+/// Every report filed in a game. Kept apart from the history, whose ring buffer only holds
+/// the last `MAX_HISTORY_POINTS` weeks.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ReportStore {
+    pub reports: Vec<FinancialReport>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ReportPeriod {
+    Quarterly,
+    Annual,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FinancialReport {
+    pub period: ReportPeriod,
+    pub first_week: u16,
+    pub last_week: u16,
+    pub companies: Vec<ReportLine>,
+    pub organizations: Vec<ReportLine>,
+}
+
+impl fmt::Display for FinancialReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let year = self.last_week / YEAR_WEEKS + 1;
+        match self.period {
+            ReportPeriod::Quarterly => {
+                let quarter = self.last_week / QUARTER_WEEKS % (YEAR_WEEKS / QUARTER_WEEKS) + 1;
+                write!(f, "Q{} Year {}", quarter, year)
+            }
+            ReportPeriod::Annual => write!(f, "Year {}", year),
+        }
+    }
+}
+
+/// One company's or organization's figures over a report's period.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReportLine {
+    pub id: Uuid,
+    pub name: String,
+    pub revenue: Money,
+    pub expenses: Money,
+    pub profit: Money,
+    /// Staff at the end of the period.
+    pub headcount: u32,
+    pub avg_satisfaction: Percent,
+    pub reputation_change: i16,
+    pub public_opinion_change: i16,
+}
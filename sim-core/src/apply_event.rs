@@ -0,0 +1,710 @@
+use shared::{
+    Employment, Financials, FormerEmployee, GameInstanceData, InternalEvent, Money, Percent,
+};
+
+/// The reducer: applies a single event to the game. Events are the only way state changes.
+pub fn apply_event(internal_event: &InternalEvent, instance_game: &mut GameInstanceData) {
+    match internal_event {
+        InternalEvent::RemoveEmployedStatus { employee_id } => {
+            if let Some(entity) = instance_game.game_state.entities.get_mut(employee_id) {
+                entity.employment = None;
+            }
+        }
+
+        InternalEvent::AddEmployedStatus {
+            organization_id,
+            employee_id,
+            role,
+            level,
+            salary,
+        } => {
+            instance_game.game_state.candidates.remove(employee_id);
+            // Someone already employed stays where they are.
+            if let Some(entity) = instance_game.game_state.entities.get_mut(employee_id)
+                && entity.employment.is_none()
+            {
+                entity.employment = Some(Employment {
+                    organization_id: *organization_id,
+                    role: *role,
+                    employee_flags: vec![],
+                    level: *level,
+                    salary: *salary,
+                    satisfaction: Percent::new(80),
+                    productivity: Percent::new(80),
+                    weeks_employed: 0,
+                    weeks_overworked: 0,
+                    raises: 0,
+                });
+            }
+        }
+
+        InternalEvent::ListCandidate { entity, candidate } => {
+            let game_state = &mut instance_game.game_state;
+            game_state.entities.insert(entity.id, entity.clone());
+            game_state
+                .candidates
+                .insert(candidate.entity_id, candidate.clone());
+        }
+
+        InternalEvent::DelistCandidate { entity_id } => {
+            // Job seekers who were not hired leave the world with their listing.
+            let game_state = &mut instance_game.game_state;
+            if game_state.candidates.remove(entity_id).is_some()
+                && game_state
+                    .entities
+                    .get(entity_id)
+                    .is_some_and(|entity| entity.employment.is_none())
+            {
+                game_state.entities.remove(entity_id);
+            }
+        }
+
+        InternalEvent::DecrementReputation { amount } => {
+            if let Some(player) = instance_game.game_state.players.first_mut() {
+                player.perception.reputation = player.perception.reputation.saturating_sub(*amount);
+            }
+        }
+
+        InternalEvent::IncrementReputation { amount } => {
+            if let Some(player) = instance_game.game_state.players.first_mut() {
+                player.perception.reputation = player.perception.reputation.saturating_add(*amount);
+            }
+        }
+
+        InternalEvent::DecrementMoney { amount } => {
+            let game_state = &mut instance_game.game_state;
+            if let Some(company) = game_state
+                .player_company_id()
+                .and_then(|company_id| game_state.companies.get_mut(&company_id))
+            {
+                company.financials.actual_cash -= *amount;
+            }
+        }
+
+        InternalEvent::IncrementMoney { amount } => {
+            let game_state = &mut instance_game.game_state;
+            if let Some(company) = game_state
+                .player_company_id()
+                .and_then(|company_id| game_state.companies.get_mut(&company_id))
+            {
+                company.financials.actual_cash += *amount;
+            }
+        }
+
+        InternalEvent::IncrementEmployeeSatisfaction {
+            employee_id,
+            amount,
+        } => {
+            if let Some(entity) = instance_game.game_state.entities.get_mut(employee_id)
+                && let Some(employment) = &mut entity.employment
+            {
+                employment.satisfaction = employment.satisfaction.saturating_add(*amount);
+            }
+        }
+
+        InternalEvent::DecrementEmployeeSatisfaction {
+            employee_id,
+            amount,
+        } => {
+            if let Some(employment) = instance_game
+                .game_state
+                .entities
+                .get_mut(employee_id)
+                .and_then(|entity| entity.employment.as_mut())
+            {
+                employment.satisfaction = employment.satisfaction.saturating_sub(*amount);
+            }
+        }
+
+        InternalEvent::RecordEmploymentWeek {
+            employee_id,
+            overworked,
+        } => {
+            if let Some(employment) = instance_game
+                .game_state
+                .entities
+                .get_mut(employee_id)
+                .and_then(|entity| entity.employment.as_mut())
+            {
+                employment.weeks_employed = employment.weeks_employed.saturating_add(1);
+                if *overworked {
+                    employment.weeks_overworked = employment.weeks_overworked.saturating_add(1);
+                } else {
+                    employment.weeks_overworked = 0;
+                }
+            }
+        }
+
+        InternalEvent::SetEmployeeFlag { employee_id, flag } => {
+            if let Some(employment) = instance_game
+                .game_state
+                .entities
+                .get_mut(employee_id)
+                .and_then(|entity| entity.employment.as_mut())
+                .filter(|employment| !employment.employee_flags.contains(flag))
+            {
+                employment.employee_flags.push(*flag);
+            }
+        }
+
+        InternalEvent::ClearEmployeeFlag { employee_id, flag } => {
+            if let Some(employment) = instance_game
+                .game_state
+                .entities
+                .get_mut(employee_id)
+                .and_then(|entity| entity.employment.as_mut())
+            {
+                employment
+                    .employee_flags
+                    .retain(|existing| existing != flag);
+            }
+        }
+
+        InternalEvent::EmployeeDeparted {
+            employee_id,
+            organization_id,
+            status,
+            reason,
+        } => {
+            let game_state = &mut instance_game.game_state;
+            if let Some(employment) = game_state
+                .entities
+                .get_mut(employee_id)
+                .and_then(|entity| entity.employment.take())
+            {
+                game_state.former_employees.push(FormerEmployee {
+                    entity_id: *employee_id,
+                    organization_id: *organization_id,
+                    role: employment.role,
+                    status: status.clone(),
+                    reason: *reason,
+                    week: game_state.week,
+                });
+            }
+        }
+
+        InternalEvent::IncrementOrgPublicOpinion {
+            organization_id,
+            amount,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization.perception.public_opinion = organization
+                    .perception
+                    .public_opinion
+                    .saturating_add(*amount);
+            }
+        }
+
+        InternalEvent::IncrementOrgReputation {
+            organization_id,
+            amount,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization.perception.reputation =
+                    organization.perception.reputation.saturating_add(*amount);
+            }
+        }
+
+        InternalEvent::IncrementSalary {
+            employee_id,
+            amount,
+        } => {
+            if let Some(entity) = instance_game.game_state.entities.get_mut(employee_id)
+                && let Some(employment) = &mut entity.employment
+            {
+                employment.salary += *amount;
+                employment.raises = employment.raises.saturating_add(1);
+            }
+        }
+
+        InternalEvent::SetOrgVp {
+            organization_id,
+            employee_id,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization.vp = *employee_id;
+            }
+        }
+
+        InternalEvent::SetEmployeeLevel { employee_id, level } => {
+            if let Some(employment) = instance_game
+                .game_state
+                .entities
+                .get_mut(employee_id)
+                .and_then(|entity| entity.employment.as_mut())
+            {
+                employment.level = *level;
+            }
+        }
+
+        InternalEvent::SetEmployeeOrganization {
+            employee_id,
+            organization_id,
+        } => {
+            if let Some(employment) = instance_game
+                .game_state
+                .entities
+                .get_mut(employee_id)
+                .and_then(|entity| entity.employment.as_mut())
+            {
+                employment.organization_id = *organization_id;
+            }
+        }
+
+        InternalEvent::SetOrganizationRole {
+            employee_id,
+            new_role,
+        } => {
+            if let Some(entity) = instance_game.game_state.entities.get_mut(employee_id)
+                && let Some(employment) = &mut entity.employment
+            {
+                employment.role = *new_role;
+            }
+        }
+
+        InternalEvent::SetOrgFinancials {
+            organization_id,
+            financials,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization.financials = financials.clone();
+            }
+        }
+
+        InternalEvent::RecordCorporateEvent { corporate_event } => {
+            instance_game
+                .game_state
+                .corporate_events
+                .push(corporate_event.clone());
+        }
+
+        InternalEvent::ResolveCorporateEvent {
+            corporate_event_id,
+            choice,
+        } => {
+            if let Some(corporate_event) = instance_game
+                .game_state
+                .corporate_events
+                .iter_mut()
+                .find(|corporate_event| corporate_event.id == *corporate_event_id)
+            {
+                corporate_event.resolution = Some(*choice);
+            }
+        }
+
+        InternalEvent::SetOrgInitiatives {
+            organization_id,
+            initiatives,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization.initiatives = initiatives.clone();
+            }
+        }
+
+        InternalEvent::AddOrgInitiative {
+            organization_id,
+            initiative,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+                .filter(|organization| {
+                    organization
+                        .initiatives
+                        .iter()
+                        .all(|running| running.kind != initiative.kind)
+                })
+            {
+                organization.initiatives.push(initiative.clone());
+            }
+        }
+
+        InternalEvent::RemoveOrgInitiative {
+            organization_id,
+            kind,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization
+                    .initiatives
+                    .retain(|initiative| initiative.kind != *kind);
+            }
+        }
+
+        InternalEvent::TickOrgInitiatives {
+            organization_id,
+            kinds,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                for initiative in organization
+                    .initiatives
+                    .iter_mut()
+                    .filter(|initiative| kinds.contains(&initiative.kind))
+                {
+                    initiative.weeks_remaining = initiative.weeks_remaining.saturating_sub(1);
+                }
+                organization
+                    .initiatives
+                    .retain(|initiative| initiative.weeks_remaining > 0);
+            }
+        }
+
+        InternalEvent::SetOrgPublicOpinion {
+            organization_id,
+            perception,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization.perception = perception.clone();
+            }
+        }
+
+        InternalEvent::SetOrgBudget {
+            organization_id,
+            budget,
+        } => {
+            if let Some(organization) = instance_game
+                .game_state
+                .organizations
+                .get_mut(organization_id)
+            {
+                organization.budget = budget.clone();
+            }
+        }
+
+        InternalEvent::SetCompanyFinancials {
+            company_id,
+            financials,
+        } => {
+            // The week's figures only, the company's cash moves with `AdjustCompanyCash`.
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.financials = Financials {
+                    actual_cash: company.financials.actual_cash,
+                    ..financials.clone()
+                };
+            }
+        }
+
+        InternalEvent::AdjustCompanyCash { company_id, amount } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.financials.actual_cash += *amount;
+            }
+        }
+
+        InternalEvent::SetCompanyOrders { company_id, orders } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.orders = orders.clone();
+            }
+        }
+
+        InternalEvent::AdjustCompanyReputation { company_id, amount } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.perception.reputation =
+                    company.perception.reputation.saturating_add(*amount);
+            }
+        }
+
+        InternalEvent::PayCeo {
+            company_id,
+            salary,
+            bonus,
+        } => {
+            let game_state = &mut instance_game.game_state;
+            let pay = *salary + *bonus;
+            if let Some(company) = game_state.companies.get_mut(company_id) {
+                company.financials.actual_cash -= pay;
+            }
+            if let Some(player) = game_state.players.first_mut() {
+                player.financials = Financials {
+                    actual_cash: player.financials.actual_cash + pay,
+                    this_weeks_income: pay,
+                    this_weeks_expenses: Money::ZERO,
+                    this_weeks_net_profit: pay,
+                };
+            }
+        }
+
+        InternalEvent::SetCeoCompensation {
+            salary,
+            bonus_percent,
+        } => {
+            if let Some(player) = instance_game.game_state.players.first_mut() {
+                player.compensation.salary = *salary;
+                player.compensation.bonus_percent = *bonus_percent;
+            }
+        }
+
+        InternalEvent::SellCeoEquity { percent, proceeds } => {
+            if let Some(player) = instance_game.game_state.players.first_mut() {
+                player.compensation.equity_percent = player
+                    .compensation
+                    .equity_percent
+                    .saturating_sub(percent.get());
+                player.financials.actual_cash += *proceeds;
+            }
+        }
+
+        InternalEvent::TakeLoan { company_id, loan } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.financials.actual_cash += loan.principal;
+                company.debt.loans_taken = company.debt.loans_taken.saturating_add(1);
+                company.debt.loans.push(loan.clone());
+            }
+        }
+
+        InternalEvent::RepayLoan {
+            company_id,
+            loan_id,
+            amount,
+        } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                if let Some(loan) = company
+                    .debt
+                    .loans
+                    .iter_mut()
+                    .find(|loan| loan.id == *loan_id)
+                {
+                    let amount = (*amount).min(loan.balance);
+                    loan.balance -= amount;
+                    company.financials.actual_cash -= amount;
+                }
+                company.debt.loans.retain(|loan| loan.balance > Money::ZERO);
+            }
+        }
+
+        InternalEvent::DrawCredit { company_id, amount } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.debt.credit_drawn += *amount;
+                company.financials.actual_cash += *amount;
+            }
+        }
+
+        InternalEvent::RepayCredit { company_id, amount } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                let amount = (*amount).min(company.debt.credit_drawn);
+                company.debt.credit_drawn -= amount;
+                company.financials.actual_cash -= amount;
+            }
+        }
+
+        InternalEvent::PayInterest { company_id, amount } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.financials.actual_cash -= *amount;
+            }
+        }
+
+        InternalEvent::SetCompanyInsolvency {
+            company_id,
+            weeks_insolvent,
+        } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.debt.weeks_insolvent = *weeks_insolvent;
+            }
+        }
+
+        InternalEvent::RestructureDebt { company_id, loan } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                let debt = &mut company.debt;
+                debt.loans = vec![loan.clone()];
+                debt.credit_drawn = Money::ZERO;
+                debt.loans_taken = debt.loans_taken.saturating_add(1);
+                debt.weeks_insolvent = 0;
+                debt.restructured = true;
+            }
+        }
+
+        InternalEvent::DeclareBankruptcy { company_id } => {
+            if let Some(company) = instance_game.game_state.companies.get_mut(company_id) {
+                company.debt.bankrupt = true;
+            }
+        }
+
+        InternalEvent::SetBoardConfidence { confidence } => {
+            instance_game.game_state.board.confidence = *confidence;
+        }
+
+        InternalEvent::IssueBoardWarning { demand } => {
+            let board = &mut instance_game.game_state.board;
+            board.warnings = board.warnings.saturating_add(1);
+            if demand.is_some() {
+                board.demand = demand.clone();
+            }
+        }
+
+        InternalEvent::CloseBoardDemand => {
+            instance_game.game_state.board.demand = None;
+        }
+
+        InternalEvent::EndGame { outcome } => {
+            instance_game.game_state.board.outcome = Some(outcome.clone());
+        }
+
+        InternalEvent::AppendHistoryPoint {
+            new_player_history_points,
+            new_company_history_points,
+            new_organization_history_points,
+        } => {
+            let history_state = &mut instance_game.history_state;
+            for (player_id, history_point) in new_player_history_points {
+                history_state
+                    .players
+                    .entry(*player_id)
+                    .or_default()
+                    .push(history_point.clone());
+            }
+
+            for (company_id, history_point) in new_company_history_points {
+                history_state
+                    .companies
+                    .entry(*company_id)
+                    .or_default()
+                    .push(history_point.clone());
+            }
+
+            for (organization_id, history_point) in new_organization_history_points {
+                history_state
+                    .organizations
+                    .entry(*organization_id)
+                    .or_default()
+                    .push(history_point.clone());
+            }
+        }
+
+        InternalEvent::FileReport { report } => {
+            instance_game.report_store.reports.push(report.clone());
+        }
+        InternalEvent::AdvanceWeek => {
+            instance_game.game_state.week = instance_game.game_state.week.saturating_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::World;
+    use shared::WorldGenerationParameters;
+    use uuid::Uuid;
+
+    fn world_and_employee() -> (World, Uuid) {
+        let world = World::generate(&WorldGenerationParameters::default());
+        let employee_id = world
+            .game_state()
+            .entities
+            .values()
+            .find(|entity| entity.employment.is_some())
+            .map(|entity| entity.id)
+            .expect("someone is employed");
+        (world, employee_id)
+    }
+
+    fn set_player_cash(world: &mut World, cash: Money) {
+        let game_state = &mut world.instance_game.game_state;
+        let company_id = game_state.player_company_id().unwrap();
+        game_state
+            .companies
+            .get_mut(&company_id)
+            .unwrap()
+            .financials
+            .actual_cash = cash;
+    }
+
+    fn player_cash(world: &World) -> Money {
+        let game_state = world.game_state();
+        game_state.companies[&game_state.player_company_id().unwrap()]
+            .financials
+            .actual_cash
+    }
+
+    #[test]
+    fn money_saturates_instead_of_overflowing() {
+        let (mut world, employee_id) = world_and_employee();
+
+        set_player_cash(&mut world, Money::new(i64::MAX - 10));
+        world.apply_event(&InternalEvent::IncrementMoney {
+            amount: Money::new(100),
+        });
+        assert_eq!(player_cash(&world), Money::new(i64::MAX));
+
+        set_player_cash(&mut world, Money::new(i64::MIN + 10));
+        world.apply_event(&InternalEvent::DecrementMoney {
+            amount: Money::new(100),
+        });
+        assert_eq!(player_cash(&world), Money::new(i64::MIN));
+
+        world
+            .instance_game
+            .game_state
+            .entities
+            .get_mut(&employee_id)
+            .unwrap()
+            .employment
+            .as_mut()
+            .unwrap()
+            .salary = Money::new(i64::MAX - 1);
+        world.apply_event(&InternalEvent::IncrementSalary {
+            employee_id,
+            amount: Money::new(100),
+        });
+        let employment = world.game_state().entities[&employee_id]
+            .employment
+            .as_ref()
+            .unwrap();
+        assert_eq!(employment.salary, Money::new(i64::MAX));
+    }
+
+    #[test]
+    fn satisfaction_stays_within_a_percent() {
+        let (mut world, employee_id) = world_and_employee();
+        let satisfaction = |world: &World| {
+            world.game_state().entities[&employee_id]
+                .employment
+                .as_ref()
+                .unwrap()
+                .satisfaction
+        };
+
+        world.apply_event(&InternalEvent::IncrementEmployeeSatisfaction {
+            employee_id,
+            amount: u16::MAX,
+        });
+        assert_eq!(satisfaction(&world), Percent::FULL);
+
+        world.apply_event(&InternalEvent::DecrementEmployeeSatisfaction {
+            employee_id,
+            amount: Percent::MAX + 1,
+        });
+        assert_eq!(satisfaction(&world), Percent::ZERO);
+    }
+}
@@ -0,0 +1,305 @@
+use shared::{
+    BoardDemand, BoardDemandKind, Company, FinalReport, GameInstanceData, GameOutcome,
+    GameOverReason, GameState, InternalEvent, Money, Percent, VictoryReason,
+};
+
+/// Confidence below which the board stops trusting the CEO to fix things alone.
+pub const WARNING_CONFIDENCE: Percent = Percent::new(40);
+
+/// Warnings the board hands out before it fires the CEO.
+pub const MAX_BOARD_WARNINGS: u16 = 3;
+
+/// Weeks the CEO gets to meet a demand.
+pub const DEMAND_WEEKS: u16 = 8;
+
+/// A company worth this much wins the game.
+pub const VICTORY_VALUATION: Money = Money::new(10_000_000);
+
+/// Length of the CEO's term, five years.
+pub const TERM_WEEKS: u16 = 260;
+
+/// Weeks back the board looks to judge growth.
+const GROWTH_WEEKS: usize = 4;
+
+const PROFIT_CONFIDENCE: i16 = 2;
+const LOSS_CONFIDENCE: i16 = -4;
+const GOOD_REPUTATION_CONFIDENCE: i16 = 1;
+const BAD_REPUTATION_CONFIDENCE: i16 = -2;
+const GROWTH_CONFIDENCE: i16 = 1;
+const DECLINE_CONFIDENCE: i16 = -2;
+const DEMAND_MET_CONFIDENCE: i16 = 10;
+const DEMAND_MISSED_CONFIDENCE: i16 = -15;
+
+/// The board's weekly review of the CEO. It scores the week's net profit, the company's
+/// reputation and how sales grew over the last few weeks of history, checks on any demand it
+/// made, and warns the CEO with a new demand when confidence runs low. Out of confidence or
+/// warnings, or with the company bankrupt, the CEO is fired; a company worth
+/// `VICTORY_VALUATION`, or a full term served, wins the game.
+pub fn board_updates(game_instance: &GameInstanceData) -> Vec<InternalEvent> {
+    let game_state = &game_instance.game_state;
+    let Some(company) = game_state
+        .player_company_id()
+        .and_then(|company_id| game_state.companies.get(&company_id))
+    else {
+        return vec![];
+    };
+    let board = &game_state.board;
+    let mut events = vec![];
+
+    let mut confidence_change = weekly_score(game_instance, company);
+    let mut warnings = board.warnings;
+
+    let mut demand_open = board.demand.is_some();
+    if let Some(demand) = &board.demand {
+        if demand_met(game_state, company, demand.kind) {
+            events.push(InternalEvent::CloseBoardDemand);
+            confidence_change += DEMAND_MET_CONFIDENCE;
+            demand_open = false;
+        } else if game_state.week >= demand.deadline_week {
+            events.push(InternalEvent::CloseBoardDemand);
+            events.push(InternalEvent::IssueBoardWarning { demand: None });
+            confidence_change += DEMAND_MISSED_CONFIDENCE;
+            warnings += 1;
+            // A missed demand is warning enough for one week.
+            demand_open = true;
+        }
+    }
+
+    let confidence = Percent::new(
+        (board.confidence.get() as i16 + confidence_change).clamp(0, Percent::MAX as i16) as u16,
+    );
+    if confidence != board.confidence {
+        events.push(InternalEvent::SetBoardConfidence { confidence });
+    }
+
+    if !demand_open && confidence < WARNING_CONFIDENCE {
+        events.push(InternalEvent::IssueBoardWarning {
+            demand: Some(BoardDemand {
+                kind: next_demand(game_state, company),
+                deadline_week: game_state.week + DEMAND_WEEKS,
+            }),
+        });
+        warnings += 1;
+    }
+
+    let report = || final_report(game_state, company, confidence, warnings);
+    let game_over = |reason| GameOutcome::GameOver {
+        reason,
+        report: Box::new(report()),
+    };
+    let victory = |reason| GameOutcome::Victory {
+        reason,
+        report: Box::new(report()),
+    };
+    let outcome = if company.debt.bankrupt {
+        Some(game_over(GameOverReason::Bankrupt))
+    } else if confidence == Percent::ZERO {
+        Some(game_over(GameOverReason::LostConfidence))
+    } else if warnings >= MAX_BOARD_WARNINGS {
+        Some(game_over(GameOverReason::TooManyWarnings))
+    } else if company.valuation() >= VICTORY_VALUATION {
+        Some(victory(VictoryReason::ValuationTarget))
+    } else if game_state.week + 1 >= TERM_WEEKS {
+        Some(victory(VictoryReason::FullTerm))
+    } else {
+        None
+    };
+    if let Some(outcome) = outcome {
+        events.push(InternalEvent::EndGame { outcome });
+    }
+
+    events
+}
+
+fn weekly_score(game_instance: &GameInstanceData, company: &Company) -> i16 {
+    let financials = &company.financials;
+    let mut score = if financials.this_weeks_net_profit > Money::ZERO {
+        PROFIT_CONFIDENCE
+    } else {
+        LOSS_CONFIDENCE
+    };
+
+    score += match company.perception.reputation {
+        reputation if reputation > 0 => GOOD_REPUTATION_CONFIDENCE,
+        reputation if reputation < 0 => BAD_REPUTATION_CONFIDENCE,
+        _ => 0,
+    };
+
+    // Sales against what they were a few weeks ago, a slump of more than a tenth counts.
+    let earlier_income = game_instance
+        .history_state
+        .companies
+        .get(&company.id)
+        .and_then(|history| {
+            let points = history.recent_history.len();
+            history
+                .recent_history
+                .get(points.checked_sub(GROWTH_WEEKS)?)
+        })
+        .map(|point| point.financials.this_weeks_income);
+    if let Some(earlier_income) = earlier_income {
+        let income = financials.this_weeks_income;
+        if income > earlier_income {
+            score += GROWTH_CONFIDENCE;
+        } else if income < earlier_income.percent(90) {
+            score += DECLINE_CONFIDENCE;
+        }
+    }
+
+    score
+}
+
+/// What the board asks for, its worst worry first.
+fn next_demand(game_state: &GameState, company: &Company) -> BoardDemandKind {
+    let owed = company.debt.outstanding();
+    if company.financials.this_weeks_net_profit <= Money::ZERO {
+        BoardDemandKind::ReturnToProfit
+    } else if company.perception.reputation < 0 {
+        BoardDemandKind::RestoreReputation
+    } else if owed > Money::ZERO {
+        BoardDemandKind::ReduceDebt {
+            below: owed.percent(50),
+        }
+    } else {
+        let salary = game_state
+            .players
+            .first()
+            .map(|player| player.compensation.salary)
+            .unwrap_or_default();
+        BoardDemandKind::CutCeoPay {
+            salary: salary.percent(50),
+        }
+    }
+}
+
+fn demand_met(game_state: &GameState, company: &Company, kind: BoardDemandKind) -> bool {
+    match kind {
+        BoardDemandKind::ReturnToProfit => company.financials.this_weeks_net_profit > Money::ZERO,
+        BoardDemandKind::RestoreReputation => company.perception.reputation >= 0,
+        BoardDemandKind::ReduceDebt { below } => company.debt.outstanding() < below,
+        BoardDemandKind::CutCeoPay { salary } => game_state
+            .players
+            .first()
+            .is_some_and(|player| player.compensation.salary <= salary),
+    }
+}
+
+fn final_report(
+    game_state: &GameState,
+    company: &Company,
+    board_confidence: Percent,
+    warnings: u16,
+) -> FinalReport {
+    let (ceo_wealth, equity_percent) = game_state
+        .players
+        .first()
+        .map(|player| {
+            (
+                player.financials.actual_cash,
+                player.compensation.equity_percent,
+            )
+        })
+        .unwrap_or_default();
+    let employees = game_state
+        .entities
+        .values()
+        .filter_map(|entity| entity.employment.as_ref())
+        .filter(|employment| {
+            game_state
+                .organizations
+                .get(&employment.organization_id)
+                .is_some_and(|organization| organization.company_relation.entity_id == company.id)
+        })
+        .count() as u32;
+
+    FinalReport {
+        week: game_state.week,
+        company_name: company.name.clone(),
+        company_cash: company.financials.actual_cash,
+        valuation: company.valuation(),
+        debt: company.debt.outstanding(),
+        ceo_wealth,
+        equity_percent,
+        board_confidence,
+        warnings,
+        employees,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{INSOLVENCY_GRACE_WEEKS, World};
+    use shared::{ClientActionCommand, WorldGenerationParameters};
+
+    #[test]
+    fn bankruptcy_ends_the_game_the_week_it_is_declared() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let game_state = &mut world.instance_game.game_state;
+        let company_id = game_state.player_company_id().unwrap();
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.financials.actual_cash = Money::new(-1_000);
+        company.debt.restructured = true;
+        company.debt.weeks_insolvent = INSOLVENCY_GRACE_WEEKS - 1;
+
+        world.step(ClientActionCommand::EndTurn).unwrap();
+
+        assert!(matches!(
+            world.game_state().board.outcome,
+            Some(GameOutcome::GameOver {
+                reason: GameOverReason::Bankrupt,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn a_reputation_hit_brings_a_demand_the_company_can_meet() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let game_state = &mut world.instance_game.game_state;
+        let company_id = game_state.player_company_id().unwrap();
+        game_state.board.confidence = Percent::new(10);
+        game_state.players[0].compensation.equity_percent = Percent::new(5);
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.financials.this_weeks_net_profit = Money::new(1_000);
+
+        world
+            .step(ClientActionCommand::SellEquity {
+                percent: Percent::new(1),
+            })
+            .unwrap();
+        assert_eq!(
+            world.game_state().companies[&company_id]
+                .perception
+                .reputation,
+            -1
+        );
+
+        let events = board_updates(&world.instance_game);
+        assert!(events.iter().any(|event| matches!(
+            event,
+            InternalEvent::IssueBoardWarning {
+                demand: Some(BoardDemand {
+                    kind: BoardDemandKind::RestoreReputation,
+                    ..
+                })
+            }
+        )));
+        events.iter().for_each(|event| world.apply_event(event));
+
+        world.step(ClientActionCommand::LaunchPRCampaign).unwrap();
+        assert_eq!(
+            world.game_state().companies[&company_id]
+                .perception
+                .reputation,
+            0
+        );
+
+        let events = board_updates(&world.instance_game);
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, InternalEvent::CloseBoardDemand))
+        );
+    }
+}
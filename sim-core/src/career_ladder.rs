@@ -0,0 +1,124 @@
+use shared::{EmployeeFlag, Employment, Entity, GameState, InternalEvent, OrganizationRole};
+use uuid::Uuid;
+
+/// Satisfaction an employee gains from a promotion or loses from a demotion.
+const PROMOTION_SATISFACTION: u16 = 5;
+const DEMOTION_SATISFACTION: u16 = 10;
+
+/// Satisfaction lost by everyone who was at least as senior as a colleague who got promoted.
+const PASSED_OVER_SATISFACTION: u16 = 3;
+
+/// Whether moving to the role and level is a step up: never to a less senior tier or a lower
+/// level, and up in at least one of them.
+pub fn is_promotion(employment: &Employment, role: OrganizationRole, level: u16) -> bool {
+    let (from_rank, to_rank) = (employment.role.rank(), role.rank());
+    to_rank <= from_rank
+        && level >= employment.level
+        && (to_rank < from_rank || level > employment.level)
+}
+
+/// Whether moving to the role and level is a step down, the mirror image of `is_promotion`.
+pub fn is_demotion(employment: &Employment, role: OrganizationRole, level: u16) -> bool {
+    let (from_rank, to_rank) = (employment.role.rank(), role.rank());
+    to_rank >= from_rank
+        && level <= employment.level
+        && (to_rank > from_rank || level < employment.level)
+}
+
+/// Puts an employee in a new role at a new level. A promotion lifts their salary to at least
+/// the bottom of the new pay band and clears any grudge about being passed over; a demotion
+/// keeps their salary but costs satisfaction.
+pub fn change_position(
+    employee: &Entity,
+    employment: &Employment,
+    role: OrganizationRole,
+    level: u16,
+) -> Vec<InternalEvent> {
+    let mut events = vec![];
+    let employee_id = employee.id;
+    let promotion = is_promotion(employment, role, level);
+
+    if role != employment.role {
+        events.push(InternalEvent::SetOrganizationRole {
+            employee_id,
+            new_role: role,
+        });
+    }
+    if level != employment.level {
+        events.push(InternalEvent::SetEmployeeLevel { employee_id, level });
+    }
+
+    if promotion {
+        let band = role.pay_band(level);
+        if employment.salary < band.min {
+            events.push(InternalEvent::IncrementSalary {
+                employee_id,
+                amount: band.min - employment.salary,
+            });
+        }
+        if employment
+            .employee_flags
+            .contains(&EmployeeFlag::PassedOver)
+        {
+            events.push(InternalEvent::ClearEmployeeFlag {
+                employee_id,
+                flag: EmployeeFlag::PassedOver,
+            });
+        }
+        events.push(InternalEvent::IncrementEmployeeSatisfaction {
+            employee_id,
+            amount: PROMOTION_SATISFACTION,
+        });
+    } else {
+        events.push(InternalEvent::DecrementEmployeeSatisfaction {
+            employee_id,
+            amount: DEMOTION_SATISFACTION,
+        });
+    }
+
+    events
+}
+
+/// Colleagues who were at least as senior as the promoted employee used to be, in the same tier
+/// or above and at the same level or higher, feel passed over. Their VP is above all this.
+pub fn passed_over_updates(
+    game_state: &GameState,
+    promoted_id: Uuid,
+    previous: &Employment,
+) -> Vec<InternalEvent> {
+    let vp = game_state
+        .organizations
+        .get(&previous.organization_id)
+        .and_then(|organization| organization.vp);
+
+    let mut colleagues: Vec<(&Entity, &Employment)> = game_state
+        .entities
+        .values()
+        .filter(|entity| entity.id != promoted_id && Some(entity.id) != vp)
+        .filter_map(|entity| Some((entity, entity.employment.as_ref()?)))
+        .filter(|(_, employment)| {
+            employment.organization_id == previous.organization_id
+                && employment.role.rank() <= previous.role.rank()
+                && employment.level >= previous.level
+        })
+        .collect();
+    colleagues.sort_by_key(|(entity, _)| entity.id);
+
+    let mut events = vec![];
+    for (colleague, employment) in colleagues {
+        if !employment
+            .employee_flags
+            .contains(&EmployeeFlag::PassedOver)
+        {
+            events.push(InternalEvent::SetEmployeeFlag {
+                employee_id: colleague.id,
+                flag: EmployeeFlag::PassedOver,
+            });
+        }
+        events.push(InternalEvent::DecrementEmployeeSatisfaction {
+            employee_id: colleague.id,
+            amount: PASSED_OVER_SATISFACTION,
+        });
+    }
+    events
+}
@@ -0,0 +1,321 @@
+use shared::{
+    CorporateEventChoice, InitiativeKind, MAX_CEO_BONUS_PERCENT, MAX_INITIATIVE_WEEKS,
+    MAX_LOAN_WEEKS, Money, OrganizationRole, Percent,
+};
+use std::fmt;
+use uuid::Uuid;
+
+/// Why a command was refused. A rejected command produces no events and does not end the week.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CommandRejection {
+    UnknownEntity {
+        entity_id: Uuid,
+    },
+    UnknownOrganization {
+        organization_id: Uuid,
+    },
+    NotAPerson {
+        name: String,
+    },
+    AlreadyEmployed {
+        name: String,
+    },
+    NotLookingForWork {
+        name: String,
+    },
+    NotEmployed {
+        name: String,
+    },
+    NotInOrganization {
+        name: String,
+        organization_name: String,
+    },
+    AlreadyVp {
+        name: String,
+        organization_name: String,
+    },
+    EmptyRaise {
+        name: String,
+    },
+    NotHireableRole {
+        role: OrganizationRole,
+    },
+    LevelOutOfReach {
+        name: String,
+        level: u16,
+    },
+    RoleDeclined {
+        name: String,
+        role: OrganizationRole,
+    },
+    OfferDeclined {
+        name: String,
+        minimum_salary: Money,
+    },
+    VpByAppointmentOnly {
+        name: String,
+    },
+    NotAPromotion {
+        name: String,
+    },
+    NotADemotion {
+        name: String,
+    },
+    AlreadyInOrganization {
+        name: String,
+        organization_name: String,
+    },
+    StillVp {
+        name: String,
+        organization_name: String,
+    },
+    InvalidInitiativePlan {
+        weeks: u16,
+        budget: Money,
+    },
+    InitiativeAlreadyRunning {
+        organization_name: String,
+        kind: InitiativeKind,
+    },
+    NoSuchInitiative {
+        organization_name: String,
+        kind: InitiativeKind,
+    },
+    InsufficientFunds {
+        cost: Money,
+    },
+    NoOpenCorporateEvent {
+        corporate_event_id: Uuid,
+    },
+    ChoiceNotOffered {
+        choice: CorporateEventChoice,
+    },
+    WorksForRival {
+        name: String,
+    },
+    RivalOrganization {
+        organization_name: String,
+    },
+    OperatorOnly,
+    InvalidCompensation {
+        salary: Money,
+        bonus_percent: Percent,
+    },
+    NotEnoughEquity {
+        held: Percent,
+    },
+    NegativeBudget {
+        organization_name: String,
+    },
+    NotFinance {
+        organization_name: String,
+    },
+    InvalidLoanTerms {
+        amount: Money,
+        weeks: u16,
+    },
+    OverBorrowingLimit {
+        available: Money,
+    },
+    CreditLineExhausted {
+        available: Money,
+    },
+    CovenantsBroken,
+    NoSuchLoan {
+        loan_id: Uuid,
+    },
+    InvalidRepayment {
+        amount: Money,
+        owed: Money,
+    },
+    EmptyDraw,
+    Bankrupt {
+        company_name: String,
+    },
+    GameFinished,
+    /// Only real-time games have a running clock.
+    TurnBasedClock,
+    /// The server could not record the command's events, so it did not take effect.
+    EventsNotRecorded,
+}
+
+impl fmt::Display for CommandRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandRejection::UnknownEntity { entity_id } => {
+                write!(f, "No one with id {} exists", entity_id)
+            }
+            CommandRejection::UnknownOrganization { organization_id } => {
+                write!(f, "No organization with id {} exists", organization_id)
+            }
+            CommandRejection::NotAPerson { name } => {
+                write!(f, "{} is a pet, not a person", name)
+            }
+            CommandRejection::AlreadyEmployed { name } => {
+                write!(f, "{} is already employed", name)
+            }
+            CommandRejection::NotLookingForWork { name } => {
+                write!(f, "{} is not on the job market", name)
+            }
+            CommandRejection::NotEmployed { name } => write!(f, "{} is not employed", name),
+            CommandRejection::NotInOrganization {
+                name,
+                organization_name,
+            } => write!(f, "{} does not work in {}", name, organization_name),
+            CommandRejection::AlreadyVp {
+                name,
+                organization_name,
+            } => write!(f, "{} is already VP of {}", name, organization_name),
+            CommandRejection::EmptyRaise { name } => {
+                write!(f, "A raise for {} must be more than nothing", name)
+            }
+            CommandRejection::NotHireableRole { role } => {
+                write!(
+                    f,
+                    "{:?} positions are filled by promotion, not hiring",
+                    role
+                )
+            }
+            CommandRejection::LevelOutOfReach { name, level } => {
+                write!(f, "{} cannot be hired at level {}", name, level)
+            }
+            CommandRejection::RoleDeclined { name, role } => {
+                write!(f, "{} has no interest in working as {:?}", name, role)
+            }
+            CommandRejection::OfferDeclined {
+                name,
+                minimum_salary,
+            } => write!(
+                f,
+                "{} declined the offer, they want at least ${} a week",
+                name, minimum_salary
+            ),
+            CommandRejection::VpByAppointmentOnly { name } => {
+                write!(f, "{} can only become VP by being appointed to it", name)
+            }
+            CommandRejection::NotAPromotion { name } => {
+                write!(f, "That position would not be a promotion for {}", name)
+            }
+            CommandRejection::NotADemotion { name } => {
+                write!(f, "That position would not be a demotion for {}", name)
+            }
+            CommandRejection::AlreadyInOrganization {
+                name,
+                organization_name,
+            } => write!(f, "{} already works in {}", name, organization_name),
+            CommandRejection::StillVp {
+                name,
+                organization_name,
+            } => write!(
+                f,
+                "{} is VP of {}, they have to step down first",
+                name, organization_name
+            ),
+            CommandRejection::InvalidInitiativePlan { weeks, budget } => write!(
+                f,
+                "An initiative needs a budget and 1 to {} weeks, not ${} over {} weeks",
+                MAX_INITIATIVE_WEEKS, budget, weeks
+            ),
+            CommandRejection::InitiativeAlreadyRunning {
+                organization_name,
+                kind,
+            } => write!(
+                f,
+                "{} is already running a {:?} initiative",
+                organization_name, kind
+            ),
+            CommandRejection::NoSuchInitiative {
+                organization_name,
+                kind,
+            } => write!(
+                f,
+                "{} is not running a {:?} initiative",
+                organization_name, kind
+            ),
+            CommandRejection::InsufficientFunds { cost } => {
+                write!(
+                    f,
+                    "That costs ${} up front, more than there is in the bank",
+                    cost
+                )
+            }
+            CommandRejection::NoOpenCorporateEvent { corporate_event_id } => write!(
+                f,
+                "No corporate event {} is waiting for a decision",
+                corporate_event_id
+            ),
+            CommandRejection::ChoiceNotOffered { choice } => {
+                write!(f, "{:?} is not one of the choices", choice)
+            }
+            CommandRejection::WorksForRival { name } => {
+                write!(f, "{} works for another company", name)
+            }
+            CommandRejection::RivalOrganization { organization_name } => {
+                write!(f, "{} belongs to another company", organization_name)
+            }
+            CommandRejection::OperatorOnly => {
+                write!(f, "Only the operator can do that")
+            }
+            CommandRejection::InvalidCompensation {
+                salary,
+                bonus_percent,
+            } => write!(
+                f,
+                "The board will not sign off on ${} a week with a {}% bonus, the salary cannot \
+                 be negative and the bonus is capped at {}%",
+                salary, bonus_percent, MAX_CEO_BONUS_PERCENT
+            ),
+            CommandRejection::NotEnoughEquity { held } => {
+                write!(f, "You only hold {}% of the company", held)
+            }
+            CommandRejection::NegativeBudget { organization_name } => {
+                write!(f, "{} cannot budget less than nothing", organization_name)
+            }
+            CommandRejection::NotFinance { organization_name } => {
+                write!(
+                    f,
+                    "{} does not handle the company's finances",
+                    organization_name
+                )
+            }
+            CommandRejection::InvalidLoanTerms { amount, weeks } => write!(
+                f,
+                "A loan needs an amount and 1 to {} weeks, not ${} over {} weeks",
+                MAX_LOAN_WEEKS, amount, weeks
+            ),
+            CommandRejection::OverBorrowingLimit { available } => {
+                write!(f, "The lenders will not go beyond another ${}", available)
+            }
+            CommandRejection::CreditLineExhausted { available } => {
+                write!(f, "Only ${} is left on the credit line", available)
+            }
+            CommandRejection::CovenantsBroken => {
+                write!(
+                    f,
+                    "The company has broken its covenants, no one will lend to it"
+                )
+            }
+            CommandRejection::NoSuchLoan { loan_id } => {
+                write!(f, "The company has no loan {}", loan_id)
+            }
+            CommandRejection::InvalidRepayment { amount, owed } => {
+                write!(f, "Cannot repay ${} of the ${} owed", amount, owed)
+            }
+            CommandRejection::EmptyDraw => {
+                write!(f, "A draw on the credit line must be more than nothing")
+            }
+            CommandRejection::Bankrupt { company_name } => {
+                write!(f, "{} is bankrupt", company_name)
+            }
+            CommandRejection::GameFinished => write!(f, "The game is over"),
+            CommandRejection::TurnBasedClock => {
+                write!(f, "This game is turn-based, end the week to move on")
+            }
+            CommandRejection::EventsNotRecorded => {
+                write!(f, "The server could not record that, try again")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommandRejection {}
@@ -0,0 +1,113 @@
+use crate::{ceo_pay, interest_due, market_sales, organization_budgets, payroll};
+use shared::{Financials, GameState, InternalEvent};
+
+/// Each company's trading so far this week: its orders, and what they earned against its
+/// payroll, its organizations' budgets, the interest on its debt and its CEO's pay.
+pub fn company_updates(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut events = vec![];
+
+    for sales in market_sales(game_state) {
+        let Some(company) = game_state.companies.get(&sales.company_id) else {
+            continue;
+        };
+        let operating_costs = payroll(game_state, &sales.company_id)
+            + organization_budgets(game_state, &sales.company_id)
+            + interest_due(company);
+        let expenses = operating_costs
+            + ceo_pay(
+                game_state,
+                &sales.company_id,
+                sales.revenue,
+                operating_costs,
+            )
+            .map(|(salary, bonus)| salary + bonus)
+            .unwrap_or_default();
+        events.push(InternalEvent::SetCompanyFinancials {
+            company_id: sales.company_id,
+            financials: Financials {
+                this_weeks_income: sales.revenue,
+                this_weeks_expenses: expenses,
+                this_weeks_net_profit: sales.revenue - expenses,
+                actual_cash: company.financials.actual_cash,
+            },
+        });
+        events.push(InternalEvent::SetCompanyOrders {
+            company_id: sales.company_id,
+            orders: sales.orders,
+        });
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{World, interest_due};
+    use shared::{Money, Percent, WorldGenerationParameters};
+    use uuid::Uuid;
+
+    fn company_financials(events: &[InternalEvent], company_id: Uuid) -> Financials {
+        events
+            .iter()
+            .find_map(|event| match event {
+                InternalEvent::SetCompanyFinancials {
+                    company_id: id,
+                    financials,
+                } if *id == company_id => Some(financials.clone()),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn the_interest_on_a_companys_debt_is_part_of_its_expenses() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let company_id = world.game_state().player_company_id().unwrap();
+        // Without a bonus, which would shrink with the profit.
+        world.instance_game.game_state.players[0]
+            .compensation
+            .bonus_percent = Percent::ZERO;
+        let debt_free = company_financials(&company_updates(world.game_state()), company_id);
+        assert_eq!(
+            debt_free.this_weeks_net_profit,
+            debt_free.this_weeks_income - debt_free.this_weeks_expenses
+        );
+
+        let game_state = &mut world.instance_game.game_state;
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.debt.credit_drawn = Money::new(520_000);
+        let interest = interest_due(company);
+        assert!(interest > Money::ZERO);
+
+        let indebted = company_financials(&company_updates(game_state), company_id);
+        assert_eq!(
+            indebted.this_weeks_expenses,
+            debt_free.this_weeks_expenses + interest
+        );
+    }
+
+    #[test]
+    fn trading_leaves_the_cash_where_it_was() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let cash: Vec<_> = world
+            .game_state()
+            .companies
+            .values()
+            .map(|company| (company.id, company.financials.actual_cash))
+            .collect();
+
+        for event in company_updates(world.game_state()) {
+            world.apply_event(&event);
+        }
+
+        for (company_id, actual_cash) in cash {
+            assert_eq!(
+                world.game_state().companies[&company_id]
+                    .financials
+                    .actual_cash,
+                actual_cash
+            );
+        }
+    }
+}
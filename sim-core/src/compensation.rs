@@ -0,0 +1,25 @@
+use shared::{GameState, Money};
+use uuid::Uuid;
+
+/// What a new CEO has in their own account, everything else they make comes from the company.
+pub const CEO_STARTING_WEALTH: Money = Money::new(25_000);
+
+/// The week's pay for the CEO of a company that earned `revenue` against `expenses`: their
+/// salary, and their bonus share of the profit left after it. Rival companies have no CEO to
+/// pay.
+pub fn ceo_pay(
+    game_state: &GameState,
+    company_id: &Uuid,
+    revenue: Money,
+    expenses: Money,
+) -> Option<(Money, Money)> {
+    if game_state.player_company_id() != Some(*company_id) {
+        return None;
+    }
+    let compensation = &game_state.players.first()?.compensation;
+    let profit = revenue - expenses - compensation.salary;
+    let bonus = profit
+        .max(Money::ZERO)
+        .percent(compensation.bonus_percent.get() as i64);
+    Some((compensation.salary, bonus))
+}
@@ -0,0 +1,326 @@
+use crate::deterministic_uuid;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use shared::{
+    CorporateEvent, CorporateEventChoice, CorporateEventKind, Entity, GameState, InitiativeKind,
+    InternalEvent, Money, Organization, OrganizationType,
+};
+use uuid::Uuid;
+
+/// Mixed into the world seed so the event deck draws from its own random stream.
+const CORPORATE_EVENT_STREAM: u64 = 0x6576_656e;
+
+/// Weekly chance in percent that a corporate event is drawn at all.
+const EVENT_CHANCE_PERCENT: u32 = 25;
+
+/// Average satisfaction below which an organization has someone ready to blow the whistle.
+const STRAINED_SATISFACTION: u32 = 50;
+
+const DECK: &[CorporateEventKind] = &[
+    CorporateEventKind::DataBreach,
+    CorporateEventKind::WarehouseAccident,
+    CorporateEventKind::ViralMarketing,
+    CorporateEventKind::Whistleblower,
+    CorporateEventKind::SurpriseAudit,
+];
+
+/// The week's corporate events. Decisions left open since last week are settled with their
+/// default choice, then a new event may be drawn from the cards whose conditions hold. The
+/// draw is seeded from the world seed and the week, so replaying the week draws the same card.
+pub fn corporate_event_updates(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut events = vec![];
+
+    for corporate_event in game_state
+        .corporate_events
+        .iter()
+        .filter(|corporate_event| corporate_event.is_pending())
+    {
+        let default_choice = *corporate_event.choices.last().unwrap();
+        events.extend(resolve_corporate_event(
+            game_state,
+            corporate_event,
+            default_choice,
+        ));
+    }
+
+    let event_seed = game_state.seed ^ CORPORATE_EVENT_STREAM;
+    let mut rng = StdRng::seed_from_u64(event_seed.wrapping_add(game_state.week as u64));
+    if rng.gen_range(0..100) >= EVENT_CHANCE_PERCENT {
+        return events;
+    }
+
+    // Only the operator's organizations make the news, rivals deal with their own trouble.
+    let player_company_id = game_state.player_company_id();
+    let mut organizations: Vec<&Organization> = game_state
+        .organizations
+        .values()
+        .filter(|organization| Some(organization.company_relation.entity_id) == player_company_id)
+        .collect();
+    organizations.sort_by_key(|organization| organization.id);
+
+    let playable: Vec<(CorporateEventKind, Vec<&Organization>)> = DECK
+        .iter()
+        .map(|kind| {
+            let eligible = organizations
+                .iter()
+                .copied()
+                .filter(|organization| condition_holds(game_state, *kind, organization))
+                .collect::<Vec<_>>();
+            (*kind, eligible)
+        })
+        .filter(|(_, eligible)| !eligible.is_empty())
+        .collect();
+
+    let Some((kind, eligible)) = playable.choose(&mut rng) else {
+        return events;
+    };
+    let organization = eligible.choose(&mut rng).unwrap();
+
+    let corporate_event = CorporateEvent {
+        id: deterministic_uuid(event_seed, game_state.week as u64),
+        kind: *kind,
+        organization_id: organization.id,
+        week: game_state.week,
+        choices: choices_for(*kind),
+        resolution: None,
+    };
+    if corporate_event.choices.is_empty() {
+        events.extend(effects(game_state, *kind, None, organization));
+    }
+    events.push(InternalEvent::RecordCorporateEvent { corporate_event });
+
+    events
+}
+
+/// The player's answer to an event: its effects, then the event is marked resolved.
+pub fn resolve_corporate_event(
+    game_state: &GameState,
+    corporate_event: &CorporateEvent,
+    choice: CorporateEventChoice,
+) -> Vec<InternalEvent> {
+    let mut events = vec![];
+    if let Some(organization) = game_state
+        .organizations
+        .get(&corporate_event.organization_id)
+    {
+        events.extend(effects(
+            game_state,
+            corporate_event.kind,
+            Some(choice),
+            organization,
+        ));
+    }
+    events.push(InternalEvent::ResolveCorporateEvent {
+        corporate_event_id: corporate_event.id,
+        choice,
+    });
+    events
+}
+
+/// What the player can do about an event, the default last. Events without choices take
+/// effect as soon as they are drawn.
+fn choices_for(kind: CorporateEventKind) -> Vec<CorporateEventChoice> {
+    use CorporateEventChoice::*;
+
+    match kind {
+        CorporateEventKind::DataBreach => vec![Disclose, CoverUp],
+        CorporateEventKind::WarehouseAccident => vec![CompensateAndFix, MinimalResponse],
+        CorporateEventKind::Whistleblower => vec![Investigate, Dismiss],
+        CorporateEventKind::ViralMarketing | CorporateEventKind::SurpriseAudit => vec![],
+    }
+}
+
+fn condition_holds(
+    game_state: &GameState,
+    kind: CorporateEventKind,
+    organization: &Organization,
+) -> bool {
+    use OrganizationType::*;
+
+    match kind {
+        CorporateEventKind::DataBreach => organization.organization_type == ITInfrastructure,
+        CorporateEventKind::WarehouseAccident => {
+            matches!(organization.organization_type, Warehouse | LogisticsHub)
+        }
+        CorporateEventKind::ViralMarketing => {
+            matches!(
+                organization.organization_type,
+                MarketingTeam | ContentCreation
+            ) || organization
+                .initiatives
+                .iter()
+                .any(|initiative| initiative.kind == InitiativeKind::Marketing)
+        }
+        CorporateEventKind::Whistleblower => {
+            let staff = staff_of(game_state, organization.id);
+            let total: u32 = staff
+                .iter()
+                .filter_map(|entity| entity.employment.as_ref())
+                .map(|employment| employment.satisfaction.get() as u32)
+                .sum();
+            !staff.is_empty() && total / (staff.len() as u32) < STRAINED_SATISFACTION
+        }
+        CorporateEventKind::SurpriseAudit => organization.organization_type == Finance,
+    }
+}
+
+fn effects(
+    game_state: &GameState,
+    kind: CorporateEventKind,
+    choice: Option<CorporateEventChoice>,
+    organization: &Organization,
+) -> Vec<InternalEvent> {
+    use CorporateEventChoice::*;
+
+    let organization_id = organization.id;
+    let company_id = organization.company_relation.entity_id;
+    let mut events = vec![];
+    let mut staff_satisfaction: i32 = 0;
+
+    match (kind, choice) {
+        (CorporateEventKind::DataBreach, Some(Disclose)) => {
+            events.push(InternalEvent::DecrementMoney {
+                amount: Money::new(50_000),
+            });
+            events.push(InternalEvent::IncrementOrgPublicOpinion {
+                organization_id,
+                amount: -3,
+            });
+            events.push(InternalEvent::AdjustCompanyReputation {
+                company_id,
+                amount: 1,
+            });
+        }
+        (CorporateEventKind::DataBreach, _) => {
+            events.push(InternalEvent::IncrementOrgPublicOpinion {
+                organization_id,
+                amount: -1,
+            });
+            events.push(InternalEvent::AdjustCompanyReputation {
+                company_id,
+                amount: -3,
+            });
+            staff_satisfaction = -5;
+        }
+        (CorporateEventKind::WarehouseAccident, Some(CompensateAndFix)) => {
+            events.push(InternalEvent::DecrementMoney {
+                amount: Money::new(30_000),
+            });
+            staff_satisfaction = 5;
+        }
+        (CorporateEventKind::WarehouseAccident, _) => {
+            events.push(InternalEvent::IncrementOrgPublicOpinion {
+                organization_id,
+                amount: -4,
+            });
+            staff_satisfaction = -10;
+        }
+        (CorporateEventKind::ViralMarketing, _) => {
+            events.push(InternalEvent::IncrementOrgPublicOpinion {
+                organization_id,
+                amount: 8,
+            });
+            events.push(InternalEvent::AdjustCompanyReputation {
+                company_id,
+                amount: 2,
+            });
+        }
+        (CorporateEventKind::Whistleblower, Some(Investigate)) => {
+            events.push(InternalEvent::DecrementMoney {
+                amount: Money::new(20_000),
+            });
+            events.push(InternalEvent::AdjustCompanyReputation {
+                company_id,
+                amount: 2,
+            });
+            staff_satisfaction = 5;
+        }
+        (CorporateEventKind::Whistleblower, _) => {
+            events.push(InternalEvent::AdjustCompanyReputation {
+                company_id,
+                amount: -4,
+            });
+            staff_satisfaction = -8;
+        }
+        (CorporateEventKind::SurpriseAudit, _) => {
+            events.push(InternalEvent::DecrementMoney {
+                amount: Money::new(10_000),
+            });
+            events.push(InternalEvent::IncrementOrgReputation {
+                organization_id,
+                amount: 1,
+            });
+        }
+    }
+
+    for employee in staff_of(game_state, organization_id) {
+        if staff_satisfaction > 0 {
+            events.push(InternalEvent::IncrementEmployeeSatisfaction {
+                employee_id: employee.id,
+                amount: staff_satisfaction as u16,
+            });
+        } else if staff_satisfaction < 0 {
+            events.push(InternalEvent::DecrementEmployeeSatisfaction {
+                employee_id: employee.id,
+                amount: staff_satisfaction.unsigned_abs() as u16,
+            });
+        }
+    }
+
+    events
+}
+
+/// Employees of an organization in a fixed order.
+fn staff_of(game_state: &GameState, organization_id: Uuid) -> Vec<&Entity> {
+    let mut staff: Vec<&Entity> = game_state
+        .entities
+        .values()
+        .filter(|entity| {
+            entity
+                .employment
+                .as_ref()
+                .is_some_and(|employment| employment.organization_id == organization_id)
+        })
+        .collect();
+    staff.sort_by_key(|entity| entity.id);
+    staff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::World;
+    use shared::WorldGenerationParameters;
+
+    #[test]
+    fn a_dismissed_whistleblower_costs_the_company_its_reputation() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let game_state = world.game_state();
+        let company_id = game_state.player_company_id().unwrap();
+        let organization_id = game_state
+            .organizations
+            .values()
+            .filter(|organization| organization.company_relation.entity_id == company_id)
+            .map(|organization| organization.id)
+            .min()
+            .unwrap();
+        let corporate_event = CorporateEvent {
+            id: Uuid::nil(),
+            kind: CorporateEventKind::Whistleblower,
+            organization_id,
+            week: game_state.week,
+            choices: choices_for(CorporateEventKind::Whistleblower),
+            resolution: None,
+        };
+        let ceo_reputation = game_state.players[0].perception.reputation;
+
+        let events =
+            resolve_corporate_event(game_state, &corporate_event, CorporateEventChoice::Dismiss);
+        events.iter().for_each(|event| world.apply_event(event));
+
+        let game_state = world.game_state();
+        assert_eq!(game_state.companies[&company_id].perception.reputation, -4);
+        assert_eq!(game_state.players[0].perception.reputation, ceo_reputation);
+    }
+}
@@ -0,0 +1,369 @@
+use crate::CEO_STARTING_WEALTH;
+use crate::deterministic_randomization::{
+    PetKind, build_all_company_name_pool, build_all_human_name_pools, build_all_org_name_pools,
+    build_all_pet_name_pools, generate_human_type_for_organization_role,
+    generate_organization_chart, generate_organization_types_for_company,
+    generate_pet_type_for_rank, generate_realistic_name, generate_unique_pet_name,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sha2::{Digest, Sha256};
+use shared::{
+    Budget, CeoCompensation, Company, CompanyRelation, Employment, Entity, EntityFlag, EntityType,
+    Financials, GameState, HumanType, Money, Organization, OrganizationRole, OrganizationType,
+    Origin, Owner, Percent, Perception, Player, RIVAL_STRATEGIES, WorldGenerationParameters,
+};
+use std::collections::{HashMap, VecDeque};
+use tracing::info;
+use uuid::Uuid;
+
+/// Cash every company starts with in its operating account.
+const COMPANY_STARTING_CASH: Money = Money::new(1_000_000);
+
+/// Mixed into the world seed so each rival company is generated from its own random stream.
+const RIVAL_STREAM: u64 = 0x7269_7661;
+
+pub fn create_empty_world_state(world_parameters: &WorldGenerationParameters) -> GameState {
+    let mut new_game_state = generate_game_state_deterministic(world_parameters, 0);
+
+    info!("spawning player");
+    new_game_state.players.push(Player {
+        id: None,
+        financials: Financials {
+            actual_cash: CEO_STARTING_WEALTH,
+            ..Financials::default()
+        },
+        perception: Perception {
+            public_opinion: 0,
+            reputation: 0,
+        },
+        compensation: CeoCompensation::default(),
+    });
+
+    new_game_state
+}
+
+pub fn generate_game_state_deterministic(
+    world_parameters: &WorldGenerationParameters,
+    week: u16,
+) -> GameState {
+    let seed = world_parameters.seed;
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let company_type = world_parameters.company_type.clone();
+    let organization_types = generate_organization_types_for_company(
+        &mut rng,
+        &company_type,
+        world_parameters.organization_count,
+    );
+
+    let mut company_name_pool = build_all_company_name_pool(&mut rng, &company_type);
+    let human_name_pool = build_all_human_name_pools(&mut rng);
+    let mut org_name_pool = build_all_org_name_pools(&mut rng);
+    let pet_name_pool = build_all_pet_name_pools(&mut rng);
+
+    let mut uuid_counter = 0;
+
+    let mut game_state = GameState {
+        week,
+        players: vec![],
+        companies: HashMap::new(),
+        organizations: HashMap::new(),
+        entities: HashMap::new(),
+        former_employees: vec![],
+        candidates: HashMap::new(),
+        seed,
+        corporate_events: vec![],
+        board: Default::default(),
+    };
+
+    let company_id = deterministic_uuid(seed, uuid_counter);
+    uuid_counter += 1;
+    let company = Company {
+        id: company_id,
+        name: company_name_pool.pop_back().unwrap(),
+        company_type: company_type.clone(),
+        perception: Perception::default(),
+        financials: Financials {
+            actual_cash: COMPANY_STARTING_CASH,
+            ..Default::default()
+        },
+        rival: None,
+        orders: Default::default(),
+        debt: Default::default(),
+    };
+    generate_company(
+        &mut game_state,
+        world_parameters,
+        company,
+        organization_types,
+        &mut NamePools {
+            human: human_name_pool,
+            organization: &mut org_name_pool,
+            pet: pet_name_pool,
+        },
+        &mut rng,
+        &mut uuid_counter,
+    );
+
+    // Rivals come after the operator's company and draw from their own random streams, so
+    // adding them leaves the operator's company as it was. Company and organization names
+    // stay unique across the world, people and pets get fresh pools per company.
+    for rival_index in 0..world_parameters.rival_count {
+        let mut rng = StdRng::seed_from_u64((seed ^ RIVAL_STREAM).wrapping_add(rival_index as u64));
+        let organization_types = generate_organization_types_for_company(
+            &mut rng,
+            &company_type,
+            world_parameters.organization_count,
+        );
+        let company_id = deterministic_uuid(seed, uuid_counter);
+        uuid_counter += 1;
+        let company = Company {
+            id: company_id,
+            name: company_name_pool.pop_back().unwrap(),
+            company_type: company_type.clone(),
+            perception: Perception::default(),
+            financials: Financials {
+                actual_cash: COMPANY_STARTING_CASH,
+                ..Default::default()
+            },
+            rival: Some(RIVAL_STRATEGIES[rival_index % RIVAL_STRATEGIES.len()]),
+            orders: Default::default(),
+            debt: Default::default(),
+        };
+        let mut name_pools = NamePools {
+            human: build_all_human_name_pools(&mut rng),
+            organization: &mut org_name_pool,
+            pet: build_all_pet_name_pools(&mut rng),
+        };
+        generate_company(
+            &mut game_state,
+            world_parameters,
+            company,
+            organization_types,
+            &mut name_pools,
+            &mut rng,
+            &mut uuid_counter,
+        );
+    }
+
+    game_state
+}
+
+struct NamePools<'a> {
+    human: HashMap<(HumanType, OrganizationRole), VecDeque<String>>,
+    organization: &'a mut HashMap<OrganizationType, VecDeque<String>>,
+    pet: HashMap<PetKind, VecDeque<String>>,
+}
+
+/// Adds a company with an organization of each given type, staffed and with their pets.
+fn generate_company(
+    game_state: &mut GameState,
+    world_parameters: &WorldGenerationParameters,
+    company: Company,
+    organization_types: Vec<OrganizationType>,
+    name_pools: &mut NamePools<'_>,
+    rng: &mut StdRng,
+    uuid_counter: &mut u64,
+) {
+    let seed = world_parameters.seed;
+    let company_id = company.id;
+    game_state.companies.insert(company_id, company);
+
+    for organization_type in organization_types {
+        let organization_chart = generate_organization_chart(
+            organization_type,
+            rng,
+            world_parameters.min_organization_size,
+            world_parameters.max_organization_size,
+        );
+
+        let org_id = deterministic_uuid(seed, *uuid_counter);
+        *uuid_counter += 1;
+        let org_name = name_pools
+            .organization
+            .get_mut(&organization_type)
+            .unwrap()
+            .pop_back()
+            .unwrap();
+
+        let mut employee_ids = vec![];
+        let vp_id = deterministic_uuid(seed, *uuid_counter);
+        *uuid_counter += 1;
+        employee_ids.push(vp_id);
+
+        let organization_role = OrganizationRole::VP;
+        let human_type =
+            generate_human_type_for_organization_role(&organization_role, rng).unwrap();
+        let human_name = name_pools
+            .human
+            .get_mut(&(human_type, organization_role))
+            .unwrap()
+            .pop_back()
+            .unwrap();
+
+        let vp = Entity {
+            id: vp_id,
+            name: human_name,
+            entity_type: EntityType::Human(human_type),
+            employment: Some(Employment {
+                organization_id: org_id,
+                role: OrganizationRole::VP,
+                employee_flags: vec![],
+                level: rng.gen_range(16..=20),
+                salary: Money::from(rng.gen_range(950u16..=2_000u16)),
+                // Drawn in hundredths to keep the world's random sequence.
+                satisfaction: Percent::new(rng.gen_range(6_700u16..=9_600u16) / 100),
+                productivity: Percent::new(rng.gen_range(7_200u16..=9_100u16) / 100),
+                weeks_employed: 0,
+                weeks_overworked: 0,
+                raises: 0,
+            }),
+            owner: None,
+            origin: Origin {
+                week_of_birth: -rng.gen_range(200..=400),
+            },
+            flags: vec![],
+        };
+        game_state.entities.insert(vp_id, vp);
+
+        for org_chart_employee in organization_chart.employees {
+            let id = deterministic_uuid(seed, *uuid_counter);
+            *uuid_counter += 1;
+
+            let organization_role = org_chart_employee.role;
+            // Large organizations can outgrow a pool, the rest get names that may repeat.
+            let human_name = name_pools
+                .human
+                .get_mut(&(org_chart_employee.human_type, organization_role))
+                .unwrap()
+                .pop_back()
+                .unwrap_or_else(|| {
+                    generate_realistic_name(org_chart_employee.human_type, organization_role, rng)
+                });
+
+            let level = match org_chart_employee.rank {
+                0 => rng.gen_range(12..=15),
+                1 => rng.gen_range(8..=11),
+                2 => rng.gen_range(6..=8),
+                3 => rng.gen_range(1..=5),
+                _ => 1,
+            };
+
+            let salary = ((level as f32 * 0.8) + (rng.gen_range(0.7..=0.9) * level as f32)) as u16;
+            let satisfaction = rng.gen_range(50..=100);
+            let productivity = rng.gen_range(60..=100);
+
+            let mut flags = Vec::new();
+
+            if rng.gen_bool(0.02) {
+                flags.push(EntityFlag::Hoarder);
+            }
+
+            let employee = Entity {
+                id,
+                name: human_name.clone(),
+                entity_type: EntityType::Human(org_chart_employee.human_type),
+                employment: Some(Employment {
+                    organization_id: org_id,
+                    role: org_chart_employee.role,
+                    employee_flags: vec![],
+                    level,
+                    salary: Money::from(salary),
+                    satisfaction: Percent::new(satisfaction),
+                    productivity: Percent::new(productivity),
+                    weeks_employed: 0,
+                    weeks_overworked: 0,
+                    raises: 0,
+                }),
+                owner: None,
+                origin: Origin {
+                    week_of_birth: -rng.gen_range(700..=2_400),
+                },
+                flags,
+            };
+            game_state.entities.insert(id, employee.clone());
+            employee_ids.push(id);
+
+            let is_hoarder = employee.flags.contains(&EntityFlag::Hoarder);
+            let number_of_pets = if is_hoarder {
+                rng.gen_range(5..11)
+            } else if rng.gen_bool(0.1) {
+                1
+            } else {
+                0
+            };
+
+            for _ in 0..number_of_pets {
+                let pet = generate_pet_for_role(
+                    org_chart_employee.rank,
+                    employee.id,
+                    &mut name_pools.pet,
+                    rng,
+                    seed,
+                    uuid_counter,
+                );
+                game_state.entities.insert(pet.id, pet);
+            }
+        }
+
+        // Create organization
+        let organization = Organization {
+            id: org_id,
+            name: org_name,
+            organization_type: organization_chart.organization_type,
+            vp: Some(vp_id),
+            company_relation: CompanyRelation {
+                entity_id: company_id,
+            },
+            financials: Financials::default(),
+            perception: Perception::default(),
+            budget: Budget {
+                marketing: Money::new(10),
+                rnd: Money::new(10),
+                training: Money::new(10),
+            },
+            initiatives: vec![],
+        };
+
+        game_state.organizations.insert(org_id, organization);
+    }
+}
+
+fn generate_pet_for_role(
+    rank: u32,
+    owner_id: Uuid,
+    pet_name_pool: &mut HashMap<PetKind, VecDeque<String>>,
+    rng: &mut StdRng,
+    seed: u64,
+    uuid_counter: &mut u64,
+) -> Entity {
+    let entity_type = generate_pet_type_for_rank(rank as usize, rng);
+    let id = deterministic_uuid(seed, *uuid_counter);
+    *uuid_counter += 1;
+
+    let name = generate_unique_pet_name(&entity_type, pet_name_pool, rng).unwrap();
+
+    Entity {
+        id,
+        name,
+        entity_type,
+        employment: None,
+        owner: Some(Owner {
+            entity_id: owner_id,
+        }),
+        origin: Origin {
+            week_of_birth: rng.gen_range(10..100),
+        },
+        flags: vec![],
+    }
+}
+
+pub(crate) fn deterministic_uuid(seed: u64, counter: u64) -> Uuid {
+    let mut hasher = Sha256::new();
+    hasher.update(seed.to_le_bytes());
+    hasher.update(counter.to_le_bytes());
+    let hash = hasher.finalize();
+    let bytes: [u8; 16] = hash[0..16].try_into().unwrap();
+    Uuid::from_bytes(bytes)
+}
@@ -1,7 +1,7 @@
 use rand::Rng;
 use rand::distributions::Distribution;
 use rand::distributions::WeightedIndex;
-use shared::{HumanType, OrganizationRole};
+use shared::{HIREABLE_ROLES, HumanType, OrganizationRole};
 
 fn human_types_for_role(organization_role: &OrganizationRole) -> Vec<(HumanType, u32)> {
     match organization_role {
@@ -148,3 +148,24 @@ pub fn generate_human_type_for_organization_role(
     let weighted_humans = human_types_for_role(role);
     weighted_sample(&weighted_humans, rng)
 }
+
+/// How well people of this type suit the role, the weight the role gives them when it is
+/// filled at random. Zero when the role never draws on this type at all.
+pub fn human_type_fit_for_role(role: &OrganizationRole, human_type: &HumanType) -> u32 {
+    human_types_for_role(role)
+        .into_iter()
+        .find(|(candidate_type, _)| candidate_type == human_type)
+        .map(|(_, weight)| weight)
+        .unwrap_or(0)
+}
+
+/// Hireable roles that people of this type are drawn to, best fit first.
+pub fn roles_suited_to_human_type(human_type: &HumanType) -> Vec<OrganizationRole> {
+    let mut suited: Vec<(OrganizationRole, u32)> = HIREABLE_ROLES
+        .iter()
+        .map(|role| (*role, human_type_fit_for_role(role, human_type)))
+        .filter(|(_, weight)| *weight > 0)
+        .collect();
+    suited.sort_by_key(|(_, weight)| std::cmp::Reverse(*weight));
+    suited.into_iter().map(|(role, _)| role).collect()
+}
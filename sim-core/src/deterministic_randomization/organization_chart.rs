@@ -1,11 +1,6 @@
 use crate::deterministic_randomization::employee_types::weighted_employee_types_for_org;
 use crate::deterministic_randomization::generate_human_type_for_organization_role;
 use rand::prelude::*;
-use shared::OrganizationRole::{
-    Accountant, CFO, COO, ContentCreator, CustomerSupport, DataScientist, DevOpsEngineer,
-    HRManager, LegalCounsel, LogisticsCoordinator, MarketingSpecialist, ProductManager,
-    ResearchScientist, RnDEngineer, SalesRep, SoftwareEngineer, UXDesigner, VP, WarehouseManager,
-};
 use shared::{HumanType, OrganizationRole, OrganizationType};
 
 pub struct Employee {
@@ -37,14 +32,7 @@ pub fn generate_organization_chart(
     for _ in 0..num_employees {
         let role = roles[dist.sample(rng)];
         let human_type = generate_human_type_for_organization_role(role, rng).unwrap();
-        let rank = match role {
-            VP => 0,
-            CFO | COO => 1,
-            HRManager | LegalCounsel | Accountant | ProductManager => 2,
-            SoftwareEngineer | DataScientist | DevOpsEngineer | UXDesigner
-            | MarketingSpecialist | ContentCreator | SalesRep | WarehouseManager
-            | LogisticsCoordinator | CustomerSupport | ResearchScientist | RnDEngineer => 3,
-        };
+        let rank = role.rank();
 
         employees.push(Employee {
             role: *role,
@@ -58,3 +46,14 @@ pub fn generate_organization_chart(
         employees,
     }
 }
+
+/// The most senior role an organization of this type is staffed with, where a VP who steps
+/// down lands. Ties go to the role the organization employs most.
+pub fn fitting_role_for_organization(org_type: OrganizationType) -> OrganizationRole {
+    weighted_employee_types_for_org(org_type)
+        .into_iter()
+        .filter(|(role, _)| *role != OrganizationRole::VP)
+        .min_by_key(|(role, weight)| (role.rank(), std::cmp::Reverse(*weight)))
+        .map(|(role, _)| role)
+        .unwrap_or(OrganizationRole::HRManager)
+}
@@ -1,7 +1,6 @@
 use rand::Rng;
 use rand::rngs::StdRng;
 use shared::{CORE_ORGANIZATION_TYPES, CompanyType, OrganizationType};
-use std::collections::HashSet;
 
 fn get_weighted_organization_for_company_type(
     company_type: &CompanyType,
@@ -27,8 +26,9 @@ pub fn generate_organization_types_for_company(
     company_type: &CompanyType,
     count: usize,
 ) -> Vec<OrganizationType> {
-    let mut selected_organizations: HashSet<OrganizationType> =
-        CORE_ORGANIZATION_TYPES.iter().copied().collect();
+    // Kept in pick order, set iteration order differs between runs and would make the world
+    // depend on more than the seed.
+    let mut selected_organizations: Vec<OrganizationType> = CORE_ORGANIZATION_TYPES.to_vec();
     let weighted_organizations = get_weighted_organization_for_company_type(company_type);
     let total_weight: f64 = weighted_organizations.iter().map(|(_, w)| *w).sum();
 
@@ -39,7 +39,9 @@ pub fn generate_organization_types_for_company(
         for (org_type, weight) in &weighted_organizations {
             accumulator += weight;
             if pick <= accumulator {
-                selected_organizations.insert(*org_type);
+                if !selected_organizations.contains(org_type) {
+                    selected_organizations.push(*org_type);
+                }
                 break;
             }
         }
@@ -52,5 +54,5 @@ pub fn generate_organization_types_for_company(
         }
     }
 
-    selected_organizations.into_iter().collect()
+    selected_organizations
 }
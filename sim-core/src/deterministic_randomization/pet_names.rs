@@ -328,7 +328,14 @@ pub fn generate_unique_pet_name(
     rng: &mut StdRng,
 ) -> Option<String> {
     let kind = pet_kind_from_entity(entity_type);
-    let base_name = pools.get_mut(&kind)?.pop_front()?;
+    let pool = pools.get_mut(&kind)?;
+    if pool.is_empty() {
+        // Every name is taken, they come round again in a new order.
+        let mut names = all_pet_names(kind);
+        names.shuffle(rng);
+        pool.extend(names);
+    }
+    let base_name = pool.pop_front()?;
 
     // 30% chance to add prefix or suffix
     let add_decoration = rng.gen_bool(0.3);
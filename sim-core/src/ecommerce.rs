@@ -0,0 +1,48 @@
+use shared::{FulfilmentStage, Money, OrganizationType};
+
+/// Orders an e-commerce company of average appeal takes in an ordinary week.
+pub const BASE_WEEKLY_ORDERS: u32 = 1_500;
+
+/// What a fulfilled order earns.
+pub const ORDER_VALUE: Money = Money::new(40);
+
+/// Orders contractors take through a stage each week for anyone, so a company without its
+/// own warehouse, logistics or support still trades. Call centers and carriers are easier to
+/// hire than a warehouse.
+pub fn third_party_capacity(stage: FulfilmentStage) -> u32 {
+    match stage {
+        FulfilmentStage::Picking => 600,
+        FulfilmentStage::Shipping => 900,
+        FulfilmentStage::Support => 1_200,
+    }
+}
+
+/// Demand through the year in percent of an ordinary week: a slow start after the holidays,
+/// a summer lull, then the run up to the holidays.
+pub fn seasonal_percent(week: u16) -> u32 {
+    match week % 52 {
+        0..=3 => 80,
+        26..=34 => 90,
+        44..=47 => 130,
+        48..=51 => 160,
+        _ => 100,
+    }
+}
+
+/// Orders a fully productive employee of the organization moves through each stage in a week.
+/// Warehouses pick and pack, logistics hubs ship, support centers answer customers, and retail
+/// sites do a little of the first two for orders collected in store.
+pub fn fulfilment_contribution(
+    organization_type: OrganizationType,
+) -> &'static [(FulfilmentStage, u32)] {
+    match organization_type {
+        OrganizationType::Warehouse => &[(FulfilmentStage::Picking, 120)],
+        OrganizationType::LogisticsHub => &[(FulfilmentStage::Shipping, 120)],
+        OrganizationType::SupportCenter => &[(FulfilmentStage::Support, 150)],
+        OrganizationType::RetailSite => &[
+            (FulfilmentStage::Picking, 40),
+            (FulfilmentStage::Shipping, 40),
+        ],
+        _ => &[],
+    }
+}
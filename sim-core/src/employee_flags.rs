@@ -0,0 +1,93 @@
+use shared::{EmployeeFlag, Employment, Money};
+
+/// Consecutive overworked weeks before an employee burns out.
+pub const BURNOUT_WEEKS: u16 = 8;
+
+/// Tenure and number of raises after which an employee becomes loyal.
+pub const LOYAL_TENURE_WEEKS: u16 = 104;
+pub const LOYAL_RAISES: u16 = 2;
+
+/// How a flag changes an employee, each as a percentage of the unflagged value.
+struct FlagEffects {
+    productivity: u32,
+    quit_chance: u32,
+    salary_expectation: u32,
+}
+
+fn flag_effects(flag: &EmployeeFlag) -> FlagEffects {
+    match flag {
+        EmployeeFlag::WantsRaise => FlagEffects {
+            productivity: 90,
+            quit_chance: 150,
+            salary_expectation: 110,
+        },
+        EmployeeFlag::BurnedOut => FlagEffects {
+            productivity: 60,
+            quit_chance: 200,
+            salary_expectation: 120,
+        },
+        EmployeeFlag::Loyal => FlagEffects {
+            productivity: 110,
+            quit_chance: 25,
+            salary_expectation: 90,
+        },
+        EmployeeFlag::PassedOver => FlagEffects {
+            productivity: 90,
+            quit_chance: 150,
+            salary_expectation: 105,
+        },
+    }
+}
+
+fn apply_flags(employment: &Employment, value: i64, effect: fn(&FlagEffects) -> u32) -> i64 {
+    apply_flags_except(employment, None, value, effect)
+}
+
+fn apply_flags_except(
+    employment: &Employment,
+    except: Option<EmployeeFlag>,
+    value: i64,
+    effect: fn(&FlagEffects) -> u32,
+) -> i64 {
+    employment
+        .employee_flags
+        .iter()
+        .filter(|flag| Some(**flag) != except)
+        .fold(value, |value, flag| {
+            value.saturating_mul(effect(&flag_effects(flag)) as i64) / 100
+        })
+}
+
+pub fn effective_productivity(employment: &Employment) -> u32 {
+    apply_flags(
+        employment,
+        employment.productivity.get() as i64,
+        |effects| effects.productivity,
+    ) as u32
+}
+
+pub fn adjusted_quit_chance(employment: &Employment, chance: u32) -> u32 {
+    apply_flags(employment, chance as i64, |effects| effects.quit_chance).min(100) as u32
+}
+
+/// The salary an employee thinks their position is worth, the middle of their role's pay band.
+pub fn expected_salary(employment: &Employment) -> Money {
+    band_expectation(employment, None)
+}
+
+/// The expectation whether an employee wants a raise is judged by, worked out from the pay
+/// band each week. Wanting one already does not count, or the flag would keep raising the bar
+/// for clearing it.
+pub fn raise_expectation(employment: &Employment) -> Money {
+    band_expectation(employment, Some(EmployeeFlag::WantsRaise))
+}
+
+fn band_expectation(employment: &Employment, except: Option<EmployeeFlag>) -> Money {
+    let midpoint = employment.role.pay_band(employment.level).midpoint;
+    Money::new(apply_flags_except(
+        employment,
+        except,
+        midpoint.get(),
+        |effects| effects.salary_expectation,
+    ))
+}
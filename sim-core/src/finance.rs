@@ -0,0 +1,348 @@
+use crate::deterministic_uuid;
+use shared::{
+    Budget, Company, GameState, InternalEvent, Loan, Money, Organization, OrganizationType, Percent,
+};
+use uuid::Uuid;
+
+const LOAN_STREAM: u64 = 0x6c6f_616e;
+
+const WEEKS_PER_YEAR: i64 = 52;
+
+/// Yearly interest on a new term loan.
+pub const LOAN_RATE: Percent = Percent::new(8);
+
+/// Yearly interest on what is drawn from the credit line.
+pub const CREDIT_LINE_RATE: Percent = Percent::new(12);
+
+/// Extra yearly interest on everything owed while a company breaks its covenants.
+pub const COVENANT_PENALTY_RATE: u16 = 6;
+
+/// Lenders want a company they can stand behind. Below either of these its covenants are
+/// broken: no new borrowing and a penalty on the interest.
+pub const COVENANT_MIN_REPUTATION: i16 = -10;
+pub const COVENANT_MIN_PUBLIC_OPINION: i16 = -10;
+
+/// Most a company may owe on term loans, in percent of its valuation.
+pub const BORROWING_LIMIT_PERCENT: i64 = 50;
+
+/// Size of the revolving credit line, in percent of the company's valuation.
+pub const CREDIT_LINE_PERCENT: i64 = 10;
+
+/// Weeks a company may start with negative cash before its lenders step in.
+pub const INSOLVENCY_GRACE_WEEKS: u16 = 4;
+
+/// Terms of the single loan a forced restructuring leaves a company with.
+pub const RESTRUCTURED_RATE: Percent = Percent::new(20);
+pub const RESTRUCTURED_WEEKS: u16 = 104;
+
+/// Reputation a company loses when its lenders take over its books.
+const RESTRUCTURING_REPUTATION_LOSS: i16 = 5;
+
+/// Judged on the company's own standing, where the operator's reputation events are booked.
+pub fn covenants_broken(company: &Company) -> bool {
+    company.perception.reputation < COVENANT_MIN_REPUTATION
+        || company.perception.public_opinion < COVENANT_MIN_PUBLIC_OPINION
+}
+
+/// The rate the company actually pays on debt taken out at `annual_rate`.
+pub fn interest_rate(company: &Company, annual_rate: Percent) -> Percent {
+    if covenants_broken(company) {
+        annual_rate.saturating_add(COVENANT_PENALTY_RATE)
+    } else {
+        annual_rate
+    }
+}
+
+fn weekly_interest(balance: Money, annual_rate: Percent) -> Money {
+    Money::new(balance.percent(annual_rate.get() as i64).get() / WEEKS_PER_YEAR)
+}
+
+/// Interest on all the company's loans and its credit line for one week.
+pub fn interest_due(company: &Company) -> Money {
+    let debt = &company.debt;
+    debt.loans
+        .iter()
+        .map(|loan| weekly_interest(loan.balance, interest_rate(company, loan.annual_rate)))
+        .sum::<Money>()
+        + weekly_interest(debt.credit_drawn, interest_rate(company, CREDIT_LINE_RATE))
+}
+
+/// What the company can still borrow on term loans.
+pub fn borrowing_capacity(company: &Company) -> Money {
+    let owed: Money = company.debt.loans.iter().map(|loan| loan.balance).sum();
+    (company.valuation().percent(BORROWING_LIMIT_PERCENT) - owed).max(Money::ZERO)
+}
+
+/// What is left to draw on the credit line, nothing while the covenants are broken.
+pub fn credit_available(company: &Company) -> Money {
+    if covenants_broken(company) {
+        return Money::ZERO;
+    }
+    (company.valuation().percent(CREDIT_LINE_PERCENT) - company.debt.credit_drawn).max(Money::ZERO)
+}
+
+/// A new loan for the company, told apart from every loan it took before.
+pub fn new_loan(
+    game_state: &GameState,
+    company: &Company,
+    principal: Money,
+    annual_rate: Percent,
+    weeks: u16,
+) -> Loan {
+    let company_seed = company.id.as_u64_pair().0;
+    Loan {
+        id: deterministic_uuid(
+            game_state.seed ^ LOAN_STREAM ^ company_seed,
+            company.debt.loans_taken as u64,
+        ),
+        principal,
+        balance: principal,
+        annual_rate,
+        weeks,
+    }
+}
+
+pub fn is_finance(organization: &Organization) -> bool {
+    organization.organization_type == OrganizationType::Finance
+}
+
+/// The week's debt service for every company still trading: interest, the installments due on
+/// its loans, and what happens to a company that keeps starting weeks in the red. After
+/// `INSOLVENCY_GRACE_WEEKS` the lenders restructure its debt into a single expensive loan,
+/// covering the shortfall and cutting its budgets. Once restructured, the next time is
+/// bankruptcy.
+pub fn debt_updates(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut companies: Vec<&Company> = game_state
+        .companies
+        .values()
+        .filter(|company| !company.debt.bankrupt)
+        .collect();
+    companies.sort_by_key(|company| company.id);
+
+    let mut events = vec![];
+    for company in companies {
+        let company_id = company.id;
+        let debt = &company.debt;
+
+        // The lenders have run out of patience, whatever was due this week goes with the rest.
+        let insolvent = company.financials.actual_cash.is_negative();
+        let weeks_insolvent = if insolvent {
+            debt.weeks_insolvent + 1
+        } else {
+            0
+        };
+        if weeks_insolvent >= INSOLVENCY_GRACE_WEEKS {
+            if debt.restructured {
+                events.push(InternalEvent::DeclareBankruptcy { company_id });
+            } else {
+                events.extend(restructuring(game_state, company));
+            }
+            continue;
+        }
+
+        let interest = interest_due(company);
+        if interest > Money::ZERO {
+            events.push(InternalEvent::PayInterest {
+                company_id,
+                amount: interest,
+            });
+        }
+        for loan in &debt.loans {
+            events.push(InternalEvent::RepayLoan {
+                company_id,
+                loan_id: loan.id,
+                amount: loan.installment(),
+            });
+        }
+        if weeks_insolvent != debt.weeks_insolvent {
+            events.push(InternalEvent::SetCompanyInsolvency {
+                company_id,
+                weeks_insolvent,
+            });
+        }
+    }
+    events
+}
+
+fn restructuring(game_state: &GameState, company: &Company) -> Vec<InternalEvent> {
+    let company_id = company.id;
+    let shortfall = -company.financials.actual_cash;
+    let loan = new_loan(
+        game_state,
+        company,
+        company.debt.outstanding() + shortfall,
+        RESTRUCTURED_RATE,
+        RESTRUCTURED_WEEKS,
+    );
+
+    let mut events = vec![
+        InternalEvent::RestructureDebt { company_id, loan },
+        InternalEvent::AdjustCompanyCash {
+            company_id,
+            amount: shortfall,
+        },
+        InternalEvent::AdjustCompanyReputation {
+            company_id,
+            amount: -RESTRUCTURING_REPUTATION_LOSS,
+        },
+    ];
+
+    let mut organizations: Vec<&Organization> = game_state
+        .organizations
+        .values()
+        .filter(|organization| organization.company_relation.entity_id == company_id)
+        .collect();
+    organizations.sort_by_key(|organization| organization.id);
+    for organization in organizations {
+        events.push(InternalEvent::SetOrgBudget {
+            organization_id: organization.id,
+            budget: Budget {
+                marketing: Money::ZERO,
+                rnd: Money::ZERO,
+                training: Money::ZERO,
+            },
+        });
+    }
+    events
+}
+
+/// One of the company's loans.
+pub fn loan_of<'a>(company: &'a Company, loan_id: &Uuid) -> Option<&'a Loan> {
+    company.debt.loans.iter().find(|loan| loan.id == *loan_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{World, booked_to};
+    use shared::WorldGenerationParameters;
+
+    fn world_and_company() -> (World, Uuid) {
+        let world = World::generate(&WorldGenerationParameters::default());
+        let company_id = world.game_state().player_company_id().unwrap();
+        (world, company_id)
+    }
+
+    fn company_events(events: Vec<InternalEvent>, company_id: Uuid) -> Vec<InternalEvent> {
+        events
+            .into_iter()
+            .filter(|event| match event {
+                InternalEvent::PayInterest { company_id: id, .. }
+                | InternalEvent::RepayLoan { company_id: id, .. }
+                | InternalEvent::SetCompanyInsolvency { company_id: id, .. }
+                | InternalEvent::RestructureDebt { company_id: id, .. }
+                | InternalEvent::AdjustCompanyCash { company_id: id, .. }
+                | InternalEvent::AdjustCompanyReputation { company_id: id, .. }
+                | InternalEvent::DeclareBankruptcy { company_id: id } => *id == company_id,
+                _ => false,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn the_operators_reputation_hits_break_the_company_covenants() {
+        let (mut world, company_id) = world_and_company();
+        assert!(!covenants_broken(
+            &world.game_state().companies[&company_id]
+        ));
+
+        let hit = booked_to(
+            world.game_state(),
+            &company_id,
+            InternalEvent::DecrementReputation {
+                amount: -COVENANT_MIN_REPUTATION + 1,
+            },
+        );
+        world.apply_event(&hit);
+
+        let company = &world.game_state().companies[&company_id];
+        assert!(covenants_broken(company));
+        assert_eq!(credit_available(company), Money::ZERO);
+        assert_eq!(
+            interest_rate(company, LOAN_RATE),
+            LOAN_RATE.saturating_add(COVENANT_PENALTY_RATE)
+        );
+    }
+
+    #[test]
+    fn interest_is_a_weekly_share_of_the_yearly_rate() {
+        let (mut world, company_id) = world_and_company();
+        let game_state = &mut world.instance_game.game_state;
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.debt.loans = vec![Loan {
+            id: Uuid::nil(),
+            principal: Money::new(520_000),
+            balance: Money::new(520_000),
+            annual_rate: LOAN_RATE,
+            weeks: 52,
+        }];
+        company.debt.credit_drawn = Money::new(52_000);
+
+        // 8% of 520,000 and 12% of 52,000, over 52 weeks.
+        assert_eq!(interest_due(company), Money::new(800 + 120));
+
+        company.perception.reputation = COVENANT_MIN_REPUTATION - 1;
+        // 14% and 18% with the covenant penalty.
+        assert_eq!(interest_due(company), Money::new(1_400 + 180));
+    }
+
+    #[test]
+    fn insolvent_weeks_are_counted_and_forgiven() {
+        let (mut world, company_id) = world_and_company();
+        let game_state = &mut world.instance_game.game_state;
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.financials.actual_cash = Money::new(-1);
+        company.debt.weeks_insolvent = 1;
+
+        let events = company_events(debt_updates(game_state), company_id);
+        assert!(matches!(
+            events.as_slice(),
+            [InternalEvent::SetCompanyInsolvency {
+                weeks_insolvent: 2,
+                ..
+            }]
+        ));
+
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.financials.actual_cash = Money::new(1);
+        let events = company_events(debt_updates(game_state), company_id);
+        assert!(matches!(
+            events.as_slice(),
+            [InternalEvent::SetCompanyInsolvency {
+                weeks_insolvent: 0,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn the_lenders_restructure_once_then_declare_bankruptcy() {
+        let (mut world, company_id) = world_and_company();
+        let game_state = &mut world.instance_game.game_state;
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.financials.actual_cash = Money::new(-10_000);
+        company.debt.credit_drawn = Money::new(5_000);
+        company.debt.weeks_insolvent = INSOLVENCY_GRACE_WEEKS - 1;
+
+        let events = company_events(debt_updates(game_state), company_id);
+        let [
+            InternalEvent::RestructureDebt { loan, .. },
+            InternalEvent::AdjustCompanyCash { amount, .. },
+            InternalEvent::AdjustCompanyReputation { .. },
+        ] = events.as_slice()
+        else {
+            panic!("expected a restructuring, got {events:?}");
+        };
+        assert_eq!(loan.principal, Money::new(15_000));
+        assert_eq!(loan.annual_rate, RESTRUCTURED_RATE);
+        assert_eq!(*amount, Money::new(10_000));
+
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.debt.restructured = true;
+        let events = company_events(debt_updates(game_state), company_id);
+        assert!(matches!(
+            events.as_slice(),
+            [InternalEvent::DeclareBankruptcy { .. }]
+        ));
+    }
+}
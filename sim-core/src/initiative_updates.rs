@@ -0,0 +1,222 @@
+use crate::booked_to;
+use shared::{
+    EmployeeFlag, Entity, GameState, Initiative, InitiativeKind, InternalEvent, MAX_LEVEL, Money,
+    Organization, OrganizationType,
+};
+use uuid::Uuid;
+
+/// Share of an initiative's budget paid at launch, the rest is spread over its weeks.
+const UPFRONT_PERCENT: i64 = 25;
+
+/// Share of the unused part of the upfront cost refunded when an initiative is cancelled.
+const CANCEL_REFUND_PERCENT: i64 = 50;
+
+/// Every this much budget makes an initiative's completion effect one step stronger.
+const BUDGET_PER_STRENGTH: i64 = 10_000;
+const MAX_STRENGTH: i64 = 3;
+
+/// Splits a budget into the upfront and weekly costs of a new initiative.
+pub fn plan_initiative(kind: InitiativeKind, weeks: u16, budget: Money) -> Initiative {
+    let upfront_cost = budget.percent(UPFRONT_PERCENT);
+    Initiative {
+        kind,
+        weeks,
+        weeks_remaining: weeks,
+        upfront_cost,
+        weekly_cost: Money::new((budget - upfront_cost).get() / weeks.max(1) as i64),
+    }
+}
+
+/// Money returned when an initiative is cancelled, half of the upfront cost for the weeks
+/// that will not run. Weekly costs stop with the initiative.
+pub fn cancellation_refund(initiative: &Initiative) -> Money {
+    let unused = (initiative.upfront_cost * initiative.weeks_remaining as i64).get()
+        / initiative.weeks.max(1) as i64;
+    Money::new(unused).percent(CANCEL_REFUND_PERCENT)
+}
+
+/// Weekly progress of every organization's initiatives. Running initiatives cost their weekly
+/// share and nudge the organization along; the ones that finish have an effect that depends
+/// on the kind of organization that ran them.
+pub fn initiative_updates(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut events = vec![];
+
+    let mut organizations: Vec<(&Uuid, &Organization)> = game_state
+        .organizations
+        .iter()
+        .filter(|(_, organization)| !organization.initiatives.is_empty())
+        .collect();
+    organizations.sort_by_key(|(organization_id, _)| **organization_id);
+
+    for (organization_id, organization) in organizations {
+        let weekly_cost: Money = organization
+            .initiatives
+            .iter()
+            .map(|initiative| initiative.weekly_cost)
+            .sum();
+        if weekly_cost > Money::ZERO {
+            events.push(booked_to(
+                game_state,
+                &organization.company_relation.entity_id,
+                InternalEvent::DecrementMoney {
+                    amount: weekly_cost,
+                },
+            ));
+        }
+
+        for initiative in &organization.initiatives {
+            match initiative.kind {
+                InitiativeKind::Marketing => {
+                    events.push(InternalEvent::IncrementOrgPublicOpinion {
+                        organization_id: *organization_id,
+                        amount: 2,
+                    })
+                }
+                InitiativeKind::RnD => events.push(InternalEvent::IncrementOrgReputation {
+                    organization_id: *organization_id,
+                    amount: 1,
+                }),
+                InitiativeKind::Training => {}
+            }
+
+            if initiative.weeks_remaining <= 1 {
+                events.extend(completion_effects(game_state, organization, initiative));
+            }
+        }
+
+        // Only the initiatives paid for above, one launched since then waits for next week.
+        events.push(InternalEvent::TickOrgInitiatives {
+            organization_id: *organization_id,
+            kinds: organization
+                .initiatives
+                .iter()
+                .map(|initiative| initiative.kind)
+                .collect(),
+        });
+    }
+
+    events
+}
+
+/// What a finished initiative does for the organization. Marketing pays off most where the
+/// organization faces customers, R&D where it builds things, and training levels up people in
+/// skilled organizations while it mostly lifts spirits and cures burnout on the floor.
+fn completion_effects(
+    game_state: &GameState,
+    organization: &Organization,
+    initiative: &Initiative,
+) -> Vec<InternalEvent> {
+    use OrganizationType::*;
+
+    let organization_id = organization.id;
+    let budget = initiative.upfront_cost + initiative.weekly_cost * initiative.weeks as i64;
+    let strength = (1 + budget.get().max(0) / BUDGET_PER_STRENGTH).min(MAX_STRENGTH) as u16;
+
+    let mut events = vec![];
+    match initiative.kind {
+        InitiativeKind::Marketing => {
+            let amount = match organization.organization_type {
+                RetailSite | MarketingTeam | ContentCreation | SupportCenter => 5,
+                _ => 2,
+            };
+            events.push(InternalEvent::IncrementOrgPublicOpinion {
+                organization_id,
+                amount: amount * strength as i16,
+            });
+        }
+        InitiativeKind::RnD => {
+            let amount = match organization.organization_type {
+                RnD | ProductManagement | DataAnalytics | ITInfrastructure => 5,
+                _ => 2,
+            };
+            events.push(InternalEvent::IncrementOrgReputation {
+                organization_id,
+                amount: amount * strength as i16,
+            });
+        }
+        InitiativeKind::Training => {
+            let mut employees: Vec<&Entity> = game_state
+                .entities
+                .values()
+                .filter(|entity| {
+                    entity
+                        .employment
+                        .as_ref()
+                        .is_some_and(|employment| employment.organization_id == organization_id)
+                })
+                .collect();
+            employees.sort_by_key(|entity| entity.id);
+
+            for employee in employees {
+                let Some(employment) = employee.employment.as_ref() else {
+                    continue;
+                };
+                let satisfaction = match organization.organization_type {
+                    RnD | ProductManagement | DataAnalytics | ITInfrastructure | Finance
+                    | Legal => {
+                        if employment.level < MAX_LEVEL {
+                            events.push(InternalEvent::SetEmployeeLevel {
+                                employee_id: employee.id,
+                                level: employment.level + 1,
+                            });
+                        }
+                        2
+                    }
+                    Warehouse | LogisticsHub | RetailSite | SupportCenter => {
+                        if employment.employee_flags.contains(&EmployeeFlag::BurnedOut) {
+                            events.push(InternalEvent::ClearEmployeeFlag {
+                                employee_id: employee.id,
+                                flag: EmployeeFlag::BurnedOut,
+                            });
+                        }
+                        5
+                    }
+                    MarketingTeam | ContentCreation | HR => 3,
+                };
+                events.push(InternalEvent::IncrementEmployeeSatisfaction {
+                    employee_id: employee.id,
+                    amount: satisfaction * strength,
+                });
+            }
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::World;
+    use shared::{ClientActionCommand, InitiativeKind, Money, WorldGenerationParameters};
+
+    #[test]
+    fn initiative_launched_in_the_same_tick_as_end_turn_survives() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let game_state = world.game_state();
+        let company_id = game_state.player_company_id().unwrap();
+        let organization_id = game_state
+            .organizations
+            .values()
+            .filter(|organization| organization.company_relation.entity_id == company_id)
+            .map(|organization| organization.id)
+            .min()
+            .unwrap();
+
+        // Both commands are decided against the state at the start of the tick.
+        let mut events = world
+            .process_command(ClientActionCommand::LaunchInitiative {
+                organization_id,
+                kind: InitiativeKind::Marketing,
+                weeks: 4,
+                budget: Money::new(20_000),
+            })
+            .unwrap();
+        events.extend(world.process_command(ClientActionCommand::EndTurn).unwrap());
+        for event in &events {
+            world.apply_event(event);
+        }
+
+        let initiatives = &world.game_state().organizations[&organization_id].initiatives;
+        assert_eq!(initiatives.len(), 1);
+        assert_eq!(initiatives[0].weeks_remaining, 4);
+    }
+}
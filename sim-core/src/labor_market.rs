@@ -0,0 +1,124 @@
+use crate::deterministic_randomization::{
+    generate_human_type_for_organization_role, generate_realistic_name, human_type_fit_for_role,
+    roles_suited_to_human_type,
+};
+use crate::deterministic_uuid;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use shared::{
+    Candidate, Entity, EntityType, GameState, HIREABLE_ROLES, HumanType, InternalEvent, Money,
+    OrganizationRole, Origin,
+};
+
+/// Mixed into the world seed so job seekers draw from their own random stream.
+const LABOR_MARKET_STREAM: u64 = 0x6a6f_6273;
+
+const NEW_CANDIDATES_PER_WEEK: std::ops::RangeInclusive<usize> = 1..=3;
+const WEEKS_ON_MARKET: std::ops::RangeInclusive<u16> = 3..=6;
+const MAX_PREFERRED_ROLES: usize = 3;
+
+/// Candidates take a role they fit at least this well, or one they asked for, at their asking salary.
+const GOOD_FIT_WEIGHT: u32 = 30;
+/// Extra pay, in percent, a candidate wants for every point of fit short of a good fit.
+const MISFIT_PREMIUM_PERCENT: u32 = 2;
+/// Extra pay, in percent, a candidate wants for every level they are hired below their own.
+const UNDERLEVEL_PREMIUM_PERCENT: u32 = 10;
+/// How far above their own level a candidate can be hired.
+pub const MAX_LEVEL_STRETCH: u16 = 2;
+/// A signing bonus counts towards an offer as if it were spread over this many weeks of pay.
+pub const SIGNING_BONUS_WEEKS: u16 = 10;
+
+/// Weekly turnover of the labor market: candidates whose listing ran out leave, and a few
+/// new job seekers arrive. Everything about them is derived from the world seed and the week.
+pub fn labor_market_updates(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut events = vec![];
+
+    let mut expired: Vec<_> = game_state
+        .candidates
+        .values()
+        .filter(|candidate| candidate.listed_until_week <= game_state.week)
+        .map(|candidate| candidate.entity_id)
+        .collect();
+    expired.sort();
+    for entity_id in expired {
+        events.push(InternalEvent::DelistCandidate { entity_id });
+    }
+
+    let week_seed = game_state.seed ^ LABOR_MARKET_STREAM;
+    let mut rng = StdRng::seed_from_u64(week_seed.wrapping_add(game_state.week as u64));
+    let arrivals = rng.gen_range(NEW_CANDIDATES_PER_WEEK);
+
+    for index in 0..arrivals {
+        let role = *HIREABLE_ROLES.choose(&mut rng).unwrap();
+        let human_type = generate_human_type_for_organization_role(&role, &mut rng).unwrap();
+
+        let mut preferred_roles = vec![role];
+        preferred_roles.extend(
+            roles_suited_to_human_type(&human_type)
+                .into_iter()
+                .filter(|suited| *suited != role)
+                .take(MAX_PREFERRED_ROLES - 1),
+        );
+
+        let level = rng.gen_range(1..=15);
+        let asking_salary = ((level as f32) * rng.gen_range(1.5..=1.9)) as u16;
+
+        let entity_id =
+            deterministic_uuid(week_seed, ((game_state.week as u64) << 8) + index as u64);
+        let entity = Entity {
+            id: entity_id,
+            entity_type: EntityType::Human(human_type),
+            name: generate_realistic_name(human_type, role, &mut rng),
+            employment: None,
+            owner: None,
+            origin: Origin {
+                week_of_birth: game_state.week as i16 - rng.gen_range(1_100..=3_000),
+            },
+            flags: vec![],
+        };
+
+        events.push(InternalEvent::ListCandidate {
+            entity,
+            candidate: Candidate {
+                entity_id,
+                level,
+                asking_salary: Money::from(asking_salary.max(1)),
+                preferred_roles,
+                listed_until_week: game_state.week + rng.gen_range(WEEKS_ON_MARKET),
+            },
+        });
+    }
+
+    events
+}
+
+/// The lowest weekly salary a candidate takes for the role at the given level, or `None` when
+/// the role does not suit their type at all. Roles they fit poorly and levels below their own
+/// cost extra, a higher level has to pay the middle of the role's pay band for it.
+pub fn minimum_acceptable_salary(
+    candidate: &Candidate,
+    human_type: &HumanType,
+    role: &OrganizationRole,
+    level: u16,
+) -> Option<Money> {
+    let fit = if candidate.preferred_roles.contains(role) {
+        GOOD_FIT_WEIGHT
+    } else {
+        human_type_fit_for_role(role, human_type)
+    };
+    if fit == 0 {
+        return None;
+    }
+
+    let fair_salary = role.pay_band(level).midpoint.max(candidate.asking_salary);
+    let premium = (GOOD_FIT_WEIGHT - fit.min(GOOD_FIT_WEIGHT)) * MISFIT_PREMIUM_PERCENT
+        + candidate.level.saturating_sub(level) as u32 * UNDERLEVEL_PREMIUM_PERCENT;
+    Some(fair_salary.percent(100 + premium as i64))
+}
+
+/// What an offer is worth to a candidate per week, the salary plus the signing bonus spread
+/// over `SIGNING_BONUS_WEEKS`.
+pub fn offer_value(salary_offer: Money, signing_bonus: Option<Money>) -> Money {
+    salary_offer + Money::new(signing_bonus.unwrap_or_default().get() / SIGNING_BONUS_WEEKS as i64)
+}
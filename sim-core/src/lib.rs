@@ -0,0 +1,44 @@
+mod apply_event;
+mod board;
+mod career_ladder;
+mod command_rejection;
+mod company_updates;
+mod compensation;
+mod corporate_events;
+mod create_empty_world_state;
+mod deterministic_randomization;
+mod ecommerce;
+mod employee_flags;
+mod finance;
+mod initiative_updates;
+mod labor_market;
+mod market;
+mod morale_updates;
+mod organization_updates;
+mod process_command;
+mod reports;
+mod rivals;
+mod simulation;
+mod world;
+
+pub use apply_event::*;
+pub use board::*;
+pub use career_ladder::*;
+pub use command_rejection::*;
+pub use company_updates::*;
+pub use compensation::*;
+pub use corporate_events::*;
+pub use create_empty_world_state::*;
+pub use ecommerce::*;
+pub use employee_flags::*;
+pub use finance::*;
+pub use initiative_updates::*;
+pub use labor_market::*;
+pub use market::*;
+pub use morale_updates::*;
+pub use organization_updates::*;
+pub use process_command::*;
+pub use reports::*;
+pub use rivals::*;
+pub use simulation::*;
+pub use world::*;
@@ -0,0 +1,314 @@
+use crate::{
+    BASE_WEEKLY_ORDERS, ORDER_VALUE, effective_productivity, fulfilment_contribution,
+    seasonal_percent, third_party_capacity,
+};
+use shared::{
+    Company, CompanyType, FulfilmentStage, GameState, InitiativeKind, InternalEvent, Money,
+    OrderBook, Organization,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Appeal of a company nobody has an opinion about yet.
+const BASE_APPEAL: i32 = 100;
+
+/// Even the least liked company keeps a few customers.
+const MIN_APPEAL: i32 = 10;
+
+/// Weekly marketing spend that buys a point of appeal.
+const MARKETING_PER_APPEAL: i64 = 100;
+
+/// Productivity counts towards fulfilment up to this much per employee.
+const FULL_PRODUCTIVITY: u32 = 100;
+
+/// A company's trading week: the orders its customers placed, how many it got out of the door
+/// and what they earned.
+#[derive(Clone, Debug)]
+pub struct CompanySales {
+    pub company_id: Uuid,
+    pub orders: OrderBook,
+    pub revenue: Money,
+}
+
+/// The company an organization belongs to.
+pub fn company_of(game_state: &GameState, organization_id: &Uuid) -> Option<Uuid> {
+    game_state
+        .organizations
+        .get(organization_id)
+        .map(|organization| organization.company_relation.entity_id)
+}
+
+/// How strongly customers are drawn to a company: its own perception plus its organizations'
+/// on average, for the operator's company the CEO's standing too, and what it spends on
+/// marketing. Customers go by public opinion more than by reputation.
+pub fn company_appeal(game_state: &GameState, company_id: &Uuid) -> i32 {
+    let Some(company) = game_state.companies.get(company_id) else {
+        return MIN_APPEAL;
+    };
+    let standing =
+        |public_opinion: i16, reputation: i16| 2 * public_opinion as i32 + reputation as i32;
+
+    let (organization_standing, organizations) = game_state
+        .organizations
+        .values()
+        .filter(|organization| organization.company_relation.entity_id == *company_id)
+        .fold((0, 0), |(sum, count), organization| {
+            let perception = &organization.perception;
+            (
+                sum + standing(perception.public_opinion, perception.reputation),
+                count + 1,
+            )
+        });
+
+    let ceo_standing = match company.rival {
+        Some(_) => 0,
+        None => game_state
+            .players
+            .first()
+            .map(|player| {
+                standing(
+                    player.perception.public_opinion,
+                    player.perception.reputation,
+                )
+            })
+            .unwrap_or_default(),
+    };
+
+    let marketing =
+        i32::try_from(marketing_spend(game_state, company_id).get() / MARKETING_PER_APPEAL)
+            .unwrap_or(i32::MAX);
+    let appeal = BASE_APPEAL
+        + standing(
+            company.perception.public_opinion,
+            company.perception.reputation,
+        )
+        + organization_standing
+            .checked_div(organizations)
+            .unwrap_or(0)
+        + ceo_standing;
+    appeal.saturating_add(marketing).max(MIN_APPEAL)
+}
+
+/// What a company puts into marketing each week, its organizations' budgets and running
+/// marketing initiatives.
+pub fn marketing_spend(game_state: &GameState, company_id: &Uuid) -> Money {
+    game_state
+        .organizations
+        .values()
+        .filter(|organization| organization.company_relation.entity_id == *company_id)
+        .map(|organization| {
+            organization.budget.marketing
+                + organization
+                    .initiatives
+                    .iter()
+                    .filter(|initiative| initiative.kind == InitiativeKind::Marketing)
+                    .map(|initiative| initiative.weekly_cost)
+                    .sum::<Money>()
+        })
+        .sum()
+}
+
+/// Orders an organization's staff can move through each stage this week.
+pub fn organization_capacity(
+    game_state: &GameState,
+    organization: &Organization,
+) -> Vec<(FulfilmentStage, u32)> {
+    let contribution = fulfilment_contribution(organization.organization_type);
+    if contribution.is_empty() {
+        return vec![];
+    }
+    let productivity: u32 = game_state
+        .entities
+        .values()
+        .filter_map(|entity| entity.employment.as_ref())
+        .filter(|employment| employment.organization_id == organization.id)
+        .map(|employment| effective_productivity(employment).min(FULL_PRODUCTIVITY))
+        .sum();
+    contribution
+        .iter()
+        .map(|(stage, orders)| (*stage, productivity * orders / FULL_PRODUCTIVITY))
+        .collect()
+}
+
+/// Orders a company can fulfil per stage, its own organizations plus contractors.
+pub fn fulfilment_capacity(
+    game_state: &GameState,
+    company_id: &Uuid,
+) -> Vec<(FulfilmentStage, u32)> {
+    let mut capacity: Vec<(FulfilmentStage, u32)> = [
+        FulfilmentStage::Picking,
+        FulfilmentStage::Shipping,
+        FulfilmentStage::Support,
+    ]
+    .into_iter()
+    .map(|stage| (stage, third_party_capacity(stage)))
+    .collect();
+    for organization in game_state
+        .organizations
+        .values()
+        .filter(|organization| organization.company_relation.entity_id == *company_id)
+    {
+        for (stage, orders) in organization_capacity(game_state, organization) {
+            if let Some((_, total)) = capacity.iter_mut().find(|(known, _)| *known == stage) {
+                *total += orders;
+            }
+        }
+    }
+    capacity
+}
+
+/// Orders an average company of the type takes in an ordinary week, and what each earns.
+fn demand_model(company_type: &CompanyType) -> (u32, Money) {
+    match company_type {
+        CompanyType::ECommerce => (BASE_WEEKLY_ORDERS, ORDER_VALUE),
+    }
+}
+
+/// The week's trading for every company that is not bankrupt, in company id order. Customers place the season's orders with the
+/// companies in proportion to their appeal; each company fulfils what its weakest stage lets
+/// through and earns on those orders only.
+pub fn market_sales(game_state: &GameState) -> Vec<CompanySales> {
+    let mut companies: Vec<(&Company, u64)> = game_state
+        .companies
+        .values()
+        .filter(|company| !company.debt.bankrupt)
+        .map(|company| (company, company_appeal(game_state, &company.id) as u64))
+        .collect();
+    companies.sort_by_key(|(company, _)| company.id);
+
+    let total_appeal: u64 = companies.iter().map(|(_, appeal)| appeal).sum();
+    let total_orders: u64 = companies
+        .iter()
+        .map(|(company, _)| demand_model(&company.company_type).0 as u64)
+        .sum::<u64>()
+        * seasonal_percent(game_state.week) as u64
+        / 100;
+
+    companies
+        .into_iter()
+        .map(|(company, appeal)| {
+            let placed = (total_orders * appeal)
+                .checked_div(total_appeal)
+                .unwrap_or(0) as u32;
+            let (stage, capacity) = fulfilment_capacity(game_state, &company.id)
+                .into_iter()
+                .min_by_key(|(_, capacity)| *capacity)
+                .unwrap();
+            let fulfilled = placed.min(capacity);
+            let (_, order_value) = demand_model(&company.company_type);
+            CompanySales {
+                company_id: company.id,
+                orders: OrderBook {
+                    placed,
+                    fulfilled,
+                    bottleneck: (fulfilled < placed).then_some(stage),
+                },
+                revenue: order_value * fulfilled as i64,
+            }
+        })
+        .collect()
+}
+
+/// Each organization's part of its company's revenue, by the orders it moved. Organizations
+/// that do not handle orders earn nothing.
+pub fn organization_revenues(game_state: &GameState) -> HashMap<Uuid, Money> {
+    let capacities: HashMap<Uuid, u32> = game_state
+        .organizations
+        .values()
+        .map(|organization| {
+            let orders = organization_capacity(game_state, organization)
+                .into_iter()
+                .map(|(_, orders)| orders)
+                .sum();
+            (organization.id, orders)
+        })
+        .collect();
+
+    let mut revenues = HashMap::new();
+    for sales in market_sales(game_state) {
+        let organizations: Vec<&Organization> = game_state
+            .organizations
+            .values()
+            .filter(|organization| organization.company_relation.entity_id == sales.company_id)
+            .collect();
+        let company_capacity: i64 = organizations
+            .iter()
+            .map(|organization| capacities[&organization.id] as i64)
+            .sum();
+        for organization in organizations {
+            let revenue = (sales.revenue * capacities[&organization.id] as i64)
+                .get()
+                .checked_div(company_capacity)
+                .unwrap_or(0);
+            revenues.insert(organization.id, Money::new(revenue));
+        }
+    }
+    revenues
+}
+
+/// What a company pays its staff each week.
+pub fn payroll(game_state: &GameState, company_id: &Uuid) -> Money {
+    game_state
+        .entities
+        .values()
+        .filter_map(|entity| entity.employment.as_ref())
+        .filter(|employment| {
+            company_of(game_state, &employment.organization_id) == Some(*company_id)
+        })
+        .map(|employment| employment.salary)
+        .sum()
+}
+
+/// What a company's organizations spend from their budgets each week.
+pub fn organization_budgets(game_state: &GameState, company_id: &Uuid) -> Money {
+    game_state
+        .organizations
+        .values()
+        .filter(|organization| organization.company_relation.entity_id == *company_id)
+        .map(|organization| {
+            let budget = &organization.budget;
+            budget.marketing + budget.rnd + budget.training
+        })
+        .sum()
+}
+
+/// Cash a company has to spend.
+pub fn company_cash(game_state: &GameState, company_id: &Uuid) -> Money {
+    game_state
+        .companies
+        .get(company_id)
+        .map(|company| company.financials.actual_cash)
+        .unwrap_or_default()
+}
+
+/// An event as it lands on a company's books. Reputation is always the company's own; money
+/// is written to the operator's company as it is, and to a rival's books as a cash adjustment.
+pub fn booked_to(
+    game_state: &GameState,
+    company_id: &Uuid,
+    internal_event: InternalEvent,
+) -> InternalEvent {
+    let is_rival = game_state
+        .companies
+        .get(company_id)
+        .is_some_and(|company| company.rival.is_some());
+
+    let company_id = *company_id;
+    match internal_event {
+        InternalEvent::IncrementReputation { amount } => {
+            InternalEvent::AdjustCompanyReputation { company_id, amount }
+        }
+        InternalEvent::DecrementReputation { amount } => InternalEvent::AdjustCompanyReputation {
+            company_id,
+            amount: amount.saturating_neg(),
+        },
+        InternalEvent::IncrementMoney { amount } if is_rival => {
+            InternalEvent::AdjustCompanyCash { company_id, amount }
+        }
+        InternalEvent::DecrementMoney { amount } if is_rival => InternalEvent::AdjustCompanyCash {
+            company_id,
+            amount: -amount,
+        },
+        internal_event => internal_event,
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    BURNOUT_WEEKS, LOYAL_RAISES, LOYAL_TENURE_WEEKS, adjusted_quit_chance, expected_salary,
+    raise_expectation,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use shared::{
+    DepartureReason, EmployeeFlag, Employment, EmploymentStatus, Entity, GameState, InternalEvent,
+    Organization, Percent,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Satisfaction below which an employee may quit.
+pub const QUIT_THRESHOLD: u16 = 30;
+
+/// Weekly chance in percent that an employee with no satisfaction left quits. It falls off
+/// linearly to nothing at `QUIT_THRESHOLD`.
+const MAX_QUIT_CHANCE_PERCENT: u32 = 50;
+
+/// Staff an organization needs before anyone feels overworked, plus more per initiative.
+const COMFORTABLE_HEADCOUNT: usize = 5;
+const HEADCOUNT_PER_INITIATIVE: usize = 2;
+
+/// VPs at or above this level are good to work for.
+const GOOD_VP_LEVEL: u16 = 18;
+
+/// Weekly morale drift of every employee and the departures it leads to. Pay against level,
+/// workload, the VP and the organization's perception each pull satisfaction up or down a
+/// little. Employees below `QUIT_THRESHOLD` may quit, giving the factor that pulled hardest
+/// down as their reason. Tenure is counted and employee flags are set or cleared here too.
+pub fn morale_updates(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut events = vec![];
+
+    let mut headcounts: HashMap<Uuid, usize> = HashMap::new();
+    for employment in game_state
+        .entities
+        .values()
+        .filter_map(|entity| entity.employment.as_ref())
+    {
+        *headcounts.entry(employment.organization_id).or_default() += 1;
+    }
+
+    // Departures are recorded in order, so go through employees in a fixed order.
+    let mut employees: Vec<(&Entity, &Employment)> = game_state
+        .entities
+        .values()
+        .filter_map(|entity| Some((entity, entity.employment.as_ref()?)))
+        .collect();
+    employees.sort_by_key(|(entity, _)| entity.id);
+
+    for (employee, employment) in employees {
+        let Some(organization) = game_state.organizations.get(&employment.organization_id) else {
+            continue;
+        };
+        let headcount = headcounts
+            .get(&employment.organization_id)
+            .copied()
+            .unwrap_or_default();
+
+        let overworked = is_overworked(organization, headcount);
+        let underpaid = is_underpaid(employment);
+        events.push(InternalEvent::RecordEmploymentWeek {
+            employee_id: employee.id,
+            overworked,
+        });
+        events.extend(flag_updates(employee, employment, overworked, underpaid));
+
+        let factors = morale_factors(game_state, employee, employment, organization, overworked);
+        let drift: i32 = factors.iter().map(|(delta, _)| delta).sum();
+
+        if drift > 0 {
+            events.push(InternalEvent::IncrementEmployeeSatisfaction {
+                employee_id: employee.id,
+                amount: drift as u16,
+            });
+        } else if drift < 0 {
+            events.push(InternalEvent::DecrementEmployeeSatisfaction {
+                employee_id: employee.id,
+                amount: drift.unsigned_abs() as u16,
+            });
+        }
+
+        let satisfaction =
+            Percent::new((employment.satisfaction.get() as i32 + drift).max(0) as u16);
+        if !quits(game_state.week, &employee.id, employment, satisfaction) {
+            continue;
+        }
+
+        let reason = factors
+            .iter()
+            .filter(|(delta, _)| *delta < 0)
+            .min_by_key(|(delta, _)| *delta)
+            .map(|(_, reason)| *reason)
+            .unwrap_or(DepartureReason::Underpaid);
+
+        events.push(InternalEvent::EmployeeDeparted {
+            employee_id: employee.id,
+            organization_id: organization.id,
+            status: EmploymentStatus::Quit,
+            reason,
+        });
+
+        if organization.vp == Some(employee.id) {
+            events.push(InternalEvent::SetOrgVp {
+                organization_id: organization.id,
+                employee_id: None,
+            });
+        }
+    }
+
+    events
+}
+
+/// Each factor's pull on satisfaction this week, with the reason it would give for leaving.
+fn morale_factors(
+    game_state: &GameState,
+    employee: &Entity,
+    employment: &Employment,
+    organization: &Organization,
+    overworked: bool,
+) -> Vec<(i32, DepartureReason)> {
+    let mut factors = vec![];
+
+    if is_underpaid(employment) {
+        factors.push((-2, DepartureReason::Underpaid));
+    } else if employment.salary * 4 > expected_salary(employment) * 5 {
+        factors.push((1, DepartureReason::Underpaid));
+    }
+
+    if overworked {
+        factors.push((-1, DepartureReason::Overworked));
+    }
+
+    if organization.vp != Some(employee.id) {
+        let vp_level = organization
+            .vp
+            .and_then(|vp_id| game_state.entities.get(&vp_id))
+            .and_then(|vp| vp.employment.as_ref())
+            .map(|vp_employment| vp_employment.level);
+        match vp_level {
+            None => factors.push((-1, DepartureReason::PoorLeadership)),
+            Some(level) if level >= GOOD_VP_LEVEL => {
+                factors.push((1, DepartureReason::PoorLeadership))
+            }
+            Some(_) => {}
+        }
+    }
+
+    let perception =
+        organization.perception.public_opinion as i32 + organization.perception.reputation as i32;
+    if perception < 0 {
+        factors.push((-1, DepartureReason::BadReputation));
+    } else if perception > 10 {
+        factors.push((1, DepartureReason::BadReputation));
+    }
+
+    factors
+}
+
+fn is_overworked(organization: &Organization, headcount: usize) -> bool {
+    headcount < COMFORTABLE_HEADCOUNT + HEADCOUNT_PER_INITIATIVE * organization.initiatives.len()
+}
+
+fn is_underpaid(employment: &Employment) -> bool {
+    employment.salary * 10 < raise_expectation(employment) * 9
+}
+
+/// Burnout follows a run of overworked weeks and lifts once the workload eases, wanting a
+/// raise lasts as long as the pay is below expectations, and loyalty, once earned, stays.
+fn flag_updates(
+    employee: &Entity,
+    employment: &Employment,
+    overworked: bool,
+    underpaid: bool,
+) -> Vec<InternalEvent> {
+    let has = |flag| employment.employee_flags.contains(&flag);
+    let burned_out = overworked && employment.weeks_overworked + 1 >= BURNOUT_WEEKS;
+    let loyal =
+        employment.weeks_employed + 1 >= LOYAL_TENURE_WEEKS && employment.raises >= LOYAL_RAISES;
+
+    let mut events = vec![];
+    for (flag, should_have) in [
+        (
+            EmployeeFlag::BurnedOut,
+            burned_out || (has(EmployeeFlag::BurnedOut) && overworked),
+        ),
+        (EmployeeFlag::WantsRaise, underpaid),
+        (EmployeeFlag::Loyal, loyal || has(EmployeeFlag::Loyal)),
+    ] {
+        if should_have && !has(flag) {
+            events.push(InternalEvent::SetEmployeeFlag {
+                employee_id: employee.id,
+                flag,
+            });
+        } else if !should_have && has(flag) {
+            events.push(InternalEvent::ClearEmployeeFlag {
+                employee_id: employee.id,
+                flag,
+            });
+        }
+    }
+    events
+}
+
+/// Whether an employee quits this week. Seeded from the employee and the week so replays and
+/// re-simulations make the same call.
+fn quits(week: u16, employee_id: &Uuid, employment: &Employment, satisfaction: Percent) -> bool {
+    let satisfaction = satisfaction.get();
+    if satisfaction >= QUIT_THRESHOLD {
+        return false;
+    }
+    let chance = adjusted_quit_chance(
+        employment,
+        MAX_QUIT_CHANCE_PERCENT * (QUIT_THRESHOLD - satisfaction) as u32 / QUIT_THRESHOLD as u32,
+    );
+
+    let (high, low) = employee_id.as_u64_pair();
+    let mut rng = StdRng::seed_from_u64(high ^ low ^ week as u64);
+    rng.gen_range(0..100) < chance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{EmployeeFlag, OrganizationRole, Percent};
+    use uuid::Uuid;
+
+    fn sales_rep(salary_percent: i64, employee_flags: Vec<EmployeeFlag>) -> Employment {
+        let role = OrganizationRole::SalesRep;
+        let level = 20;
+        Employment {
+            organization_id: Uuid::nil(),
+            role,
+            employee_flags,
+            level,
+            salary: role.pay_band(level).midpoint.percent(salary_percent),
+            satisfaction: Percent::new(50),
+            productivity: Percent::new(50),
+            weeks_employed: 0,
+            weeks_overworked: 0,
+            raises: 0,
+        }
+    }
+
+    #[test]
+    fn wanting_a_raise_does_not_raise_the_bar_for_clearing_it() {
+        assert!(is_underpaid(&sales_rep(80, vec![EmployeeFlag::WantsRaise])));
+        assert!(!is_underpaid(&sales_rep(95, vec![])));
+        assert!(!is_underpaid(&sales_rep(
+            95,
+            vec![EmployeeFlag::WantsRaise]
+        )));
+    }
+
+    #[test]
+    fn flags_still_shift_salary_expectations() {
+        assert!(is_underpaid(&sales_rep(95, vec![EmployeeFlag::BurnedOut])));
+        assert!(
+            expected_salary(&sales_rep(100, vec![EmployeeFlag::WantsRaise]))
+                > expected_salary(&sales_rep(100, vec![]))
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::organization_revenues;
+use shared::{Budget, Financials, GameState, InternalEvent, Money};
+
+/// Weekly update of every organization: budget spend and finances. An organization earns its
+/// part of the orders its company fulfilled. Initiatives progress at the end of the week
+/// instead, see `initiative_updates`.
+pub fn organization_updates(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut events = vec![];
+    let revenues = organization_revenues(game_state);
+
+    // In id order, so the events do not depend on map order.
+    let mut organizations: Vec<_> = game_state.organizations.iter().collect();
+    organizations.sort_by_key(|(organization_id, _)| **organization_id);
+
+    for (organization_id, organization) in organizations {
+        let mut employees: Vec<_> = game_state
+            .entities
+            .values()
+            .filter(|entity| {
+                entity
+                    .employment
+                    .as_ref()
+                    .is_some_and(|e| e.organization_id == *organization_id)
+            })
+            .collect();
+        employees.sort_by_key(|employee| employee.id);
+
+        let mut expenses: Money = employees
+            .iter()
+            .filter_map(|e| e.employment.as_ref())
+            .map(|e| e.salary)
+            .sum();
+
+        let Budget {
+            marketing,
+            rnd,
+            training,
+        } = organization.budget;
+
+        let total_budget = marketing + rnd + training;
+        let can_afford = organization.financials.actual_cash >= total_budget;
+
+        if can_afford {
+            expenses += total_budget;
+        }
+
+        if marketing > Money::ZERO {
+            events.push(InternalEvent::IncrementOrgPublicOpinion {
+                organization_id: *organization_id,
+                amount: 1,
+            });
+        }
+
+        if rnd > Money::ZERO {
+            events.push(InternalEvent::IncrementOrgReputation {
+                organization_id: *organization_id,
+                amount: 1,
+            });
+        }
+
+        if training > Money::ZERO {
+            for employee in &employees {
+                events.push(InternalEvent::IncrementEmployeeSatisfaction {
+                    employee_id: employee.id,
+                    amount: 1,
+                });
+            }
+        }
+
+        let income = revenues.get(organization_id).copied().unwrap_or_default();
+        let net_profit = income - expenses;
+
+        events.push(InternalEvent::SetOrgFinancials {
+            organization_id: *organization_id,
+            financials: Financials {
+                this_weeks_income: income,
+                this_weeks_expenses: expenses,
+                this_weeks_net_profit: net_profit,
+                actual_cash: organization.financials.actual_cash + net_profit,
+            },
+        });
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::World;
+    use shared::WorldGenerationParameters;
+    use uuid::Uuid;
+
+    fn organization_expenses(events: &[InternalEvent], organization_id: Uuid) -> Money {
+        events
+            .iter()
+            .find_map(|event| match event {
+                InternalEvent::SetOrgFinancials {
+                    organization_id: id,
+                    financials,
+                } if *id == organization_id => Some(financials.this_weeks_expenses),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn budgets_are_spent_only_when_the_organization_can_afford_them() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let game_state = &mut world.instance_game.game_state;
+        let organization_id = *game_state.organizations.keys().min().unwrap();
+        let payroll: Money = game_state
+            .entities
+            .values()
+            .filter_map(|entity| entity.employment.as_ref())
+            .filter(|employment| employment.organization_id == organization_id)
+            .map(|employment| employment.salary)
+            .sum();
+        let organization = game_state.organizations.get_mut(&organization_id).unwrap();
+        organization.budget = Budget {
+            marketing: Money::new(1_000),
+            rnd: Money::ZERO,
+            training: Money::new(500),
+        };
+        organization.financials.actual_cash = Money::new(10_000);
+
+        let events = organization_updates(game_state);
+        assert_eq!(
+            organization_expenses(&events, organization_id),
+            payroll + Money::new(1_500)
+        );
+        assert!(events.iter().any(|event| matches!(
+            event,
+            InternalEvent::IncrementOrgPublicOpinion { organization_id: id, .. }
+                if *id == organization_id
+        )));
+        assert!(!events.iter().any(|event| matches!(
+            event,
+            InternalEvent::IncrementOrgReputation { organization_id: id, .. }
+                if *id == organization_id
+        )));
+
+        let organization = game_state.organizations.get_mut(&organization_id).unwrap();
+        organization.financials.actual_cash = Money::new(1_000);
+        let events = organization_updates(game_state);
+        assert_eq!(organization_expenses(&events, organization_id), payroll);
+    }
+
+    #[test]
+    fn the_week_settles_the_same_way_whatever_the_map_order() {
+        let world = World::generate(&WorldGenerationParameters::default());
+        // A round trip rebuilds the maps with new hashers, and so a new iteration order.
+        let copy: GameState =
+            serde_json::from_str(&serde_json::to_string(world.game_state()).unwrap()).unwrap();
+
+        let events = serde_json::to_string(&organization_updates(world.game_state())).unwrap();
+        let copy_events = serde_json::to_string(&organization_updates(&copy)).unwrap();
+        assert_eq!(events, copy_events);
+    }
+}
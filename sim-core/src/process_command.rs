@@ -0,0 +1,1024 @@
+use crate::deterministic_randomization::fitting_role_for_organization;
+use crate::{
+    CommandRejection, LOAN_RATE, MAX_LEVEL_STRETCH, apply_event, board_updates, booked_to,
+    borrowing_capacity, cancellation_refund, ceo_pay, change_position, company_cash, company_of,
+    corporate_event_updates, covenants_broken, credit_available, debt_updates, initiative_updates,
+    interest_due, is_demotion, is_finance, is_promotion, labor_market_updates, loan_of,
+    market_sales, minimum_acceptable_salary, morale_updates, new_loan, offer_value,
+    organization_budgets, passed_over_updates, payroll, plan_initiative, report_updates,
+    resolve_corporate_event, rival_turns,
+};
+use bevy::utils::HashMap;
+use shared::{
+    Budget, ClientActionCommand, Company, DepartureReason, EmployeeFlag, Employment,
+    EmploymentStatus, Entity, EntityType, GameInstanceData, GameState, HIREABLE_ROLES,
+    HistoryPoint, InternalEvent, MAX_CEO_BONUS_PERCENT, MAX_INITIATIVE_WEEKS, MAX_LEVEL,
+    MAX_LOAN_WEEKS, Money, Organization, OrganizationRole, Percent, Player,
+};
+use tracing::info;
+use uuid::Uuid;
+
+/// Turns an operator command into the events that carry it out. Every command except
+/// `EndTurn` is an action within the current week; `EndTurn` runs the rivals' moves and the
+/// week's morale drift, labor market, initiatives, corporate events, debt service, the board's
+/// review, the quarterly and annual reports, accounting, history and rollover. Only reads the
+/// state, the events change it once applied. Commands that make no sense for the current state
+/// are rejected without any events, and once the game has ended every command is.
+pub fn process_command(
+    game_instance: &GameInstanceData,
+    client_action_command: ClientActionCommand,
+) -> Result<Vec<InternalEvent>, CommandRejection> {
+    let game_state = &game_instance.game_state;
+    if game_state.board.outcome.is_some() {
+        return Err(CommandRejection::GameFinished);
+    }
+
+    match client_action_command {
+        ClientActionCommand::EndTurn => {
+            info!("Player ended week {}", game_state.week);
+            let mut events = vec![];
+            events.extend(rival_turns(game_state));
+            events.extend(morale_updates(game_state));
+            events.extend(labor_market_updates(game_state));
+            events.extend(initiative_updates(game_state));
+            events.extend(corporate_event_updates(game_state));
+            // The board reviews the week with its debt settled, so a bankruptcy declared
+            // just now ends the game this week.
+            let debt_events = debt_updates(game_state);
+            let mut settled = game_instance.clone();
+            for debt_event in &debt_events {
+                apply_event(debt_event, &mut settled);
+            }
+            events.extend(debt_events);
+            events.extend(board_updates(&settled));
+            events.extend(report_updates(game_instance));
+            events.extend(end_of_week(game_state));
+            Ok(events)
+        }
+        client_action_command => {
+            let company_id = game_state.player_company_id().unwrap_or_default();
+            process_company_command(game_state, &company_id, client_action_command)
+        }
+    }
+}
+
+/// A command issued on behalf of a company, the operator's or a rival's. It may only touch
+/// the company's own organizations and staff, and what it costs or earns is booked to that
+/// company. Running the clock and the week stays with the operator, ending the week goes
+/// through `process_command`.
+pub fn process_company_command(
+    game_state: &GameState,
+    company_id: &Uuid,
+    client_action_command: ClientActionCommand,
+) -> Result<Vec<InternalEvent>, CommandRejection> {
+    let mut events = vec![];
+
+    let is_rival = game_state
+        .companies
+        .get(company_id)
+        .is_some_and(|company| company.rival.is_some());
+    if is_rival
+        && matches!(
+            client_action_command,
+            ClientActionCommand::EndTurn
+                | ClientActionCommand::SetClockSpeed { .. }
+                | ClientActionCommand::ResolveCorporateEvent { .. }
+                | ClientActionCommand::SetCeoCompensation { .. }
+                | ClientActionCommand::SellEquity { .. }
+        )
+    {
+        return Err(CommandRejection::OperatorOnly);
+    }
+
+    // A bankrupt company can only watch the weeks go by.
+    let waits = matches!(
+        client_action_command,
+        ClientActionCommand::EndTurn
+            | ClientActionCommand::SetClockSpeed { .. }
+            | ClientActionCommand::DoNothing
+    );
+    if let Some(company) = game_state
+        .companies
+        .get(company_id)
+        .filter(|company| company.debt.bankrupt && !waits)
+    {
+        return Err(CommandRejection::Bankrupt {
+            company_name: company.name.clone(),
+        });
+    }
+
+    info!(
+        "Server has clients command for processing {:?}",
+        client_action_command
+    );
+
+    match client_action_command {
+        ClientActionCommand::FireEmployee { employee_id } => {
+            let (employee, employment) = employee(game_state, company_id, &employee_id)?;
+            info!("Firing employee: {}", employee.name);
+
+            events.push(InternalEvent::EmployeeDeparted {
+                employee_id,
+                organization_id: employment.organization_id,
+                status: EmploymentStatus::Fired,
+                reason: DepartureReason::Dismissed,
+            });
+
+            for (org_id, org) in &game_state.organizations {
+                if org.vp == Some(employee_id) {
+                    events.push(InternalEvent::SetOrgVp {
+                        organization_id: *org_id,
+                        employee_id: None,
+                    });
+                }
+            }
+        }
+
+        ClientActionCommand::HireEmployee {
+            organization_id,
+            employee_id,
+            role,
+            level,
+            salary_offer,
+            signing_bonus,
+        } => {
+            organization(game_state, company_id, &organization_id)?;
+            let person = person(game_state, &employee_id)?;
+            if person.employment.is_some() {
+                return Err(CommandRejection::AlreadyEmployed {
+                    name: person.name.clone(),
+                });
+            }
+            let Some(candidate) = game_state.candidates.get(&employee_id) else {
+                return Err(CommandRejection::NotLookingForWork {
+                    name: person.name.clone(),
+                });
+            };
+            if !HIREABLE_ROLES.contains(&role) {
+                return Err(CommandRejection::NotHireableRole { role });
+            }
+            if level == 0 || level > candidate.level + MAX_LEVEL_STRETCH {
+                return Err(CommandRejection::LevelOutOfReach {
+                    name: person.name.clone(),
+                    level,
+                });
+            }
+            let EntityType::Human(human_type) = &person.entity_type else {
+                return Err(CommandRejection::NotAPerson {
+                    name: person.name.clone(),
+                });
+            };
+            let Some(minimum_salary) =
+                minimum_acceptable_salary(candidate, human_type, &role, level)
+            else {
+                return Err(CommandRejection::RoleDeclined {
+                    name: person.name.clone(),
+                    role,
+                });
+            };
+            if offer_value(salary_offer, signing_bonus) < minimum_salary {
+                return Err(CommandRejection::OfferDeclined {
+                    name: person.name.clone(),
+                    minimum_salary,
+                });
+            }
+            let signing_bonus = signing_bonus.filter(|bonus| *bonus > Money::ZERO);
+            if let Some(signing_bonus) = signing_bonus
+                && signing_bonus > company_cash(game_state, company_id)
+            {
+                return Err(CommandRejection::InsufficientFunds {
+                    cost: signing_bonus,
+                });
+            }
+
+            info!("Hiring employee: {} as {:?}", person.name, role);
+            events.push(InternalEvent::AddEmployedStatus {
+                organization_id,
+                employee_id,
+                role,
+                level,
+                salary: salary_offer,
+            });
+            if let Some(signing_bonus) = signing_bonus {
+                events.push(InternalEvent::DecrementMoney {
+                    amount: signing_bonus,
+                });
+            }
+        }
+
+        ClientActionCommand::GiveRaise {
+            employee_id,
+            amount,
+        } => {
+            let (employee, employment) = employee(game_state, company_id, &employee_id)?;
+            if amount <= Money::ZERO {
+                return Err(CommandRejection::EmptyRaise {
+                    name: employee.name.clone(),
+                });
+            }
+
+            info!("Growing employee: {}", employee.name);
+
+            events.push(InternalEvent::IncrementEmployeeSatisfaction {
+                employee_id,
+                amount: 1,
+            });
+
+            events.push(InternalEvent::IncrementSalary {
+                employee_id,
+                amount,
+            });
+
+            if employment
+                .employee_flags
+                .contains(&EmployeeFlag::PassedOver)
+            {
+                events.push(InternalEvent::ClearEmployeeFlag {
+                    employee_id,
+                    flag: EmployeeFlag::PassedOver,
+                });
+            }
+        }
+
+        ClientActionCommand::LaunchPRCampaign => {
+            events.push(InternalEvent::IncrementReputation { amount: 1 });
+
+            events.push(InternalEvent::DecrementMoney {
+                amount: Money::new(1_000),
+            });
+        }
+
+        ClientActionCommand::LaunchInitiative {
+            organization_id,
+            kind,
+            weeks,
+            budget,
+        } => {
+            let organization = organization(game_state, company_id, &organization_id)?;
+            if weeks == 0 || weeks > MAX_INITIATIVE_WEEKS || budget <= Money::ZERO {
+                return Err(CommandRejection::InvalidInitiativePlan { weeks, budget });
+            }
+            if organization
+                .initiatives
+                .iter()
+                .any(|initiative| initiative.kind == kind)
+            {
+                return Err(CommandRejection::InitiativeAlreadyRunning {
+                    organization_name: organization.name.clone(),
+                    kind,
+                });
+            }
+            let initiative = plan_initiative(kind, weeks, budget);
+            if initiative.upfront_cost > company_cash(game_state, company_id) {
+                return Err(CommandRejection::InsufficientFunds {
+                    cost: initiative.upfront_cost,
+                });
+            }
+
+            info!(
+                "Launching a {:?} initiative in {} for {} weeks",
+                kind, organization.name, weeks
+            );
+            events.push(InternalEvent::DecrementMoney {
+                amount: initiative.upfront_cost,
+            });
+            events.push(InternalEvent::AddOrgInitiative {
+                organization_id,
+                initiative,
+            });
+        }
+
+        ClientActionCommand::CancelInitiative {
+            organization_id,
+            kind,
+        } => {
+            let organization = organization(game_state, company_id, &organization_id)?;
+            let Some(cancelled) = organization
+                .initiatives
+                .iter()
+                .find(|initiative| initiative.kind == kind)
+            else {
+                return Err(CommandRejection::NoSuchInitiative {
+                    organization_name: organization.name.clone(),
+                    kind,
+                });
+            };
+
+            info!(
+                "Cancelling the {:?} initiative in {}",
+                kind, organization.name
+            );
+            let refund = cancellation_refund(cancelled);
+            if refund > Money::ZERO {
+                events.push(InternalEvent::IncrementMoney { amount: refund });
+            }
+            events.push(InternalEvent::RemoveOrgInitiative {
+                organization_id,
+                kind,
+            });
+        }
+
+        ClientActionCommand::ResolveCorporateEvent {
+            corporate_event_id,
+            choice,
+        } => {
+            let Some(corporate_event) = game_state
+                .corporate_events
+                .iter()
+                .find(|corporate_event| corporate_event.id == corporate_event_id)
+                .filter(|corporate_event| corporate_event.is_pending())
+            else {
+                return Err(CommandRejection::NoOpenCorporateEvent { corporate_event_id });
+            };
+            if !corporate_event.choices.contains(&choice) {
+                return Err(CommandRejection::ChoiceNotOffered { choice });
+            }
+
+            info!("Player chose {:?} for {:?}", choice, corporate_event.kind);
+            events.extend(resolve_corporate_event(game_state, corporate_event, choice));
+        }
+
+        ClientActionCommand::DoNothing => {
+            info!("Player did nothing this turn.");
+        }
+
+        ClientActionCommand::SetClockSpeed { speed } => {
+            // The clock belongs to the running instance, not to the simulated world.
+            info!("Player set the clock to {}", speed);
+        }
+
+        ClientActionCommand::EndTurn => return Err(CommandRejection::OperatorOnly),
+
+        ClientActionCommand::PromoteToVp {
+            organization_id,
+            employee_id,
+        } => {
+            let organization = organization(game_state, company_id, &organization_id)?;
+            let (employee, employment) = employee(game_state, company_id, &employee_id)?;
+            if employment.organization_id != organization_id {
+                return Err(CommandRejection::NotInOrganization {
+                    name: employee.name.clone(),
+                    organization_name: organization.name.clone(),
+                });
+            }
+            if organization.vp == Some(employee_id) {
+                return Err(CommandRejection::AlreadyVp {
+                    name: employee.name.clone(),
+                    organization_name: organization.name.clone(),
+                });
+            }
+
+            events.extend(passed_over_updates(game_state, employee_id, employment));
+            events.extend(change_position(
+                employee,
+                employment,
+                OrganizationRole::VP,
+                employment.level,
+            ));
+
+            if let Some(existing_vp_id) = organization.vp {
+                events.push(InternalEvent::SetOrganizationRole {
+                    employee_id: existing_vp_id,
+                    new_role: fitting_role_for_organization(organization.organization_type),
+                });
+            }
+
+            events.push(InternalEvent::SetOrgVp {
+                organization_id,
+                employee_id: Some(employee_id),
+            });
+        }
+
+        ClientActionCommand::PromoteEmployee {
+            employee_id,
+            role,
+            level,
+        } => {
+            let (employee, employment) = employee(game_state, company_id, &employee_id)?;
+            let role = role.unwrap_or(employment.role);
+            if role == OrganizationRole::VP && employment.role != OrganizationRole::VP {
+                return Err(CommandRejection::VpByAppointmentOnly {
+                    name: employee.name.clone(),
+                });
+            }
+            if level > MAX_LEVEL || !is_promotion(employment, role, level) {
+                return Err(CommandRejection::NotAPromotion {
+                    name: employee.name.clone(),
+                });
+            }
+
+            info!("Promoting {} to {:?} level {}", employee.name, role, level);
+            events.extend(passed_over_updates(game_state, employee_id, employment));
+            events.extend(change_position(employee, employment, role, level));
+        }
+
+        ClientActionCommand::DemoteEmployee {
+            employee_id,
+            role,
+            level,
+        } => {
+            let (employee, employment) = employee(game_state, company_id, &employee_id)?;
+            let organization = organization(game_state, company_id, &employment.organization_id)?;
+            let is_vp = organization.vp == Some(employee_id);
+            let role = match role {
+                Some(role) => role,
+                None if is_vp => fitting_role_for_organization(organization.organization_type),
+                None => employment.role,
+            };
+            if level == 0 || role == OrganizationRole::VP || !is_demotion(employment, role, level) {
+                return Err(CommandRejection::NotADemotion {
+                    name: employee.name.clone(),
+                });
+            }
+
+            info!("Demoting {} to {:?} level {}", employee.name, role, level);
+            events.extend(change_position(employee, employment, role, level));
+            if is_vp {
+                events.push(InternalEvent::SetOrgVp {
+                    organization_id: organization.id,
+                    employee_id: None,
+                });
+            }
+        }
+
+        ClientActionCommand::TransferEmployee {
+            employee_id,
+            organization_id,
+        } => {
+            let destination = organization(game_state, company_id, &organization_id)?;
+            let (employee, employment) = employee(game_state, company_id, &employee_id)?;
+            if employment.organization_id == organization_id {
+                return Err(CommandRejection::AlreadyInOrganization {
+                    name: employee.name.clone(),
+                    organization_name: destination.name.clone(),
+                });
+            }
+            let current = organization(game_state, company_id, &employment.organization_id)?;
+            if current.vp == Some(employee_id) {
+                return Err(CommandRejection::StillVp {
+                    name: employee.name.clone(),
+                    organization_name: current.name.clone(),
+                });
+            }
+
+            info!("Transferring {} to {}", employee.name, destination.name);
+            events.push(InternalEvent::SetEmployeeOrganization {
+                employee_id,
+                organization_id,
+            });
+        }
+
+        ClientActionCommand::UpdateBudget {
+            organization_id,
+            organization_budget,
+        } => {
+            let organization = organization(game_state, company_id, &organization_id)?;
+            let Budget {
+                marketing,
+                rnd,
+                training,
+            } = organization_budget;
+            if [marketing, rnd, training]
+                .into_iter()
+                .any(Money::is_negative)
+            {
+                return Err(CommandRejection::NegativeBudget {
+                    organization_name: organization.name.clone(),
+                });
+            }
+            events.push(InternalEvent::SetOrgBudget {
+                organization_id,
+                budget: organization_budget,
+            });
+        }
+
+        ClientActionCommand::SetCeoCompensation {
+            salary,
+            bonus_percent,
+        } => {
+            let compensation = &ceo(game_state)?.compensation;
+            if salary.is_negative() || bonus_percent.get() > MAX_CEO_BONUS_PERCENT {
+                return Err(CommandRejection::InvalidCompensation {
+                    salary,
+                    bonus_percent,
+                });
+            }
+
+            // Paying yourself more never goes unnoticed.
+            if salary > compensation.salary || bonus_percent > compensation.bonus_percent {
+                events.push(InternalEvent::DecrementReputation { amount: 1 });
+            }
+            events.push(InternalEvent::SetCeoCompensation {
+                salary,
+                bonus_percent,
+            });
+        }
+
+        ClientActionCommand::SellEquity { percent } => {
+            let held = ceo(game_state)?.compensation.equity_percent;
+            if percent == Percent::ZERO || percent > held {
+                return Err(CommandRejection::NotEnoughEquity { held });
+            }
+            let valuation = game_state
+                .companies
+                .get(company_id)
+                .map(|company| company.valuation())
+                .unwrap_or_default();
+
+            info!("CEO selling {}% of the company", percent);
+            events.push(InternalEvent::SellCeoEquity {
+                percent,
+                proceeds: valuation.percent(percent.get() as i64),
+            });
+            // The market reads a CEO selling out as a lack of faith.
+            events.push(InternalEvent::DecrementReputation { amount: 1 });
+        }
+
+        ClientActionCommand::TakeLoan {
+            organization_id,
+            amount,
+            weeks,
+        } => {
+            let company = finance_desk(game_state, company_id, &organization_id)?;
+            if amount <= Money::ZERO || weeks == 0 || weeks > MAX_LOAN_WEEKS {
+                return Err(CommandRejection::InvalidLoanTerms { amount, weeks });
+            }
+            if covenants_broken(company) {
+                return Err(CommandRejection::CovenantsBroken);
+            }
+            let available = borrowing_capacity(company);
+            if amount > available {
+                return Err(CommandRejection::OverBorrowingLimit { available });
+            }
+
+            info!(
+                "{} borrowing ${} over {} weeks",
+                company.name, amount, weeks
+            );
+            events.push(InternalEvent::TakeLoan {
+                company_id: *company_id,
+                loan: new_loan(game_state, company, amount, LOAN_RATE, weeks),
+            });
+        }
+
+        ClientActionCommand::RepayLoan {
+            organization_id,
+            loan_id,
+            amount,
+        } => {
+            let company = finance_desk(game_state, company_id, &organization_id)?;
+            let Some(loan) = loan_of(company, &loan_id) else {
+                return Err(CommandRejection::NoSuchLoan { loan_id });
+            };
+            if amount <= Money::ZERO || amount > loan.balance {
+                return Err(CommandRejection::InvalidRepayment {
+                    amount,
+                    owed: loan.balance,
+                });
+            }
+            if amount > company.financials.actual_cash {
+                return Err(CommandRejection::InsufficientFunds { cost: amount });
+            }
+
+            events.push(InternalEvent::RepayLoan {
+                company_id: *company_id,
+                loan_id,
+                amount,
+            });
+        }
+
+        ClientActionCommand::DrawCredit {
+            organization_id,
+            amount,
+        } => {
+            let company = finance_desk(game_state, company_id, &organization_id)?;
+            if amount <= Money::ZERO {
+                return Err(CommandRejection::EmptyDraw);
+            }
+            if covenants_broken(company) {
+                return Err(CommandRejection::CovenantsBroken);
+            }
+            let available = credit_available(company);
+            if amount > available {
+                return Err(CommandRejection::CreditLineExhausted { available });
+            }
+
+            events.push(InternalEvent::DrawCredit {
+                company_id: *company_id,
+                amount,
+            });
+        }
+
+        ClientActionCommand::RepayCredit {
+            organization_id,
+            amount,
+        } => {
+            let company = finance_desk(game_state, company_id, &organization_id)?;
+            let owed = company.debt.credit_drawn;
+            if amount <= Money::ZERO || amount > owed {
+                return Err(CommandRejection::InvalidRepayment { amount, owed });
+            }
+            if amount > company.financials.actual_cash {
+                return Err(CommandRejection::InsufficientFunds { cost: amount });
+            }
+
+            events.push(InternalEvent::RepayCredit {
+                company_id: *company_id,
+                amount,
+            });
+        }
+    }
+
+    Ok(events
+        .into_iter()
+        .map(|internal_event| booked_to(game_state, company_id, internal_event))
+        .collect())
+}
+
+/// The week's accounting, a history point for everyone and the rollover to the next week.
+fn end_of_week(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut events = vec![];
+
+    for sales in market_sales(game_state) {
+        let company_id = sales.company_id;
+        events.push(booked_to(
+            game_state,
+            &company_id,
+            InternalEvent::IncrementMoney {
+                amount: sales.revenue,
+            },
+        ));
+        let payroll = payroll(game_state, &company_id);
+        let budgets = organization_budgets(game_state, &company_id);
+        events.push(booked_to(
+            game_state,
+            &company_id,
+            InternalEvent::DecrementMoney { amount: payroll },
+        ));
+        if budgets > Money::ZERO {
+            events.push(booked_to(
+                game_state,
+                &company_id,
+                InternalEvent::DecrementMoney { amount: budgets },
+            ));
+        }
+        let interest = game_state
+            .companies
+            .get(&company_id)
+            .map(interest_due)
+            .unwrap_or_default();
+        if let Some((salary, bonus)) = ceo_pay(
+            game_state,
+            &company_id,
+            sales.revenue,
+            payroll + budgets + interest,
+        ) {
+            events.push(InternalEvent::PayCeo {
+                company_id,
+                salary,
+                bonus,
+            });
+        }
+
+        // Customers left waiting for half the orders tell everyone about it.
+        if sales.orders.fulfilled * 2 < sales.orders.placed {
+            events.push(InternalEvent::AdjustCompanyReputation {
+                company_id,
+                amount: -1,
+            });
+        }
+    }
+
+    let mut new_player_history_points = HashMap::new();
+    let mut new_company_history_points = HashMap::new();
+    let mut new_organization_history_points = HashMap::new();
+
+    let player_company_id = game_state.player_company_id();
+    for player in &game_state.players {
+        let employees = game_state
+            .entities
+            .values()
+            .filter_map(|entity| entity.employment.as_ref())
+            .filter(|employment| {
+                company_of(game_state, &employment.organization_id) == player_company_id
+            });
+
+        let avg_employee_satisfaction =
+            average_satisfaction(employees.map(|employment| employment.satisfaction));
+
+        let history_point = HistoryPoint {
+            week: game_state.week,
+            financials: player.financials.clone(),
+            perception: player.perception.clone(),
+            avg_employee_satisfaction,
+        };
+
+        new_player_history_points.insert(
+            player.id.unwrap_or(Uuid::from_u128(987123564738)),
+            history_point,
+        );
+    }
+
+    for (company_id, company) in &game_state.companies {
+        let avg_employee_satisfaction =
+            average_satisfaction(game_state.entities.values().filter_map(|entity| {
+                let employment = entity.employment.as_ref()?;
+                let organization = game_state.organizations.get(&employment.organization_id)?;
+                if organization.company_relation.entity_id == *company_id {
+                    Some(employment.satisfaction)
+                } else {
+                    None
+                }
+            }));
+
+        let history_point = HistoryPoint {
+            week: game_state.week,
+            financials: company.financials.clone(),
+            perception: company.perception.clone(),
+            avg_employee_satisfaction,
+        };
+
+        new_company_history_points.insert(*company_id, history_point);
+    }
+
+    for (organization_id, organization) in &game_state.organizations {
+        let avg_employee_satisfaction =
+            average_satisfaction(game_state.entities.values().filter_map(|entity| {
+                entity
+                    .employment
+                    .as_ref()
+                    .filter(|e| e.organization_id == *organization_id)
+                    .map(|e| e.satisfaction)
+            }));
+
+        let history_point = HistoryPoint {
+            week: game_state.week,
+            financials: organization.financials.clone(),
+            perception: organization.perception.clone(),
+            avg_employee_satisfaction,
+        };
+
+        new_organization_history_points.insert(*organization_id, history_point);
+    }
+
+    events.push(InternalEvent::AppendHistoryPoint {
+        new_player_history_points,
+        new_organization_history_points,
+        new_company_history_points,
+    });
+
+    events.push(InternalEvent::AdvanceWeek);
+
+    events
+}
+
+/// Mean satisfaction of a group of employees, nothing for an empty one.
+pub(crate) fn average_satisfaction(satisfactions: impl Iterator<Item = Percent>) -> Percent {
+    let (total, count) = satisfactions.fold((0u32, 0u32), |(total, count), satisfaction| {
+        (total + satisfaction.get() as u32, count + 1)
+    });
+    Percent::new(total.checked_div(count).unwrap_or(0) as u16)
+}
+
+fn ceo(game_state: &GameState) -> Result<&Player, CommandRejection> {
+    game_state
+        .players
+        .first()
+        .ok_or(CommandRejection::OperatorOnly)
+}
+
+fn person<'a>(game_state: &'a GameState, entity_id: &Uuid) -> Result<&'a Entity, CommandRejection> {
+    let entity = game_state
+        .entities
+        .get(entity_id)
+        .ok_or(CommandRejection::UnknownEntity {
+            entity_id: *entity_id,
+        })?;
+
+    match entity.entity_type {
+        EntityType::Human(_) => Ok(entity),
+        _ => Err(CommandRejection::NotAPerson {
+            name: entity.name.clone(),
+        }),
+    }
+}
+
+/// Someone working for the company.
+fn employee<'a>(
+    game_state: &'a GameState,
+    company_id: &Uuid,
+    entity_id: &Uuid,
+) -> Result<(&'a Entity, &'a Employment), CommandRejection> {
+    let entity = person(game_state, entity_id)?;
+    match &entity.employment {
+        Some(employment)
+            if company_of(game_state, &employment.organization_id) == Some(*company_id) =>
+        {
+            Ok((entity, employment))
+        }
+        Some(_) => Err(CommandRejection::WorksForRival {
+            name: entity.name.clone(),
+        }),
+        None => Err(CommandRejection::NotEmployed {
+            name: entity.name.clone(),
+        }),
+    }
+}
+
+/// The company, when the organization is one of its Finance organizations.
+fn finance_desk<'a>(
+    game_state: &'a GameState,
+    company_id: &Uuid,
+    organization_id: &Uuid,
+) -> Result<&'a Company, CommandRejection> {
+    let organization = organization(game_state, company_id, organization_id)?;
+    if !is_finance(organization) {
+        return Err(CommandRejection::NotFinance {
+            organization_name: organization.name.clone(),
+        });
+    }
+    game_state
+        .companies
+        .get(company_id)
+        .ok_or(CommandRejection::OperatorOnly)
+}
+
+/// One of the company's organizations.
+fn organization<'a>(
+    game_state: &'a GameState,
+    company_id: &Uuid,
+    organization_id: &Uuid,
+) -> Result<&'a Organization, CommandRejection> {
+    let organization = game_state.organizations.get(organization_id).ok_or(
+        CommandRejection::UnknownOrganization {
+            organization_id: *organization_id,
+        },
+    )?;
+    if organization.company_relation.entity_id != *company_id {
+        return Err(CommandRejection::RivalOrganization {
+            organization_name: organization.name.clone(),
+        });
+    }
+    Ok(organization)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::World;
+    use shared::{Candidate, WorldGenerationParameters};
+
+    /// The operator's staff, by id so the picks are the same every run.
+    fn player_staff(game_state: &GameState) -> Vec<&Entity> {
+        let company_id = game_state.player_company_id();
+        let mut staff: Vec<&Entity> = game_state
+            .entities
+            .values()
+            .filter(|entity| {
+                entity.employment.as_ref().is_some_and(|employment| {
+                    company_of(game_state, &employment.organization_id) == company_id
+                })
+            })
+            .collect();
+        staff.sort_by_key(|entity| entity.id);
+        staff
+    }
+
+    #[test]
+    fn hiring_someone_already_employed_is_rejected() {
+        let world = World::generate(&WorldGenerationParameters::default());
+        let employee = player_staff(world.game_state())[0];
+        let employment = employee.employment.as_ref().unwrap();
+
+        let rejection = world
+            .process_command(ClientActionCommand::HireEmployee {
+                employee_id: employee.id,
+                organization_id: employment.organization_id,
+                role: employment.role,
+                level: employment.level,
+                salary_offer: employment.salary,
+                signing_bonus: None,
+            })
+            .unwrap_err();
+        assert_eq!(
+            rejection,
+            CommandRejection::AlreadyEmployed {
+                name: employee.name.clone()
+            }
+        );
+    }
+
+    #[test]
+    fn firing_a_pet_is_rejected() {
+        let world = World::generate(&WorldGenerationParameters::default());
+        let pet = world
+            .game_state()
+            .entities
+            .values()
+            .find(|entity| !matches!(entity.entity_type, EntityType::Human(_)))
+            .expect("the world has pets");
+
+        let rejection = world
+            .process_command(ClientActionCommand::FireEmployee {
+                employee_id: pet.id,
+            })
+            .unwrap_err();
+        assert_eq!(
+            rejection,
+            CommandRejection::NotAPerson {
+                name: pet.name.clone()
+            }
+        );
+    }
+
+    #[test]
+    fn promoting_to_vp_of_another_organization_is_rejected() {
+        let world = World::generate(&WorldGenerationParameters::default());
+        let game_state = world.game_state();
+        let employee = player_staff(game_state)[0];
+        let employment = employee.employment.as_ref().unwrap();
+        let other_organization = game_state
+            .organizations
+            .values()
+            .find(|organization| {
+                organization.id != employment.organization_id
+                    && Some(organization.company_relation.entity_id)
+                        == game_state.player_company_id()
+            })
+            .expect("the player's company has more than one organization");
+
+        let rejection = world
+            .process_command(ClientActionCommand::PromoteToVp {
+                organization_id: other_organization.id,
+                employee_id: employee.id,
+            })
+            .unwrap_err();
+        assert_eq!(
+            rejection,
+            CommandRejection::NotInOrganization {
+                name: employee.name.clone(),
+                organization_name: other_organization.name.clone(),
+            }
+        );
+    }
+
+    #[test]
+    fn a_signing_bonus_the_company_cannot_pay_is_rejected() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let candidate = loop {
+            let mut candidates: Vec<&Candidate> = world
+                .game_state()
+                .candidates
+                .values()
+                .filter(|candidate| {
+                    candidate
+                        .preferred_roles
+                        .iter()
+                        .any(|role| HIREABLE_ROLES.contains(role))
+                })
+                .collect();
+            candidates.sort_by_key(|candidate| candidate.entity_id);
+            if let Some(candidate) = candidates.first() {
+                break (*candidate).clone();
+            }
+            world.step(ClientActionCommand::EndTurn).unwrap();
+        };
+        let game_state = &mut world.instance_game.game_state;
+        let company_id = game_state.player_company_id().unwrap();
+        let organization_id = game_state
+            .organizations
+            .values()
+            .filter(|organization| organization.company_relation.entity_id == company_id)
+            .map(|organization| organization.id)
+            .min()
+            .unwrap();
+        let role = *candidate
+            .preferred_roles
+            .iter()
+            .find(|role| HIREABLE_ROLES.contains(role))
+            .unwrap();
+        let hire = ClientActionCommand::HireEmployee {
+            organization_id,
+            employee_id: candidate.entity_id,
+            role,
+            level: candidate.level,
+            salary_offer: candidate.asking_salary.percent(200)
+                + role.pay_band(candidate.level).midpoint,
+            signing_bonus: Some(Money::new(5_000)),
+        };
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.financials.actual_cash = Money::new(4_999);
+
+        assert_eq!(
+            world.process_command(hire.clone()).unwrap_err(),
+            CommandRejection::InsufficientFunds {
+                cost: Money::new(5_000)
+            }
+        );
+
+        let game_state = &mut world.instance_game.game_state;
+        let company = game_state.companies.get_mut(&company_id).unwrap();
+        company.financials.actual_cash = Money::new(5_000);
+        assert!(world.process_command(hire).is_ok());
+    }
+}
@@ -0,0 +1,339 @@
+use crate::{average_satisfaction, company_of};
+use shared::{
+    FinancialReport, Financials, GameInstanceData, GameState, HistoryPoint, InternalEvent, Money,
+    Percent, Perception, QUARTER_WEEKS, ReportLine, ReportPeriod, YEAR_WEEKS,
+};
+use std::collections::VecDeque;
+use uuid::Uuid;
+
+/// Files the quarterly report once the last week of a quarter ends, and the annual report
+/// along with the year's last quarter. A quarter is read from the weekly history plus the
+/// week ending now, whose history point is only appended by `end_of_week`. A year is longer
+/// than the history keeps, so it is put together from its quarters instead.
+pub fn report_updates(game_instance: &GameInstanceData) -> Vec<InternalEvent> {
+    let week = game_instance.game_state.week;
+    if !(week + 1).is_multiple_of(QUARTER_WEEKS) {
+        return vec![];
+    }
+
+    let quarter = quarterly_report(game_instance);
+    let annual = (week + 1).is_multiple_of(YEAR_WEEKS).then(|| {
+        let first_week = week + 1 - YEAR_WEEKS;
+        let earlier_quarters = game_instance.report_store.reports.iter().filter(|report| {
+            report.period == ReportPeriod::Quarterly && report.first_week >= first_week
+        });
+        annual_report(first_week, earlier_quarters, &quarter)
+    });
+
+    [quarter]
+        .into_iter()
+        .chain(annual)
+        .map(|report| InternalEvent::FileReport { report })
+        .collect()
+}
+
+fn quarterly_report(game_instance: &GameInstanceData) -> FinancialReport {
+    let game_state = &game_instance.game_state;
+    let history = &game_instance.history_state;
+    let last_week = game_state.week;
+    let first_week = last_week + 1 - QUARTER_WEEKS;
+
+    let mut companies: Vec<_> = game_state.companies.values().collect();
+    companies.sort_by_key(|company| company.id);
+    let companies = companies
+        .into_iter()
+        .map(|company| {
+            quarter_line(
+                company.id,
+                &company.name,
+                &company.financials,
+                &company.perception,
+                staff_satisfaction(game_state, |organization_id| {
+                    company_of(game_state, organization_id) == Some(company.id)
+                }),
+                history
+                    .companies
+                    .get(&company.id)
+                    .map(|history| &history.recent_history),
+                first_week,
+            )
+        })
+        .collect();
+
+    let mut organizations: Vec<_> = game_state.organizations.values().collect();
+    organizations.sort_by_key(|organization| organization.id);
+    let organizations = organizations
+        .into_iter()
+        .map(|organization| {
+            quarter_line(
+                organization.id,
+                &organization.name,
+                &organization.financials,
+                &organization.perception,
+                staff_satisfaction(game_state, |organization_id| {
+                    *organization_id == organization.id
+                }),
+                history
+                    .organizations
+                    .get(&organization.id)
+                    .map(|history| &history.recent_history),
+                first_week,
+            )
+        })
+        .collect();
+
+    FinancialReport {
+        period: ReportPeriod::Quarterly,
+        first_week,
+        last_week,
+        companies,
+        organizations,
+    }
+}
+
+/// Satisfaction of everyone employed in the organizations `works_in` accepts.
+fn staff_satisfaction(game_state: &GameState, works_in: impl Fn(&Uuid) -> bool) -> Vec<Percent> {
+    game_state
+        .entities
+        .values()
+        .filter_map(|entity| entity.employment.as_ref())
+        .filter(|employment| works_in(&employment.organization_id))
+        .map(|employment| employment.satisfaction)
+        .collect()
+}
+
+fn quarter_line(
+    id: Uuid,
+    name: &str,
+    financials: &Financials,
+    perception: &Perception,
+    staff_satisfaction: Vec<Percent>,
+    history: Option<&VecDeque<HistoryPoint>>,
+    first_week: u16,
+) -> ReportLine {
+    let points: Vec<&HistoryPoint> = history.into_iter().flatten().collect();
+    let quarter_points: Vec<&HistoryPoint> = points
+        .iter()
+        .copied()
+        .filter(|point| point.week >= first_week)
+        .collect();
+
+    let revenue = quarter_points
+        .iter()
+        .map(|point| point.financials.this_weeks_income)
+        .sum::<Money>()
+        + financials.this_weeks_income;
+    let expenses = quarter_points
+        .iter()
+        .map(|point| point.financials.this_weeks_expenses)
+        .sum::<Money>()
+        + financials.this_weeks_expenses;
+    let headcount = staff_satisfaction.len() as u32;
+    let avg_satisfaction = average_satisfaction(
+        quarter_points
+            .iter()
+            .map(|point| point.avg_employee_satisfaction)
+            .chain([average_satisfaction(staff_satisfaction.into_iter())]),
+    );
+
+    // Perception as the previous quarter left it, or as early in this one as is known.
+    let opening = points
+        .iter()
+        .find(|point| point.week + 1 >= first_week)
+        .map_or(perception, |point| &point.perception);
+
+    ReportLine {
+        id,
+        name: name.to_string(),
+        revenue,
+        expenses,
+        profit: revenue - expenses,
+        headcount,
+        avg_satisfaction,
+        reputation_change: perception.reputation.saturating_sub(opening.reputation),
+        public_opinion_change: perception
+            .public_opinion
+            .saturating_sub(opening.public_opinion),
+    }
+}
+
+fn annual_report<'a>(
+    first_week: u16,
+    earlier_quarters: impl Iterator<Item = &'a FinancialReport>,
+    last_quarter: &'a FinancialReport,
+) -> FinancialReport {
+    let quarters: Vec<&FinancialReport> = earlier_quarters.chain([last_quarter]).collect();
+
+    // Companies and organizations as they stand at the end of the year, over every quarter
+    // they were part of.
+    let year_lines = |lines_of: fn(&FinancialReport) -> &Vec<ReportLine>| -> Vec<ReportLine> {
+        lines_of(last_quarter)
+            .iter()
+            .map(|line| {
+                let lines: Vec<&ReportLine> = quarters
+                    .iter()
+                    .filter_map(|quarter| {
+                        lines_of(quarter)
+                            .iter()
+                            .find(|quarter_line| quarter_line.id == line.id)
+                    })
+                    .collect();
+                ReportLine {
+                    id: line.id,
+                    name: line.name.clone(),
+                    revenue: lines.iter().map(|line| line.revenue).sum(),
+                    expenses: lines.iter().map(|line| line.expenses).sum(),
+                    profit: lines.iter().map(|line| line.profit).sum(),
+                    headcount: line.headcount,
+                    avg_satisfaction: average_satisfaction(
+                        lines.iter().map(|line| line.avg_satisfaction),
+                    ),
+                    reputation_change: lines.iter().fold(0, |total: i16, line| {
+                        total.saturating_add(line.reputation_change)
+                    }),
+                    public_opinion_change: lines.iter().fold(0, |total: i16, line| {
+                        total.saturating_add(line.public_opinion_change)
+                    }),
+                }
+            })
+            .collect()
+    };
+
+    FinancialReport {
+        period: ReportPeriod::Annual,
+        first_week,
+        last_week: last_quarter.last_week,
+        companies: year_lines(|report| &report.companies),
+        organizations: year_lines(|report| &report.organizations),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::World;
+    use shared::WorldGenerationParameters;
+
+    const WEEKLY_INCOME: i64 = 100;
+
+    /// A world at `week` whose operating company has earned the same every week so far.
+    fn world_at(week: u16) -> (World, Uuid) {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let company_id = world.game_state().player_company_id().unwrap();
+        let instance_game = &mut world.instance_game;
+        instance_game.game_state.week = week;
+        let company = instance_game
+            .game_state
+            .companies
+            .get_mut(&company_id)
+            .unwrap();
+        company.financials.this_weeks_income = Money::new(WEEKLY_INCOME);
+        let history = instance_game
+            .history_state
+            .companies
+            .entry(company_id)
+            .or_default();
+        *history = Default::default();
+        for week in 0..week {
+            history.push(HistoryPoint {
+                week,
+                financials: company.financials.clone(),
+                perception: company.perception.clone(),
+                avg_employee_satisfaction: Percent::new(50),
+            });
+        }
+        (world, company_id)
+    }
+
+    fn filed(events: &[InternalEvent]) -> Vec<&FinancialReport> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                InternalEvent::FileReport { report } => Some(report),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn company_line(report: &FinancialReport, company_id: Uuid) -> &ReportLine {
+        report
+            .companies
+            .iter()
+            .find(|line| line.id == company_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn a_quarter_is_filed_when_its_last_week_ends() {
+        for week in [QUARTER_WEEKS - 2, QUARTER_WEEKS] {
+            let (world, _) = world_at(week);
+            assert!(filed(&report_updates(&world.instance_game)).is_empty());
+        }
+
+        let (world, company_id) = world_at(2 * QUARTER_WEEKS - 1);
+        let events = report_updates(&world.instance_game);
+        let [quarter] = filed(&events)[..] else {
+            panic!("expected one report, got {events:?}");
+        };
+        assert_eq!(quarter.period, ReportPeriod::Quarterly);
+        assert_eq!(
+            (quarter.first_week, quarter.last_week),
+            (QUARTER_WEEKS, 2 * QUARTER_WEEKS - 1)
+        );
+        // Twelve weeks from the history and the week ending now.
+        assert_eq!(
+            company_line(quarter, company_id).revenue,
+            Money::new(QUARTER_WEEKS as i64 * WEEKLY_INCOME)
+        );
+    }
+
+    #[test]
+    fn the_years_last_quarter_files_the_annual_report_with_it() {
+        let (mut world, company_id) = world_at(2 * YEAR_WEEKS - 1);
+
+        // Last year's final quarter, then this year's first three.
+        let earlier_quarter = |first_week: u16| FinancialReport {
+            period: ReportPeriod::Quarterly,
+            first_week,
+            last_week: first_week + QUARTER_WEEKS - 1,
+            companies: vec![ReportLine {
+                id: company_id,
+                name: String::new(),
+                revenue: Money::new(1_000),
+                expenses: Money::ZERO,
+                profit: Money::new(1_000),
+                headcount: 0,
+                avg_satisfaction: Percent::new(50),
+                reputation_change: 1,
+                public_opinion_change: 0,
+            }],
+            organizations: vec![],
+        };
+        world.instance_game.report_store.reports = (0..4)
+            .map(|quarter| earlier_quarter(YEAR_WEEKS - QUARTER_WEEKS + quarter * QUARTER_WEEKS))
+            .collect();
+
+        let events = report_updates(&world.instance_game);
+        let [quarter, year] = filed(&events)[..] else {
+            panic!("expected two reports, got {events:?}");
+        };
+        assert_eq!(year.period, ReportPeriod::Annual);
+        assert_eq!(
+            (year.first_week, year.last_week),
+            (YEAR_WEEKS, 2 * YEAR_WEEKS - 1)
+        );
+        assert_eq!(quarter.to_string(), "Q4 Year 2");
+        assert_eq!(year.to_string(), "Year 2");
+
+        let year_line = company_line(year, company_id);
+        let quarter_line = company_line(quarter, company_id);
+        assert_eq!(
+            year_line.revenue,
+            Money::new(3 * 1_000) + quarter_line.revenue
+        );
+        assert_eq!(
+            year_line.reputation_change,
+            3 + quarter_line.reputation_change
+        );
+    }
+}
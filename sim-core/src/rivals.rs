@@ -0,0 +1,237 @@
+use crate::{
+    QUIT_THRESHOLD, apply_event, company_cash, minimum_acceptable_salary, process_company_command,
+};
+use shared::{
+    ClientActionCommand, Company, EntityType, GameInstanceData, GameState, InitiativeKind,
+    InternalEvent, Money, Organization, OrganizationType, Percent, RivalStrategy,
+};
+use std::collections::HashMap;
+use tracing::info;
+use uuid::Uuid;
+
+/// Cash a marketer keeps in hand before it spends on a campaign.
+const MARKETER_RESERVE: Money = Money::new(100_000);
+const MARKETER_CAMPAIGN_WEEKS: u16 = 8;
+const MARKETER_CAMPAIGN_BUDGET: Money = Money::new(20_000);
+
+/// A builder stops hiring once its smallest organization has this many people.
+const BUILDER_TARGET_HEADCOUNT: usize = 25;
+
+/// A caretaker gives a raise to anyone whose satisfaction drops below this.
+const CARETAKER_CONCERN: Percent = Percent::new(QUIT_THRESHOLD * 2);
+const CARETAKER_RAISE: Money = Money::new(2);
+
+/// Decides what a rival company does each week. It issues the same commands the operator
+/// can, and they are checked and carried out the same way. Returning `DoNothing` skips the
+/// week; the week itself is only ever ended by the operator.
+pub trait CompanyPolicy {
+    fn next_command(&self, game_state: &GameState, company: &Company) -> ClientActionCommand;
+}
+
+pub fn policy_for_strategy(strategy: RivalStrategy) -> Box<dyn CompanyPolicy> {
+    match strategy {
+        RivalStrategy::Marketer => Box::new(MarketerPolicy),
+        RivalStrategy::Builder => Box::new(BuilderPolicy),
+        RivalStrategy::Caretaker => Box::new(CaretakerPolicy),
+    }
+}
+
+/// Every rival's move for the week, in company id order. Policies only look at the state, so
+/// a replay makes the same moves. Each rival sees the moves of those before it, so two of them
+/// can't hire the same candidate. A move the rules refuse is dropped.
+pub fn rival_turns(game_state: &GameState) -> Vec<InternalEvent> {
+    let mut rivals: Vec<(Uuid, RivalStrategy)> = game_state
+        .companies
+        .values()
+        .filter(|company| !company.debt.bankrupt)
+        .filter_map(|company| Some((company.id, company.rival?)))
+        .collect();
+    rivals.sort_by_key(|(company_id, _)| *company_id);
+
+    // Only the state is needed to decide and check moves, history and reports stay behind.
+    let mut working = GameInstanceData {
+        game_state: game_state.clone(),
+        ..Default::default()
+    };
+    let mut events = vec![];
+    for (company_id, strategy) in rivals {
+        let game_state = &working.game_state;
+        let Some(company) = game_state.companies.get(&company_id) else {
+            continue;
+        };
+        let command = policy_for_strategy(strategy).next_command(game_state, company);
+        match process_company_command(game_state, &company_id, command) {
+            Ok(rival_events) => {
+                for rival_event in &rival_events {
+                    apply_event(rival_event, &mut working);
+                }
+                events.extend(rival_events);
+            }
+            Err(rejection) => info!("{} could not act: {}", company.name, rejection),
+        }
+    }
+    events
+}
+
+pub struct MarketerPolicy;
+
+impl CompanyPolicy for MarketerPolicy {
+    fn next_command(&self, game_state: &GameState, company: &Company) -> ClientActionCommand {
+        // No point buying demand it cannot ship.
+        if company_cash(game_state, &company.id) < MARKETER_RESERVE
+            || company.orders.bottleneck.is_some()
+        {
+            return ClientActionCommand::DoNothing;
+        }
+
+        // Customer facing organizations get the most out of a campaign.
+        let away_from_customers = |organization: &Organization| {
+            !matches!(
+                organization.organization_type,
+                OrganizationType::RetailSite
+                    | OrganizationType::MarketingTeam
+                    | OrganizationType::ContentCreation
+                    | OrganizationType::SupportCenter
+            )
+        };
+        organizations_of(game_state, &company.id)
+            .into_iter()
+            .filter(|organization| {
+                !organization
+                    .initiatives
+                    .iter()
+                    .any(|initiative| initiative.kind == InitiativeKind::Marketing)
+            })
+            .min_by_key(|organization| (away_from_customers(organization), organization.id))
+            .map(|organization| ClientActionCommand::LaunchInitiative {
+                organization_id: organization.id,
+                kind: InitiativeKind::Marketing,
+                weeks: MARKETER_CAMPAIGN_WEEKS,
+                budget: MARKETER_CAMPAIGN_BUDGET,
+            })
+            .unwrap_or(ClientActionCommand::LaunchPRCampaign)
+    }
+}
+
+pub struct BuilderPolicy;
+
+impl CompanyPolicy for BuilderPolicy {
+    fn next_command(&self, game_state: &GameState, company: &Company) -> ClientActionCommand {
+        let mut headcounts: HashMap<Uuid, usize> = organizations_of(game_state, &company.id)
+            .into_iter()
+            .map(|organization| (organization.id, 0))
+            .collect();
+        for employment in game_state
+            .entities
+            .values()
+            .filter_map(|entity| entity.employment.as_ref())
+        {
+            if let Some(headcount) = headcounts.get_mut(&employment.organization_id) {
+                *headcount += 1;
+            }
+        }
+        let Some((organization_id, _)) = headcounts
+            .into_iter()
+            .filter(|(_, headcount)| *headcount < BUILDER_TARGET_HEADCOUNT)
+            .min_by_key(|(organization_id, headcount)| (*headcount, *organization_id))
+        else {
+            return ClientActionCommand::DoNothing;
+        };
+
+        let mut candidates: Vec<_> = game_state
+            .candidates
+            .values()
+            .filter(|candidate| candidate.listed_until_week > game_state.week)
+            .collect();
+        candidates.sort_by_key(|candidate| candidate.entity_id);
+
+        candidates
+            .into_iter()
+            .find_map(|candidate| {
+                let entity = game_state.entities.get(&candidate.entity_id)?;
+                let EntityType::Human(human_type) = &entity.entity_type else {
+                    return None;
+                };
+                let role = *candidate.preferred_roles.first()?;
+                let salary_offer =
+                    minimum_acceptable_salary(candidate, human_type, &role, candidate.level)?;
+                Some(ClientActionCommand::HireEmployee {
+                    employee_id: candidate.entity_id,
+                    organization_id,
+                    role,
+                    level: candidate.level,
+                    salary_offer,
+                    signing_bonus: None,
+                })
+            })
+            .unwrap_or(ClientActionCommand::DoNothing)
+    }
+}
+
+pub struct CaretakerPolicy;
+
+impl CompanyPolicy for CaretakerPolicy {
+    fn next_command(&self, game_state: &GameState, company: &Company) -> ClientActionCommand {
+        let organization_ids: Vec<Uuid> = organizations_of(game_state, &company.id)
+            .into_iter()
+            .map(|organization| organization.id)
+            .collect();
+
+        // Ties are broken by id so the pick does not depend on map order.
+        game_state
+            .entities
+            .values()
+            .filter_map(|entity| {
+                let employment = entity.employment.as_ref()?;
+                organization_ids
+                    .contains(&employment.organization_id)
+                    .then_some((employment.satisfaction, entity.id))
+            })
+            .filter(|(satisfaction, _)| *satisfaction < CARETAKER_CONCERN)
+            .min()
+            .map(|(_, employee_id)| ClientActionCommand::GiveRaise {
+                employee_id,
+                amount: CARETAKER_RAISE,
+            })
+            .unwrap_or(ClientActionCommand::DoNothing)
+    }
+}
+
+fn organizations_of<'a>(game_state: &'a GameState, company_id: &Uuid) -> Vec<&'a Organization> {
+    game_state
+        .organizations
+        .values()
+        .filter(|organization| organization.company_relation.entity_id == *company_id)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::World;
+    use shared::WorldGenerationParameters;
+    use std::collections::HashSet;
+
+    #[test]
+    fn builders_in_the_same_week_hire_different_candidates() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        while world.game_state().candidates.len() < 2 {
+            world.step(ClientActionCommand::EndTurn).unwrap();
+        }
+        for company in world.instance_game.game_state.companies.values_mut() {
+            if company.rival.is_some() {
+                company.rival = Some(RivalStrategy::Builder);
+            }
+        }
+
+        let hired: Vec<Uuid> = rival_turns(world.game_state())
+            .into_iter()
+            .filter_map(|event| match event {
+                InternalEvent::AddEmployedStatus { employee_id, .. } => Some(employee_id),
+                _ => None,
+            })
+            .collect();
+        assert!(hired.len() >= 2);
+        assert_eq!(hired.iter().collect::<HashSet<_>>().len(), hired.len());
+    }
+}
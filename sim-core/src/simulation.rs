@@ -0,0 +1,217 @@
+use crate::{company_cash, company_of};
+use serde::Serialize;
+use shared::{ClientActionCommand, Financials, GameState, Money, Perception};
+use std::fs::read_to_string;
+use std::path::Path;
+use uuid::Uuid;
+
+/// Decides what the operator does each week of a headless simulation. The week is ended after
+/// the command, a policy only returns `EndTurn` to skip acting.
+pub trait SimulationPolicy {
+    fn next_command(&mut self, game_state: &GameState) -> ClientActionCommand;
+}
+
+pub struct DoNothingPolicy;
+
+impl SimulationPolicy for DoNothingPolicy {
+    fn next_command(&mut self, _game_state: &GameState) -> ClientActionCommand {
+        ClientActionCommand::DoNothing
+    }
+}
+
+pub struct PrCampaignPolicy;
+
+impl SimulationPolicy for PrCampaignPolicy {
+    fn next_command(&mut self, game_state: &GameState) -> ClientActionCommand {
+        let can_afford = game_state
+            .player_company_id()
+            .is_some_and(|company_id| company_cash(game_state, &company_id) >= Money::new(1_000));
+        if can_afford {
+            ClientActionCommand::LaunchPRCampaign
+        } else {
+            ClientActionCommand::DoNothing
+        }
+    }
+}
+
+pub struct RaiseLeastSatisfiedPolicy {
+    pub amount: Money,
+}
+
+impl SimulationPolicy for RaiseLeastSatisfiedPolicy {
+    fn next_command(&mut self, game_state: &GameState) -> ClientActionCommand {
+        // Ties are broken by id so the pick does not depend on map order.
+        let player_company_id = game_state.player_company_id();
+        game_state
+            .entities
+            .values()
+            .filter_map(|entity| {
+                let employment = entity.employment.as_ref()?;
+                (company_of(game_state, &employment.organization_id) == player_company_id)
+                    .then_some((employment.satisfaction, entity.id))
+            })
+            .min()
+            .map(|(_, employee_id)| ClientActionCommand::GiveRaise {
+                employee_id,
+                amount: self.amount,
+            })
+            .unwrap_or(ClientActionCommand::DoNothing)
+    }
+}
+
+/// Plays a fixed list of commands, one per week, starting over once it runs out.
+pub struct ScriptedPolicy {
+    pub commands: Vec<ClientActionCommand>,
+    pub position: usize,
+}
+
+impl ScriptedPolicy {
+    /// Loads a script from a JSON array of `ClientActionCommand`s.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let commands: Vec<ClientActionCommand> = serde_json::from_str(&read_to_string(path)?)?;
+        if commands.is_empty() {
+            anyhow::bail!("Policy script {:?} contains no commands", path);
+        }
+        Ok(ScriptedPolicy {
+            commands,
+            position: 0,
+        })
+    }
+}
+
+impl SimulationPolicy for ScriptedPolicy {
+    fn next_command(&mut self, _game_state: &GameState) -> ClientActionCommand {
+        let command = self.commands[self.position % self.commands.len()].clone();
+        self.position += 1;
+        command
+    }
+}
+
+#[derive(Serialize)]
+pub struct WeekMetrics {
+    pub week: u16,
+    pub kind: &'static str,
+    pub id: Uuid,
+    pub name: String,
+    pub cash: Money,
+    pub income: Money,
+    pub expenses: Money,
+    pub net_profit: Money,
+    pub public_opinion: i16,
+    pub reputation: i16,
+    pub employees: u32,
+    pub avg_satisfaction: u32,
+}
+
+pub const CSV_HEADER: &str = "week,kind,id,name,cash,income,expenses,net_profit,public_opinion,reputation,employees,avg_satisfaction";
+
+impl WeekMetrics {
+    fn new(
+        game_state: &GameState,
+        kind: &'static str,
+        id: Uuid,
+        name: String,
+        financials: &Financials,
+        perception: &Perception,
+        satisfaction: (u32, u32),
+    ) -> Self {
+        let (total_satisfaction, employees) = satisfaction;
+        WeekMetrics {
+            week: game_state.week,
+            kind,
+            id,
+            name,
+            cash: financials.actual_cash,
+            income: financials.this_weeks_income,
+            expenses: financials.this_weeks_expenses,
+            net_profit: financials.this_weeks_net_profit,
+            public_opinion: perception.public_opinion,
+            reputation: perception.reputation,
+            employees,
+            avg_satisfaction: total_satisfaction.checked_div(employees).unwrap_or(0),
+        }
+    }
+
+    pub fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},\"{}\",{},{},{},{},{},{},{},{}",
+            self.week,
+            self.kind,
+            self.id,
+            self.name.replace('"', "\"\""),
+            self.cash,
+            self.income,
+            self.expenses,
+            self.net_profit,
+            self.public_opinion,
+            self.reputation,
+            self.employees,
+            self.avg_satisfaction
+        )
+    }
+}
+
+/// One row for the player, each company and each organization, in a stable order.
+pub fn collect_week_metrics(game_state: &GameState) -> Vec<WeekMetrics> {
+    let satisfaction_where = |include: &dyn Fn(Uuid) -> bool| {
+        game_state
+            .entities
+            .values()
+            .filter_map(|entity| entity.employment.as_ref())
+            .filter(|employment| include(employment.organization_id))
+            .fold((0, 0), |(sum, count), employment| {
+                (sum + employment.satisfaction.get() as u32, count + 1)
+            })
+    };
+
+    let player_company_id = game_state.player_company_id();
+    let mut rows = vec![];
+    for player in &game_state.players {
+        rows.push(WeekMetrics::new(
+            game_state,
+            "player",
+            player.id.unwrap_or_default(),
+            "player".to_string(),
+            &player.financials,
+            &player.perception,
+            satisfaction_where(&|organization_id| {
+                company_of(game_state, &organization_id) == player_company_id
+            }),
+        ));
+    }
+
+    let mut companies: Vec<_> = game_state.companies.values().collect();
+    companies.sort_by_key(|company| company.id);
+    for company in companies {
+        rows.push(WeekMetrics::new(
+            game_state,
+            "company",
+            company.id,
+            company.name.clone(),
+            &company.financials,
+            &company.perception,
+            satisfaction_where(&|organization_id| {
+                game_state
+                    .organizations
+                    .get(&organization_id)
+                    .is_some_and(|org| org.company_relation.entity_id == company.id)
+            }),
+        ));
+    }
+
+    let mut organizations: Vec<_> = game_state.organizations.values().collect();
+    organizations.sort_by_key(|organization| organization.id);
+    for organization in organizations {
+        rows.push(WeekMetrics::new(
+            game_state,
+            "organization",
+            organization.id,
+            organization.name.clone(),
+            &organization.financials,
+            &organization.perception,
+            satisfaction_where(&|organization_id| organization_id == organization.id),
+        ));
+    }
+
+    rows
+}
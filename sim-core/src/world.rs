@@ -0,0 +1,189 @@
+use crate::{
+    CommandRejection, apply_event, company_updates, create_empty_world_state, organization_updates,
+    process_command,
+};
+use shared::{
+    ClientActionCommand, GameInstanceData, GameState, InternalEvent, WorldGenerationParameters,
+};
+
+/// A game world with no I/O attached: the state plus the rules that move it forward.
+#[derive(Clone, Debug, Default)]
+pub struct World {
+    pub instance_game: GameInstanceData,
+}
+
+impl World {
+    /// A freshly generated world with no history.
+    pub fn generate(world_parameters: &WorldGenerationParameters) -> Self {
+        World {
+            instance_game: GameInstanceData {
+                game_state: create_empty_world_state(world_parameters),
+                history_state: Default::default(),
+                report_store: Default::default(),
+            },
+        }
+    }
+
+    pub fn game_state(&self) -> &GameState {
+        &self.instance_game.game_state
+    }
+
+    pub fn apply_event(&mut self, internal_event: &InternalEvent) {
+        apply_event(internal_event, &mut self.instance_game);
+    }
+
+    pub fn process_command(
+        &self,
+        client_action_command: ClientActionCommand,
+    ) -> Result<Vec<InternalEvent>, CommandRejection> {
+        process_command(&self.instance_game, client_action_command)
+    }
+
+    /// One command as the server runs it. The command's events are applied first; when they
+    /// end the week, the organizations' weekly accounting follows on the new state, and then
+    /// the companies' trading is brought up to date. Each stage sees the one before it
+    /// applied. Returns the events in the order they were applied; a rejected command leaves
+    /// the world untouched.
+    pub fn step(
+        &mut self,
+        client_action_command: ClientActionCommand,
+    ) -> Result<Vec<InternalEvent>, CommandRejection> {
+        let mut events = self.process_command(client_action_command)?;
+        self.apply_events(&events);
+
+        if ends_week(&events) {
+            let organization_events = organization_updates(self.game_state());
+            self.apply_events(&organization_events);
+            events.extend(organization_events);
+        }
+
+        let company_events = company_updates(self.game_state());
+        self.apply_events(&company_events);
+        events.extend(company_events);
+        Ok(events)
+    }
+
+    fn apply_events(&mut self, internal_events: &[InternalEvent]) {
+        for internal_event in internal_events {
+            self.apply_event(internal_event);
+        }
+    }
+}
+
+/// Whether a command's events roll the week over, after which the organizations settle it.
+pub fn ends_week(internal_events: &[InternalEvent]) -> bool {
+    internal_events
+        .iter()
+        .any(|internal_event| matches!(internal_event, InternalEvent::AdvanceWeek))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{ClockSpeed, ClockState, Money, Percent, ServerEvent};
+
+    #[test]
+    fn full_state_round_trips_through_bincode() {
+        let world = World::generate(&WorldGenerationParameters::default());
+        let event = ServerEvent::FullState {
+            game_state: world.game_state().clone(),
+            clock: ClockState {
+                speed: ClockSpeed::Normal,
+                seconds_per_week: 10,
+                millis_until_next_week: 5_000,
+            },
+        };
+
+        let bytes = bincode::serialize(&event).unwrap();
+        let ServerEvent::FullState { game_state, .. } = bincode::deserialize(&bytes).unwrap()
+        else {
+            panic!("expected a full state");
+        };
+        assert_eq!(
+            serde_json::to_value(&game_state).unwrap(),
+            serde_json::to_value(world.game_state()).unwrap()
+        );
+    }
+
+    #[test]
+    fn percent_commands_round_trip_through_bincode() {
+        let command = ClientActionCommand::SetCeoCompensation {
+            salary: Money::new(5_000),
+            bonus_percent: Percent::new(15),
+        };
+
+        let bytes = bincode::serialize(&command).unwrap();
+        let ClientActionCommand::SetCeoCompensation { bonus_percent, .. } =
+            bincode::deserialize(&bytes).unwrap()
+        else {
+            panic!("expected the compensation command");
+        };
+        assert_eq!(bonus_percent, Percent::new(15));
+    }
+
+    #[test]
+    fn stepping_is_deterministic_for_a_seed() {
+        let parameters = WorldGenerationParameters {
+            seed: 7,
+            ..Default::default()
+        };
+        let run = || {
+            let mut world = World::generate(&parameters);
+            let mut events = vec![];
+            for _ in 0..30 {
+                events.extend(world.step(ClientActionCommand::EndTurn).unwrap());
+            }
+            (
+                serde_json::to_value(&events).unwrap(),
+                serde_json::to_value(&world.instance_game).unwrap(),
+            )
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn the_largest_valid_world_generates() {
+        let parameters = WorldGenerationParameters {
+            organization_count: shared::MAX_ORGANIZATION_COUNT,
+            min_organization_size: shared::MAX_ORGANIZATION_SIZE,
+            max_organization_size: shared::MAX_ORGANIZATION_SIZE,
+            rival_count: shared::MAX_RIVALS,
+            ..Default::default()
+        };
+        assert!(parameters.validate().is_ok());
+
+        let world = World::generate(&parameters);
+        assert_eq!(world.game_state().companies.len(), shared::MAX_RIVALS + 1);
+    }
+
+    #[test]
+    fn ending_the_week_settles_the_organizations() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        world.step(ClientActionCommand::EndTurn).unwrap();
+
+        let organizations = &world.game_state().organizations;
+        assert!(
+            organizations
+                .values()
+                .all(|organization| organization.financials.this_weeks_expenses > Money::ZERO)
+        );
+        assert!(
+            organizations
+                .values()
+                .any(|organization| organization.financials.this_weeks_income > Money::ZERO)
+        );
+    }
+
+    #[test]
+    fn other_commands_leave_the_organizations_alone() {
+        let mut world = World::generate(&WorldGenerationParameters::default());
+        let events = world.step(ClientActionCommand::DoNothing).unwrap();
+
+        assert!(
+            !events
+                .iter()
+                .any(|event| matches!(event, InternalEvent::SetOrgFinancials { .. }))
+        );
+    }
+}